@@ -1,6 +1,9 @@
+use std::path::Path;
+
 use crate::{
     api::{ AtpBlockMethods, AtpBuilderMethods, AtpConditionalMethods },
     globals::var::TokenWrapper,
+    text::reader::read_from_file,
     tokens::InstructionMethods,
     utils::errors::AtpError,
 };
@@ -17,15 +20,77 @@ impl<'ap> AtpBuilder<'ap> {
         AtpBuilder { tokens: Vec::new(), processor }
     }
 
+    /// Creates a builder that already holds the tokens of the `.atp` file at `path`,
+    /// so a base pipeline can be loaded and extended with the builder methods.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::path::Path;
+    ///
+    /// use atp::builder::atp_builder::AtpBuilder;
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    ///
+    /// let id = AtpBuilder::from_atp_file(&mut processor, Path::new("base.atp"))?
+    ///     .add_to_end("!")?
+    ///     .build();
+    ///
+    /// let out = processor.process_all(&id, "banana")?;
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err` if the file cannot be read or parsed.
+    pub fn from_atp_file(
+        processor: &'ap mut AtpProcessor,
+        path: &Path
+    ) -> Result<AtpBuilder<'ap>, AtpError> {
+        let tokens = read_from_file(path)?;
+        processor.check_token_count(tokens.len())?;
+
+        Ok(AtpBuilder { tokens, processor })
+    }
+
     pub fn build(&mut self) -> String {
         let id = self.processor.add_transform(self.tokens.clone());
 
         id
     }
+
+    /// Builds the pipeline, processes `input` once and removes the pipeline again, for
+    /// one-shot use where the id is not needed. Use `build()` to process several inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    ///
+    /// let out = processor.create_pipeline().trim_both_sides()?.add_to_end("!")?.run("  banana ")?;
+    ///
+    /// assert_eq!(out, "banana!");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err` if any token fails while processing `input`.
+    pub fn run(&mut self, input: &str) -> Result<String, AtpError> {
+        let id = self.build();
+        let result = self.processor.process_all(&id, input);
+        self.processor.remove_transform(&id)?;
+
+        result
+    }
 }
 
 impl<'ap> AtpBuilderMethods for AtpBuilder<'ap> {
     fn push_token(&mut self, t: impl Into<TokenWrapper>) -> Result<(), AtpError> {
+        self.processor.check_token_count(self.tokens.len() + 1)?;
         self.tokens.push(t.into());
         Ok(())
     }
@@ -33,3 +98,120 @@ impl<'ap> AtpBuilderMethods for AtpBuilder<'ap> {
 
 impl<'ap> AtpConditionalMethods for AtpBuilder<'ap> {}
 impl<'ap> AtpBlockMethods for AtpBuilder<'ap> {}
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod atp_builder_tests {
+    mod run_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::AtpError,
+        };
+
+        #[test]
+        fn run_matches_build_and_process_all() -> Result<(), AtpError> {
+            let input = "  Banana laranja cheia de canja  ";
+            let mut processor = AtpProcessor::new();
+
+            let id = processor.create_pipeline().trim_both_sides()?.to_uppercase_all()?.build();
+            let expected = processor.process_all(&id, input)?;
+            let out = processor.create_pipeline().trim_both_sides()?.to_uppercase_all()?.run(input)?;
+            assert_eq!(out, expected);
+
+            let id = processor
+                .create_pipeline()
+                .replace_all_with("a", "e")?
+                .add_to_beginning("> ")?
+                .repeat(2)?
+                .build();
+            let expected = processor.process_all(&id, input)?;
+            let out = processor
+                .create_pipeline()
+                .replace_all_with("a", "e")?
+                .add_to_beginning("> ")?
+                .repeat(2)?
+                .run(input)?;
+            assert_eq!(out, expected);
+
+            let id = processor.create_pipeline().to_reverse()?.delete_first()?.build();
+            let expected = processor.process_all(&id, input)?;
+            let out = processor.create_pipeline().to_reverse()?.delete_first()?.run(input)?;
+            assert_eq!(out, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn run_returns_token_errors() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let result = processor.create_pipeline().limit_length(3)?.run("banana");
+
+            assert!(result.is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn if_do_contains_each_takes_any_number_of_tokens() -> Result<(), AtpError> {
+            use crate::api::AtpConditionalMethods;
+
+            let mut processor = AtpProcessor::new();
+
+            let out = processor
+                .create_pipeline()
+                .if_do_contains_each("xy", |b| {
+                    for _ in 0..10 {
+                        b.add_to_end("!")?;
+                    }
+                    Ok(())
+                })?
+                .run("xy")?;
+
+            assert_eq!(out, format!("xy{}", "!".repeat(10)));
+            Ok(())
+        }
+    }
+
+    mod from_atp_file_tests {
+        use std::path::PathBuf;
+
+        use crate::{
+            api::{
+                AtpBuilderMethods,
+                atp_builder::AtpBuilder,
+                atp_processor::{ AtpProcessor, AtpProcessorMethods },
+            },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        fn write_temp_atp(name: &str, contents: &str) -> PathBuf {
+            let mut path = std::env::temp_dir();
+            path.push(format!("atp_builder_{}_{}.atp", name, std::process::id()));
+            std::fs::write(&path, contents).unwrap();
+            path
+        }
+
+        #[test]
+        fn loaded_tokens_run_before_chained_ones() -> Result<(), AtpError> {
+            let path = write_temp_atp("chain", "tbs;\natb b;\n");
+            let mut processor = AtpProcessor::new();
+
+            let id = AtpBuilder::from_atp_file(&mut processor, &path)?.add_to_end("!")?.build();
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(processor.get_text_transform_vec(&id)?, vec!["tbs;\n", "atb b;\n", "ate !;\n"]);
+            assert_eq!(processor.process_all(&id, "  anana  ")?, "banana!");
+            Ok(())
+        }
+
+        #[test]
+        fn missing_file_is_an_error() {
+            let mut processor = AtpProcessor::new();
+            let path = std::env::temp_dir().join("atp_builder_missing_file_for_test.atp");
+
+            let err = AtpBuilder::from_atp_file(&mut processor, &path).err().unwrap();
+
+            assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+        }
+    }
+}
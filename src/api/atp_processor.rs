@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::Path;
 
 use uuid::Uuid;
@@ -6,9 +7,17 @@ use uuid::Uuid;
 use colored::*;
 
 use crate::api::atp_builder::AtpBuilder;
+use crate::api::pipeline_spec::PipelineSpec;
 #[cfg(feature = "bytecode")]
-use crate::bytecode::{ reader::read_bytecode_from_file, writer::write_bytecode_to_file };
-use crate::context::execution_context::{ GlobalContextMethods, GlobalExecutionContext };
+use crate::bytecode::{
+    reader::read_bytecode_from_file,
+    writer::{ BYTECODE_HEADER_SIZE, write_bytecode_to_file },
+};
+use crate::context::execution_context::{
+    BranchRecord,
+    GlobalContextMethods,
+    GlobalExecutionContext,
+};
 use crate::globals::var::{ TokenWrapper };
 
 use crate::utils::apply::apply_transform;
@@ -16,6 +25,7 @@ use crate::text::reader::read_from_file;
 use crate::text::writer::write_to_file;
 
 use crate::utils::errors::{ AtpError, AtpErrorCode, ErrorManager, token_array_not_found };
+use crate::utils::validations::{ IndexPolicy, check_token_consistency };
 
 /// ATP Processor
 ///
@@ -189,9 +199,15 @@ use crate::utils::errors::{ AtpError, AtpErrorCode, ErrorManager, token_array_no
 /// - `build()` registers a new transform entry inside the processor and returns its UUID.
 /// - The pipeline is **one giant vector** of tokens; execution is deterministic and ordered.
 /// - Debug methods (`*_with_debug`) only add printing; they do not change execution.
+///   The output goes to stdout unless a writer is set with `set_debug_writer`.
 pub struct AtpProcessor {
     transforms: HashMap<String, Vec<TokenWrapper>>,
     errors: ErrorManager,
+    locale: Option<String>,
+    index_policy: Option<IndexPolicy>,
+    registers: HashMap<String, String>,
+    debug_writer: Option<Box<dyn Write + Send>>,
+    max_tokens: Option<usize>,
 }
 
 /// Operational API for `AtpProcessor`.
@@ -243,6 +259,42 @@ pub trait AtpProcessorMethods {
     /// Returns `Err` if reading/parsing the file fails.
     fn read_from_text_file(&mut self, path: &Path) -> Result<String, AtpError>;
 
+    /// Parses a JSON `PipelineSpec`, registers it as a new transform,
+    /// and returns the newly created transform ID.
+    ///
+    /// The JSON is a list of `{ "op": "<token id>", "args": ["..."] }` entries; each entry
+    /// is resolved through `TOKEN_TABLE` with the same rules as an `.atp` line.
+    ///
+    /// # Returns
+    /// The UUID string identifying the newly registered transform.
+    ///
+    /// # Errors
+    /// Returns `Err` if:
+    /// - the JSON is malformed (`TextParsingError`)
+    /// - an `op` is not a known token (`TokenNotFound`)
+    /// - the `args` do not match the token syntax
+    fn read_from_json(&mut self, json: &str) -> Result<String, AtpError>;
+
+    /// Serializes a registered transform as a JSON `PipelineSpec`.
+    ///
+    /// The output can be loaded back with `read_from_json`. Variable references are kept
+    /// unresolved (`{{name}}`).
+    ///
+    /// # Errors
+    /// Returns `Err` if the transform does not exist.
+    fn to_json(&self, id: &str) -> Result<String, AtpError>;
+
+    /// Checks that a registered transform is structurally sound, without processing any input.
+    ///
+    /// Every token has its params checked against the syntax registered in `TOKEN_TABLE` and,
+    /// when it has no variable references, is rebuilt through `from_params` to confirm that
+    /// the params are accepted and produce a consistent `.atp` line.
+    ///
+    /// # Errors
+    /// Returns `Err` if the transform does not exist, or the first inconsistency found
+    /// (with `position` and `pipeline_id` filled in).
+    fn validate_pipeline(&self, id: &str) -> Result<(), AtpError>;
+
     /// Registers a new transform (pipeline) directly from a token vector.
     ///
     /// This is the low-level “insert” API. Higher-level builder APIs typically call this.
@@ -277,6 +329,29 @@ pub trait AtpProcessorMethods {
     /// - any token execution fails (propagated from `parse_token`)
     fn process_all(&mut self, id: &str, input: &str) -> Result<String, AtpError>;
 
+    /// Executes all tokens of a registered transform, but keeps going when a token fails.
+    ///
+    /// Semantics:
+    /// - `result` starts as `input`
+    /// - for each token `t` in the transform:
+    ///   - on success: `result = t(result)`
+    ///   - on failure: the error is collected and `result` is left unchanged for that step
+    /// - returns the final `result` together with every collected token error
+    ///
+    /// Token errors are also pushed into the internal `ErrorManager`, just like `process_all`.
+    ///
+    /// # Parameters
+    /// - `id`: Transform identifier.
+    /// - `input`: Input string to process.
+    ///
+    /// # Errors
+    /// Returns `Err` only if the transform does not exist; token failures are non-fatal.
+    fn process_all_lenient(
+        &mut self,
+        id: &str,
+        input: &str
+    ) -> Result<(String, Vec<AtpError>), AtpError>;
+
     /// Executes a single token over `input`, without registering it into the processor.
     ///
     /// This is a convenience method for ad-hoc transformations:
@@ -453,7 +528,208 @@ impl AtpProcessor {
         AtpProcessor {
             transforms: HashMap::new(),
             errors: ErrorManager::default(),
+            locale: None,
+            index_policy: None,
+            registers: HashMap::new(),
+            debug_writer: None,
+            max_tokens: None,
+        }
+    }
+
+    /// Sets the locale used by the case tokens (`tua`, `tla`, `tucw`, ...) and the capitalizing
+    /// ones (`cfw`, `ctr`, `jpsc`, ...) of every pipeline executed by this processor.
+    ///
+    /// Only the language part matters (`"tr"`, `"tr-TR"` and `"tr_TR"` are the same), currently
+    /// Turkish and Azerbaijani get special handling for the dotted `İ` and dotless `ı`.
+    /// Without a locale the default Unicode case mapping is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// processor.set_locale("tr");
+    ///
+    /// let id = processor.create_pipeline().to_uppercase_all()?.build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "istanbul")?, "İSTANBUL");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = Some(locale.to_string());
+    }
+
+    /// Removes the locale set with `set_locale`, going back to the default case mapping.
+    pub fn clear_locale(&mut self) {
+        self.locale = None;
+    }
+
+    pub fn get_locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Sets how the indexed tokens (`dls`, `dla`, `dlb`, `tucs`, `tlcs`, `tgl`, `tucw`, `tlcw`,
+    /// `cts` and `swpw`) of every pipeline treat an index that does not exist in their input.
+    ///
+    /// Without a policy each token keeps its own behavior, which currently is `IndexPolicy::Strict`
+    /// for all of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    /// use atp::IndexPolicy;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// processor.set_index_policy(IndexPolicy::Ignore);
+    ///
+    /// let id = processor.create_pipeline().delete_single(10)?.build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "banana")?, "banana");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn set_index_policy(&mut self, policy: IndexPolicy) {
+        self.index_policy = Some(policy);
+    }
+
+    /// Removes the policy set with `set_index_policy`, going back to each token's own behavior.
+    pub fn clear_index_policy(&mut self) {
+        self.index_policy = None;
+    }
+
+    pub fn get_index_policy(&self) -> Option<IndexPolicy> {
+        self.index_policy
+    }
+
+    /// Stores `value` in the register `name`, every run of this processor starts with it, so
+    /// `interp` can replace `${name}` with `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// processor.set_register("fruit", "banana");
+    ///
+    /// let id = processor.create_pipeline().interpolate_registers()?.build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "I like ${fruit}")?, "I like banana");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn set_register(&mut self, name: &str, value: &str) {
+        self.registers.insert(name.to_string(), value.to_string());
+    }
+
+    /// Removes the register `name`, returning its value.
+    pub fn remove_register(&mut self, name: &str) -> Option<String> {
+        self.registers.remove(name)
+    }
+
+    /// Removes every register set with `set_register`.
+    pub fn clear_registers(&mut self) {
+        self.registers.clear();
+    }
+
+    pub fn get_register(&self, name: &str) -> Option<&str> {
+        self.registers.get(name).map(|v| v.as_str())
+    }
+
+    /// Limits how many tokens a single pipeline of this processor can have.
+    ///
+    /// Once set, the builders, the file/JSON readers and `compose` fail with `InvalidParameters`
+    /// instead of creating a pipeline with more than `max` tokens. Useful when loading untrusted
+    /// `.atp` files. There is no limit by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// processor.set_max_tokens(1);
+    ///
+    /// assert!(processor.create_pipeline().trim_both_sides()?.to_uppercase_all().is_err());
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn set_max_tokens(&mut self, max: usize) {
+        self.max_tokens = Some(max);
+    }
+
+    /// Removes the limit set with `set_max_tokens`.
+    pub fn clear_max_tokens(&mut self) {
+        self.max_tokens = None;
+    }
+
+    pub fn get_max_tokens(&self) -> Option<usize> {
+        self.max_tokens
+    }
+
+    /// Fails if a pipeline with `count` tokens goes over the limit set with `set_max_tokens`.
+    pub(crate) fn check_token_count(&self, count: usize) -> Result<(), AtpError> {
+        match self.max_tokens {
+            Some(max) if count > max =>
+                Err(
+                    AtpError::new(
+                        AtpErrorCode::InvalidParameters(
+                            format!("Pipeline exceeds the limit of {} tokens", max).into()
+                        ),
+                        "check_token_count",
+                        count.to_string()
+                    )
+                ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends the output of the `*_with_debug` methods to `writer` instead of stdout.
+    ///
+    /// Useful to keep the processed text and the diagnostics apart, e.g. by passing
+    /// `Box::new(std::io::stderr())` when the result itself is written to stdout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// processor.set_debug_writer(Box::new(std::io::stderr()));
+    ///
+    /// let id = processor.create_pipeline().add_to_end("!")?.build();
+    ///
+    /// assert_eq!(processor.process_all_with_debug(&id, "banana")?, "banana!");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn set_debug_writer(&mut self, writer: Box<dyn Write + Send>) {
+        self.debug_writer = Some(writer);
+    }
+
+    /// Removes the writer set with `set_debug_writer`, going back to stdout.
+    pub fn clear_debug_writer(&mut self) {
+        self.debug_writer = None;
+    }
+
+    fn new_context(&self) -> GlobalExecutionContext {
+        let mut context = GlobalExecutionContext::new();
+        context.set_locale(self.locale.as_deref());
+        context.set_index_policy(self.index_policy);
+        for (name, value) in self.registers.iter() {
+            context.set_register(name, value);
         }
+        context
+    }
+
+    /// Same as `new_context`, with debug mode on so tokens can queue debug messages.
+    fn new_debug_context(&self) -> GlobalExecutionContext {
+        let mut context = self.new_context();
+        context.set_debug(true);
+        context
     }
 
     /// Creates an `AtpBuilder` bound to this processor.
@@ -482,54 +758,177 @@ impl AtpProcessor {
     pub fn create_pipeline(&mut self) -> AtpBuilder<'_> {
         AtpBuilder::new(self)
     }
-}
 
-impl AtpProcessorMethods for AtpProcessor {
-    fn write_to_text_file(&mut self, id: &str, path: &Path) -> Result<(), AtpError> {
-        let tokens = match self.transforms.get(id).ok_or_else(token_array_not_found(id)) {
-            Ok(x) => x,
-            Err(e) => {
-                self.errors.add_error(e.clone());
-                return Err(e);
-            }
-        };
+    /// Registers a new pipeline running the tokens of `first_id` followed by the tokens
+    /// of `second_id`, returning its ID.
+    ///
+    /// Tokens are cloned, so both source pipelines stay registered and unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    ///
+    /// let trim = processor.create_pipeline().trim_both_sides()?.build();
+    /// let upper = processor.create_pipeline().to_uppercase_all()?.build();
+    ///
+    /// let id = processor.compose(&trim, &upper)?;
+    ///
+    /// assert_eq!(processor.process_all(&id, "  banana  ")?, "BANANA");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn compose(&mut self, first_id: &str, second_id: &str) -> Result<String, AtpError> {
+        let first = self.transforms.get(first_id).ok_or_else(token_array_not_found(first_id))?;
+        let second = self.transforms.get(second_id).ok_or_else(token_array_not_found(second_id))?;
 
-        write_to_file(Path::new(path), tokens)
+        let tokens: Vec<TokenWrapper> = first.iter().chain(second.iter()).cloned().collect();
+        self.check_token_count(tokens.len())?;
+
+        Ok(self.add_transform(tokens))
     }
 
-    fn read_from_text_file(&mut self, path: &Path) -> Result<String, AtpError> {
-        let tokens = match read_from_file(Path::new(path)) {
-            Ok(x) => x,
-            Err(e) => {
-                self.errors.add_error(e.clone());
-                return Err(e);
+    /// Returns the size in bytes that the `.atpbc` encoding of a registered transform takes,
+    /// header included, without writing anything.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// let id = processor.create_pipeline().trim_both_sides()?.to_uppercase_all()?.build();
+    ///
+    /// // 20 bytes of header + 13 bytes for each token without params
+    /// assert_eq!(processor.bytecode_size(&id)?, 46);
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Err` if the transform does not exist, or `BytecodeNotFound` (with `position`
+    /// and `pipeline_id` filled in) for the first token that has no bytecode encoding.
+    #[cfg(feature = "bytecode")]
+    pub fn bytecode_size(&self, id: &str) -> Result<usize, AtpError> {
+        let tokens = self.transforms.get(id).ok_or_else(token_array_not_found(id))?;
+
+        let mut size = BYTECODE_HEADER_SIZE;
+
+        for (position, token) in tokens.iter().enumerate() {
+            let encoded = token.to_bytecode();
+
+            if encoded.is_empty() {
+                return Err(
+                    AtpError::new(
+                        AtpErrorCode::BytecodeNotFound(
+                            "Token does not support bytecode encoding".into()
+                        ),
+                        token.to_atp_line(),
+                        ""
+                    )
+                        .with_position(position)
+                        .with_pipeline_id(id)
+                );
             }
-        };
 
-        let identifier = Uuid::new_v4();
+            size += encoded.len();
+        }
 
-        self.transforms.insert(identifier.to_string(), tokens);
+        Ok(size)
+    }
 
-        Ok(identifier.to_string())
+    /// Same as `process_all`, but for raw bytes that may not be valid UTF-8.
+    ///
+    /// Invalid sequences are replaced with `U+FFFD` (`�`) before running the pipeline, and
+    /// the result is returned UTF-8 encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// let id = processor.create_pipeline().add_to_end("!")?.build();
+    ///
+    /// assert_eq!(processor.process_bytes(&id, b"ban\xffna")?, "ban\u{FFFD}na!".as_bytes());
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn process_bytes(&mut self, id: &str, input: &[u8]) -> Result<Vec<u8>, AtpError> {
+        let decoded = String::from_utf8_lossy(input);
+
+        Ok(self.process_all(id, &decoded)?.into_bytes())
     }
 
-    fn process_all(&mut self, id: &str, input: &str) -> Result<String, AtpError> {
-        let mut result = String::from(input);
+    /// Runs the pipeline `id` once for each item of `inputs`.
+    ///
+    /// Every input is processed on its own, so an error in one item does not stop the
+    /// others, the results keep the same order as `inputs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::{AtpProcessor, AtpProcessorMethods};
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// let id = processor.create_pipeline().delete_single(3)?.build();
+    ///
+    /// let results = processor.process_all_batch(&id, &["banana", "abc"]);
+    ///
+    /// assert_eq!(results[0], Ok("banna".to_string()));
+    /// assert!(results[1].is_err());
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn process_all_batch(&mut self, id: &str, inputs: &[&str]) -> Vec<Result<String, AtpError>> {
+        inputs
+            .iter()
+            .map(|input| self.process_all(id, input))
+            .collect()
+    }
+
+    /// Same as `process_all`, but takes the input by value, which makes it easier to chain the
+    /// output of a pipeline into another one.
+    ///
+    /// The input buffer is used as the initial result instead of being copied, so a pipeline
+    /// without tokens hands back the same allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::AtpProcessor;
+    /// use atp::builder::AtpBuilderMethods;
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// let upper = processor.create_pipeline().to_uppercase_all()?.build();
+    /// let bang = processor.create_pipeline().add_to_end("!")?.build();
+    ///
+    /// let output = processor.process_owned(&upper, "banana".to_string())?;
+    ///
+    /// assert_eq!(processor.process_owned(&bang, output)?, "BANANA!");
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn process_owned(&mut self, id: &str, input: String) -> Result<String, AtpError> {
+        let mut result = input;
 
         let tokens = self.transforms.get(id).ok_or_else(token_array_not_found(id));
-        let mut context = GlobalExecutionContext::new();
+        let mut context = self.new_context();
 
         match tokens {
             Ok(tks) => {
-                for token in tks.iter() {
+                for (position, token) in tks.iter().enumerate() {
                     result = apply_transform(
                         token,
                         result.as_str(),
+                        position,
+                        id,
                         &mut self.errors,
                         &mut context
                     )?;
                 }
-                Ok(result.to_string())
+                Ok(result)
             }
             Err(e) => {
                 self.errors.add_error(e.clone());
@@ -538,22 +937,214 @@ impl AtpProcessorMethods for AtpProcessor {
         }
     }
 
-    fn add_transform(&mut self, tokens: Vec<TokenWrapper>) -> String {
-        let identifier = Uuid::new_v4().to_string();
-        self.transforms.insert(identifier.clone(), tokens);
-        identifier
-    }
-
-    fn remove_transform(&mut self, id: &str) -> Result<(), AtpError> {
-        match
-            self.transforms
-                .remove(id)
-                .ok_or_else(|| {
-                    AtpError::new(
-                        crate::utils::errors::AtpErrorCode::TokenNotFound(
-                            "Transformation not found".into()
-                        ),
-                        "remove_transform",
+    /// Runs the pipeline `id` like `process_all`, also returning which conditional branches fired.
+    ///
+    /// Each conditional instruction executed during the run (e.g. `ifdc`) adds one
+    /// `BranchRecord` in execution order, with `taken` set when its inner token was executed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atp::builder::atp_processor::AtpProcessor;
+    /// use atp::builder::{ AtpBuilderMethods, AtpConditionalMethods };
+    ///
+    /// let mut processor = AtpProcessor::new();
+    /// let id = processor
+    ///     .create_pipeline()
+    ///     .if_do_contains_each("xy", |b| {
+    ///         b.to_uppercase_all()?;
+    ///         Ok(())
+    ///     })?
+    ///     .build();
+    ///
+    /// let (output, branches) = processor.branch_report(&id, "banana")?;
+    ///
+    /// assert_eq!(output, "banana");
+    /// assert!(!branches[0].taken);
+    /// # Ok::<(), atp::utils::errors::AtpError>(())
+    /// ```
+    pub fn branch_report(
+        &mut self,
+        id: &str,
+        input: &str
+    ) -> Result<(String, Vec<BranchRecord>), AtpError> {
+        let tokens = match self.transforms.get(id).ok_or_else(token_array_not_found(id)) {
+            Ok(x) => x,
+            Err(e) => {
+                self.errors.add_error(e.clone());
+                return Err(e);
+            }
+        };
+
+        let mut result = String::from(input);
+        let mut context = self.new_context();
+        context.set_record_branches(true);
+
+        for (position, token) in tokens.iter().enumerate() {
+            result = apply_transform(
+                token,
+                result.as_str(),
+                position,
+                id,
+                &mut self.errors,
+                &mut context
+            )?;
+        }
+
+        Ok((result, context.take_branches()))
+    }
+}
+
+/// Drains the messages queued by tokens through `GlobalExecutionContext::debug_log`, one per line.
+fn take_debug_log(context: &mut GlobalExecutionContext) -> String {
+    context
+        .take_debug_messages()
+        .into_iter()
+        .map(|message| message + "\n")
+        .collect()
+}
+
+/// Writes debug output to `writer`, or to stdout when no writer was set.
+fn write_debug(writer: &mut Option<Box<dyn Write + Send>>, text: &str) -> Result<(), AtpError> {
+    match writer {
+        Some(w) =>
+            w.write_all(text.as_bytes()).map_err(|e| {
+                AtpError::new(
+                    AtpErrorCode::FileWritingError("Failed writing debug output".into()),
+                    "write_debug",
+                    e.to_string()
+                )
+            }),
+        None => {
+            print!("{text}");
+            Ok(())
+        }
+    }
+}
+
+impl AtpProcessorMethods for AtpProcessor {
+    fn write_to_text_file(&mut self, id: &str, path: &Path) -> Result<(), AtpError> {
+        let tokens = match self.transforms.get(id).ok_or_else(token_array_not_found(id)) {
+            Ok(x) => x,
+            Err(e) => {
+                self.errors.add_error(e.clone());
+                return Err(e);
+            }
+        };
+
+        write_to_file(Path::new(path), tokens)
+    }
+
+    fn read_from_text_file(&mut self, path: &Path) -> Result<String, AtpError> {
+        let tokens = match
+            read_from_file(Path::new(path)).and_then(|tokens| {
+                self.check_token_count(tokens.len())?;
+                Ok(tokens)
+            })
+        {
+            Ok(x) => x,
+            Err(e) => {
+                self.errors.add_error(e.clone());
+                return Err(e);
+            }
+        };
+
+        let identifier = Uuid::new_v4();
+
+        self.transforms.insert(identifier.to_string(), tokens);
+
+        Ok(identifier.to_string())
+    }
+
+    fn read_from_json(&mut self, json: &str) -> Result<String, AtpError> {
+        let tokens = match
+            PipelineSpec::from_json(json)
+                .and_then(|spec| spec.to_tokens())
+                .and_then(|tokens| {
+                    self.check_token_count(tokens.len())?;
+                    Ok(tokens)
+                })
+        {
+            Ok(x) => x,
+            Err(e) => {
+                self.errors.add_error(e.clone());
+                return Err(e);
+            }
+        };
+
+        Ok(self.add_transform(tokens))
+    }
+
+    fn to_json(&self, id: &str) -> Result<String, AtpError> {
+        let tokens = self.transforms.get(id).ok_or_else(token_array_not_found(id))?;
+
+        PipelineSpec::from_tokens(tokens)?.to_json()
+    }
+
+    fn validate_pipeline(&self, id: &str) -> Result<(), AtpError> {
+        let tokens = self.transforms.get(id).ok_or_else(token_array_not_found(id))?;
+
+        for (position, token) in tokens.iter().enumerate() {
+            check_token_consistency(token).map_err(|e|
+                e.with_position(position).with_pipeline_id(id)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_all(&mut self, id: &str, input: &str) -> Result<String, AtpError> {
+        self.process_owned(id, input.to_string())
+    }
+
+    fn process_all_lenient(
+        &mut self,
+        id: &str,
+        input: &str
+    ) -> Result<(String, Vec<AtpError>), AtpError> {
+        let mut result = String::from(input);
+        let mut token_errors: Vec<AtpError> = Vec::new();
+
+        let tokens = match self.transforms.get(id).ok_or_else(token_array_not_found(id)) {
+            Ok(x) => x,
+            Err(e) => {
+                self.errors.add_error(e.clone());
+                return Err(e);
+            }
+        };
+
+        let mut context = self.new_context();
+
+        for (position, token) in tokens.iter().enumerate() {
+            match
+                apply_transform(token, result.as_str(), position, id, &mut self.errors, &mut context)
+            {
+                Ok(x) => {
+                    result = x;
+                }
+                Err(e) => token_errors.push(e),
+            }
+        }
+
+        Ok((result, token_errors))
+    }
+
+    fn add_transform(&mut self, tokens: Vec<TokenWrapper>) -> String {
+        let identifier = Uuid::new_v4().to_string();
+        self.transforms.insert(identifier.clone(), tokens);
+        identifier
+    }
+
+    fn remove_transform(&mut self, id: &str) -> Result<(), AtpError> {
+        match
+            self.transforms
+                .remove(id)
+                .ok_or_else(|| {
+                    AtpError::new(
+                        crate::utils::errors::AtpErrorCode::TokenNotFound(
+                            "Transformation not found".into()
+                        ),
+                        "remove_transform",
                         id.to_string()
                     )
                 })
@@ -614,7 +1205,7 @@ impl AtpProcessorMethods for AtpProcessor {
     }
 
     fn process_single(&mut self, token: TokenWrapper, input: &str) -> Result<String, AtpError> {
-        let mut context = GlobalExecutionContext::new();
+        let mut context = self.new_context();
         match token.apply_token(input, &mut context) {
             Ok(x) => Ok(x),
             Err(e) => {
@@ -640,10 +1231,19 @@ impl AtpProcessorMethods for AtpProcessor {
         log.push_str(&"-".repeat(dashes));
         log.push_str("\n\n");
 
-        let mut context = GlobalExecutionContext::new();
+        let mut context = self.new_debug_context();
 
         for (counter, token) in (0_i64..).zip(tokens.iter()) {
-            let temp = apply_transform(token, result.as_str(), &mut self.errors, &mut context)?;
+            let temp = apply_transform(
+                token,
+                result.as_str(),
+                counter as usize,
+                id,
+                &mut self.errors,
+                &mut context
+            )?;
+
+            log.push_str(&take_debug_log(&mut context));
 
             if token.get_string_repr() == "blk" {
                 // Gambiarra feia, futuramente pensar em forma melhor de consultar os parâmetros de um token
@@ -694,7 +1294,7 @@ impl AtpProcessorMethods for AtpProcessor {
             result = temp;
         }
 
-        print!("{log}"); // 1 única saída
+        write_debug(&mut self.debug_writer, &log)?; // 1 única saída
         Ok(result)
     }
 
@@ -703,7 +1303,7 @@ impl AtpProcessorMethods for AtpProcessor {
         token: TokenWrapper,
         input: &str
     ) -> Result<String, AtpError> {
-        let mut ctx = GlobalExecutionContext::new();
+        let mut ctx = self.new_debug_context();
         let output = match token.apply_token(input, &mut ctx) {
             Ok(x) => x,
             Err(e) => {
@@ -711,14 +1311,18 @@ impl AtpProcessorMethods for AtpProcessor {
                 return Err(e);
             }
         };
-        println!(
-            "Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n",
-            (0).to_string().blue(),
-            (1).to_string().blue(),
-            token.to_atp_line().yellow(),
-            input.red(),
-            output.green()
-        );
+        write_debug(
+            &mut self.debug_writer,
+            &format!(
+                "{}Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n\n",
+                take_debug_log(&mut ctx),
+                (0).to_string().blue(),
+                (1).to_string().blue(),
+                token.to_atp_line().yellow(),
+                input.red(),
+                output.green()
+            )
+        )?;
 
         Ok(output)
     }
@@ -736,7 +1340,12 @@ impl AtpProcessorMethods for AtpProcessor {
     }
     #[cfg(feature = "bytecode")]
     fn read_from_bytecode_file(&mut self, path: &Path) -> Result<String, AtpError> {
-        let tokens = match read_bytecode_from_file(path) {
+        let tokens = match
+            read_bytecode_from_file(path).and_then(|tokens| {
+                self.check_token_count(tokens.len())?;
+                Ok(tokens)
+            })
+        {
             Ok(x) => x,
             Err(e) => {
                 self.errors.add_error(e.clone());
@@ -760,23 +1369,37 @@ impl AtpProcessorMethods for AtpProcessor {
 
         let tokens = self.transforms.get(id).ok_or_else(token_array_not_found(id))?;
 
-        println!("PROCESSING STEP BY STEP:\n{}\n", "-".repeat(dashes));
+        write_debug(
+            &mut self.debug_writer,
+            &format!("PROCESSING STEP BY STEP:\n{}\n\n", "-".repeat(dashes))
+        )?;
 
-        let mut context = GlobalExecutionContext::new();
+        let mut context = self.new_debug_context();
 
         for (counter, token) in (0_i64..).zip(tokens.iter()) {
-            let temp = apply_transform(token, result.as_str(), &mut self.errors, &mut context)?;
-            println!(
-                "Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n",
-                counter.to_string().blue(),
-                (counter + 1).to_string().blue(),
-                token.to_atp_line().yellow(),
-                result.red(),
-                temp.green()
-            );
+            let temp = apply_transform(
+                token,
+                result.as_str(),
+                counter as usize,
+                id,
+                &mut self.errors,
+                &mut context
+            )?;
+            write_debug(
+                &mut self.debug_writer,
+                &format!(
+                    "{}Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n\n",
+                    take_debug_log(&mut context),
+                    counter.to_string().blue(),
+                    (counter + 1).to_string().blue(),
+                    token.to_atp_line().yellow(),
+                    result.red(),
+                    temp.green()
+                )
+            )?;
 
             if (counter as usize) < tokens.len() {
-                println!("{}\n", "-".repeat(dashes));
+                write_debug(&mut self.debug_writer, &format!("{}\n\n", "-".repeat(dashes)))?;
             }
 
             result = temp;
@@ -790,7 +1413,7 @@ impl AtpProcessorMethods for AtpProcessor {
         token: TokenWrapper,
         input: &str
     ) -> Result<String, AtpError> {
-        let mut ctx = GlobalExecutionContext::new();
+        let mut ctx = self.new_debug_context();
         let output = match token.apply_token(input, &mut ctx) {
             Ok(x) => x,
             Err(e) => {
@@ -798,15 +1421,1080 @@ impl AtpProcessorMethods for AtpProcessor {
                 return Err(e);
             }
         };
-        println!(
-            "Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n",
-            (0).to_string().blue(),
-            (1).to_string().blue(),
-            token.to_atp_line().yellow(),
-            input.red(),
-            output.green()
-        );
+        write_debug(
+            &mut self.debug_writer,
+            &format!(
+                "{}Step: [{}] => [{}]\nInstruction: {}\nBefore: {}\nAfter: {}\n\n",
+                take_debug_log(&mut ctx),
+                (0).to_string().blue(),
+                (1).to_string().blue(),
+                token.to_atp_line().yellow(),
+                input.red(),
+                output.green()
+            )
+        )?;
 
         Ok(output)
     }
 }
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod processor_tests {
+    mod process_all_lenient_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn keeps_running_after_a_failing_token() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("b")?
+                .delete_single(100)?
+                .add_to_end("l")?
+                .build();
+
+            let (result, errors) = processor.process_all_lenient(&id, "anana")?;
+
+            assert_eq!(result, "bananal".to_string());
+            assert_eq!(errors.len(), 1);
+            assert!(matches!(errors[0].error_code, AtpErrorCode::IndexOutOfRange(_)));
+            Ok(())
+        }
+
+        #[test]
+        fn process_all_aborts_on_the_same_pipeline() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("b")?
+                .delete_single(100)?
+                .add_to_end("l")?
+                .build();
+
+            let err = processor.process_all(&id, "anana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+            Ok(())
+        }
+
+        #[test]
+        fn returns_no_errors_when_every_token_succeeds() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor.create_pipeline().add_to_beginning("b")?.add_to_end("l")?.build();
+
+            let (result, errors) = processor.process_all_lenient(&id, "anana")?;
+
+            assert_eq!(result, "bananal".to_string());
+            assert!(errors.is_empty());
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_still_fatal() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.process_all_lenient("banana", "anana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    mod error_position_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn failing_third_token_reports_its_position() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("b")?
+                .add_to_end("l")?
+                .delete_single(100)?
+                .build();
+
+            let err = processor.process_all(&id, "anana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+            assert_eq!(err.position, Some(2));
+            assert_eq!(err.pipeline_id.as_deref(), Some(id.as_str()));
+            Ok(())
+        }
+
+        #[test]
+        fn lenient_errors_carry_position_and_pipeline_id() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .delete_single(100)?
+                .add_to_end("l")?
+                .delete_single(200)?
+                .build();
+
+            let (_, errors) = processor.process_all_lenient(&id, "anana")?;
+
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[0].position, Some(0));
+            assert_eq!(errors[1].position, Some(2));
+            assert!(errors.iter().all(|e| e.pipeline_id.as_deref() == Some(id.as_str())));
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_has_no_position() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.process_all("banana", "anana").unwrap_err();
+
+            assert_eq!(err.position, None);
+            assert_eq!(err.pipeline_id, None);
+        }
+    }
+
+    mod read_from_json_tests {
+        use crate::{
+            api::atp_processor::{ AtpProcessor, AtpProcessorMethods },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn loads_a_valid_spec() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor.read_from_json(
+                r#"[
+                    { "op": "atb", "args": ["b"] },
+                    { "op": "ate", "args": ["l"] },
+                    { "op": "tua" }
+                ]"#
+            )?;
+
+            assert_eq!(processor.process_all(&id, "anana")?, "BANANAL".to_string());
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_op_is_token_not_found() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor
+                .read_from_json(r#"[{ "op": "banana", "args": [] }]"#)
+                .unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenNotFound(_)));
+        }
+
+        #[test]
+        fn missing_args_are_rejected() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.read_from_json(r#"[{ "op": "raw", "args": ["a"] }]"#).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+
+        #[test]
+        fn extra_args_are_rejected() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor
+                .read_from_json(r#"[{ "op": "tua", "args": ["unexpected"] }]"#)
+                .unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+
+        #[test]
+        fn malformed_json_is_rejected() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.read_from_json(r#"[{ "op": "tua" }"#).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+    }
+
+    mod to_json_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn round_trips_through_read_from_json() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("b")?
+                .replace_all_with("a", "o")?
+                .delete_first()?
+                .to_uppercase_all()?
+                .build();
+
+            let json = processor.to_json(&id)?;
+            let loaded = processor.read_from_json(&json)?;
+
+            let input = "banana";
+            assert_eq!(processor.process_all(&loaded, input)?, processor.process_all(&id, input)?);
+            assert_eq!(processor.to_json(&loaded)?, json);
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_token_array_not_found() {
+            let processor = AtpProcessor::new();
+
+            let err = processor.to_json("banana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    mod locale_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::AtpError,
+        };
+
+        #[test]
+        fn default_locale_uses_unicode_case_mapping() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().to_uppercase_all()?.build();
+
+            assert_eq!(processor.process_all(&id, "i")?, "I");
+            Ok(())
+        }
+
+        #[test]
+        fn turkish_locale_uppercases_to_dotted_i() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_locale("tr");
+            let id = processor.create_pipeline().to_uppercase_all()?.build();
+
+            assert_eq!(processor.process_all(&id, "i")?, "İ");
+            Ok(())
+        }
+
+        #[test]
+        fn turkish_locale_lowercases_to_dotless_i() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_locale("tr-TR");
+            let id = processor.create_pipeline().to_lowercase_all()?.to_uppercase_word(1)?.build();
+
+            assert_eq!(processor.process_all(&id, "IRMAK istanbul")?, "ırmak İSTANBUL");
+            Ok(())
+        }
+
+        #[test]
+        fn clear_locale_restores_default_mapping() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_locale("tr");
+            processor.clear_locale();
+            let id = processor.create_pipeline().to_uppercase_all()?.build();
+
+            assert_eq!(processor.get_locale(), None);
+            assert_eq!(processor.process_all(&id, "i")?, "I");
+            Ok(())
+        }
+    }
+
+    mod register_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn process_all_interpolates_processor_registers() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_register("fruit", "banana");
+            processor.set_register("n", "3");
+            let id = processor
+                .create_pipeline()
+                .interpolate_registers()?
+                .to_uppercase_all()?
+                .build();
+
+            assert_eq!(processor.process_all(&id, "${n} ${fruit}s, ${x}")?, "3 BANANAS, ${X}");
+            assert_eq!(processor.process_owned(&id, "${fruit}".to_string())?, "BANANA");
+            Ok(())
+        }
+
+        #[test]
+        fn strict_mode_succeeds_once_the_register_is_set() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().interpolate_registers_with(true)?.build();
+
+            let err = processor.process_all(&id, "${fruit}").unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::VariableNotFound(_)));
+
+            processor.set_register("fruit", "laranja");
+            assert_eq!(processor.process_all(&id, "${fruit}")?, "laranja");
+            Ok(())
+        }
+
+        #[test]
+        fn removed_registers_are_not_interpolated() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_register("a", "1");
+            processor.set_register("b", "2");
+            let id = processor.create_pipeline().interpolate_registers()?.build();
+
+            assert_eq!(processor.remove_register("a"), Some("1".to_string()));
+            assert_eq!(processor.process_all(&id, "${a}${b}")?, "${a}2");
+
+            processor.clear_registers();
+            assert_eq!(processor.get_register("b"), None);
+            assert_eq!(processor.process_all(&id, "${a}${b}")?, "${a}${b}");
+            Ok(())
+        }
+    }
+
+    mod index_policy_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::{ errors::{ AtpError, AtpErrorCode }, validations::IndexPolicy },
+        };
+
+        #[test]
+        fn strict_policy_rejects_out_of_range_index() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_index_policy(IndexPolicy::Strict);
+            let id = processor.create_pipeline().delete_single(10)?.build();
+
+            let err = processor.process_all(&id, "banana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+            Ok(())
+        }
+
+        #[test]
+        fn ignore_policy_leaves_input_unchanged() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_index_policy(IndexPolicy::Ignore);
+            let id = processor.create_pipeline().delete_single(10)?.build();
+
+            assert_eq!(processor.process_all(&id, "banana")?, "banana");
+            Ok(())
+        }
+
+        #[test]
+        fn clamp_policy_uses_last_word() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_index_policy(IndexPolicy::Clamp);
+            let id = processor.create_pipeline().to_uppercase_word(10)?.build();
+
+            assert_eq!(processor.process_all(&id, "foo bar")?, "foo BAR");
+            Ok(())
+        }
+
+        #[test]
+        fn without_policy_tokens_keep_their_default() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_index_policy(IndexPolicy::Ignore);
+            processor.clear_index_policy();
+            let id = processor.create_pipeline().delete_single(10)?.build();
+
+            assert_eq!(processor.get_index_policy(), None);
+            assert!(processor.process_all(&id, "banana").is_err());
+            Ok(())
+        }
+    }
+
+    mod compose_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn composed_pipeline_matches_running_both_in_sequence() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let trim = processor.create_pipeline().trim_both_sides()?.build();
+            let upper = processor.create_pipeline().to_uppercase_all()?.add_to_end("!")?.build();
+
+            let composed = processor.compose(&trim, &upper)?;
+
+            let input = "  banana  ";
+            let sequential = {
+                let trimmed = processor.process_all(&trim, input)?;
+                processor.process_all(&upper, &trimmed)?
+            };
+
+            assert_eq!(processor.process_all(&composed, input)?, sequential);
+            assert_eq!(processor.get_transform_vec(&composed)?.len(), 3);
+            Ok(())
+        }
+
+        #[test]
+        fn sources_are_kept() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let trim = processor.create_pipeline().trim_both_sides()?.build();
+            let upper = processor.create_pipeline().to_uppercase_all()?.build();
+
+            processor.compose(&trim, &upper)?;
+
+            assert!(processor.transform_exists(&trim));
+            assert_eq!(processor.process_all(&upper, " a ")?, " A ");
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_id_is_token_array_not_found() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let trim = processor.create_pipeline().trim_both_sides()?.build();
+
+            let err = processor.compose(&trim, "banana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+            Ok(())
+        }
+    }
+
+    mod validate_pipeline_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            globals::var::{ TokenWrapper, ValType },
+            tokens::{ InstructionMethods, transforms::{ ate::Ate, raw::Raw } },
+            utils::{ errors::{ AtpError, AtpErrorCode }, params::AtpParamTypes },
+        };
+
+        #[test]
+        fn consistent_pipeline_passes() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("b")?
+                .replace_all_with("a", "o")?
+                .pad_to_multiple("-", 4)?
+                .default_if_empty("N/A", true)?
+                .build();
+
+            processor.validate_pipeline(&id)
+        }
+
+        #[test]
+        fn json_pipeline_with_variables_passes() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.read_from_json(r#"[{"op":"ate","args":["{{suffix}}"]}]"#)?;
+
+            processor.validate_pipeline(&id)
+        }
+
+        #[test]
+        fn wrong_param_count_fails_with_position() {
+            let mut processor = AtpProcessor::new();
+            let broken = TokenWrapper::new(Box::new(Ate::default()), Some(vec![]));
+            let valid = TokenWrapper::new(Box::new(Ate::new("!")), None);
+            let id = processor.add_transform(vec![valid, broken]);
+
+            let err = processor.validate_pipeline(&id).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+            assert_eq!(err.position, Some(1));
+            assert_eq!(err.pipeline_id, Some(id));
+        }
+
+        #[test]
+        fn wrong_param_type_fails() {
+            let mut processor = AtpProcessor::new();
+            let broken = TokenWrapper::new(
+                Box::new(Raw::default()),
+                Some(
+                    vec![
+                        ValType::Literal(AtpParamTypes::Usize(1)),
+                        ValType::Literal(AtpParamTypes::String("b".to_string()))
+                    ]
+                )
+            );
+            let id = processor.add_transform(vec![broken]);
+
+            let err = processor.validate_pipeline(&id).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+            assert_eq!(err.position, Some(0));
+        }
+
+        #[test]
+        fn params_rejected_by_from_params_fail() {
+            let mut processor = AtpProcessor::new();
+            let broken = TokenWrapper::new(
+                Box::new(Raw::default()),
+                Some(
+                    vec![
+                        ValType::Literal(AtpParamTypes::String("(".to_string())),
+                        ValType::Literal(AtpParamTypes::String("b".to_string()))
+                    ]
+                )
+            );
+            let id = processor.add_transform(vec![broken]);
+
+            let err = processor.validate_pipeline(&id).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+
+        #[test]
+        fn unknown_identifier_is_token_array_not_found() {
+            let processor = AtpProcessor::new();
+
+            let err = processor.validate_pipeline("banana").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    mod debug_writer_tests {
+        use std::{ io::Write, sync::{ Arc, Mutex } };
+
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            globals::var::TokenWrapper,
+            tokens::{ InstructionMethods, transforms::ate::Ate },
+            utils::errors::AtpError,
+        };
+
+        #[derive(Clone, Default)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl SharedBuffer {
+            fn contents(&self) -> String {
+                String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+            }
+        }
+
+        #[test]
+        fn debug_output_goes_to_the_writer() -> Result<(), AtpError> {
+            let buffer = SharedBuffer::default();
+            let mut processor = AtpProcessor::new();
+            processor.set_debug_writer(Box::new(buffer.clone()));
+
+            let id = processor.create_pipeline().add_to_beginning("b")?.add_to_end("!")?.build();
+
+            let out = processor.process_all_with_debug(&id, "anana")?;
+            let log = buffer.contents();
+
+            assert_eq!(out, "banana!");
+            assert!(log.contains("PROCESSING STEP BY STEP"));
+            assert!(log.contains("atb b;"));
+            assert!(log.contains("ate !;"));
+            assert_eq!(log.matches("Instruction:").count(), 2);
+            Ok(())
+        }
+
+        #[test]
+        fn debug_result_matches_process_all() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_debug_writer(Box::new(SharedBuffer::default()));
+
+            let id = processor.create_pipeline().trim_both_sides()?.to_uppercase_all()?.build();
+
+            assert_eq!(
+                processor.process_all_with_debug(&id, "  banana  ")?,
+                processor.process_all(&id, "  banana  ")?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn single_debug_output_goes_to_the_writer() -> Result<(), AtpError> {
+            let buffer = SharedBuffer::default();
+            let mut processor = AtpProcessor::new();
+            processor.set_debug_writer(Box::new(buffer.clone()));
+
+            let token: Box<dyn InstructionMethods> = Box::new(Ate::new("!"));
+
+            assert_eq!(processor.process_single_with_debug(TokenWrapper::from(token), "banana")?, "banana!");
+            assert!(buffer.contents().contains("ate !;"));
+            Ok(())
+        }
+
+        #[test]
+        fn mark_writes_to_the_debug_output_and_passes_input_through() -> Result<(), AtpError> {
+            let buffer = SharedBuffer::default();
+            let mut processor = AtpProcessor::new();
+            processor.set_debug_writer(Box::new(buffer.clone()));
+
+            let id = processor
+                .create_pipeline()
+                .trim_both_sides()?
+                .mark("after trim")?
+                .add_to_end("!")?
+                .build();
+
+            let out = processor.process_all_with_debug(&id, "  banana \t")?;
+            let log = buffer.contents();
+
+            assert_eq!(out, "banana!");
+            assert!(log.contains("[mark: after trim] banana\n"));
+            assert_eq!(processor.process_all(&id, "  banana \t")?, out);
+            Ok(())
+        }
+    }
+
+    mod process_bytes_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn valid_utf8_matches_process_all() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().to_uppercase_all()?.build();
+
+            assert_eq!(
+                processor.process_bytes(&id, "maçã".as_bytes())?,
+                processor.process_all(&id, "maçã")?.into_bytes()
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn invalid_utf8_runs_on_replacement_chars() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().to_uppercase_all()?.add_to_end("!")?.build();
+
+            let out = processor.process_bytes(&id, b"ab\xff\xfecd")?;
+
+            assert_eq!(String::from_utf8(out).unwrap(), "AB\u{FFFD}\u{FFFD}CD!");
+            Ok(())
+        }
+
+        #[test]
+        fn truncated_sequence_is_replaced() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().add_to_beginning(">")?.build();
+
+            // First two bytes of "€" (e2 82 ac)
+            let out = processor.process_bytes(&id, b"x\xe2\x82")?;
+
+            assert_eq!(String::from_utf8(out).unwrap(), ">x\u{FFFD}");
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_token_array_not_found() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.process_bytes("missing", b"\xff").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    mod max_tokens_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn builder_rejects_the_token_over_the_limit() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_max_tokens(3);
+
+            let mut builder = processor.create_pipeline();
+            builder.trim_both_sides()?.to_uppercase_all()?.add_to_end("!")?;
+            let err = builder.add_to_end("?").err().unwrap();
+            let id = builder.build();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+            assert_eq!(processor.process_all(&id, " banana ")?, "BANANA!");
+            Ok(())
+        }
+
+        #[test]
+        fn no_limit_by_default() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            assert_eq!(processor.get_max_tokens(), None);
+
+            let mut builder = processor.create_pipeline();
+            for _ in 0..100 {
+                builder.add_to_end("a")?;
+            }
+            let id = builder.build();
+
+            assert_eq!(processor.process_all(&id, "")?, "a".repeat(100));
+            Ok(())
+        }
+
+        #[test]
+        fn clear_max_tokens_removes_the_limit() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_max_tokens(1);
+            processor.clear_max_tokens();
+
+            processor.create_pipeline().trim_both_sides()?.to_uppercase_all()?;
+            Ok(())
+        }
+
+        #[test]
+        fn text_file_over_the_limit_is_rejected() {
+            let mut path = std::env::temp_dir();
+            path.push(format!("atp_max_tokens_{}.atp", std::process::id()));
+            std::fs::write(&path, "tbs;\ntua;\nate !;\n").unwrap();
+
+            let mut processor = AtpProcessor::new();
+            processor.set_max_tokens(2);
+            let rejected = processor.read_from_text_file(&path);
+            processor.set_max_tokens(3);
+            let accepted = processor.read_from_text_file(&path);
+            let _ = std::fs::remove_file(&path);
+
+            assert!(
+                matches!(rejected, Err(e) if matches!(e.error_code, AtpErrorCode::InvalidParameters(_)))
+            );
+            assert!(accepted.is_ok());
+        }
+
+        #[test]
+        fn json_and_compose_respect_the_limit() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            processor.set_max_tokens(2);
+
+            let first = processor.read_from_json(r#"[{"op":"tbs"},{"op":"tua"}]"#)?;
+            let second = processor.read_from_json(r#"[{"op":"rev"}]"#)?;
+            assert!(processor.read_from_json(r#"[{"op":"tbs"},{"op":"tua"},{"op":"rev"}]"#).is_err());
+
+            let err = processor.compose(&first, &second).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+            Ok(())
+        }
+    }
+
+    mod process_all_batch_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn results_follow_input_order() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().to_uppercase_all()?.build();
+
+            let results = processor.process_all_batch(&id, &["banana", "", "laranja"]);
+
+            assert_eq!(
+                results,
+                vec![Ok("BANANA".to_string()), Ok("".to_string()), Ok("LARANJA".to_string())]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn failing_inputs_do_not_affect_the_others() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().delete_single(3)?.add_to_end("!")?.build();
+
+            let results = processor.process_all_batch(&id, &["banana", "abc", "laranja", "x"]);
+
+            assert_eq!(results.len(), 4);
+            assert_eq!(results[0], Ok("banna!".to_string()));
+            assert!(
+                matches!(
+                    &results[1],
+                    Err(e) if matches!(e.error_code, AtpErrorCode::IndexOutOfRange(_))
+                )
+            );
+            assert_eq!(results[2], Ok("larnja!".to_string()));
+            assert!(results[3].is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn empty_batch_returns_empty_vec() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().trim_both_sides()?.build();
+
+            assert!(processor.process_all_batch(&id, &[]).is_empty());
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_fails_every_item() {
+            let mut processor = AtpProcessor::new();
+
+            let results = processor.process_all_batch("missing", &["a", "b"]);
+
+            assert_eq!(results.len(), 2);
+            for result in results {
+                assert!(
+                    matches!(result, Err(e) if matches!(e.error_code, AtpErrorCode::TokenArrayNotFound(_)))
+                );
+            }
+        }
+    }
+
+    mod process_owned_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn output_matches_process_all() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor
+                .create_pipeline()
+                .trim_both_sides()?
+                .to_uppercase_all()?
+                .add_to_end("!")?
+                .build();
+
+            for input in ["  banana ", "", "laranja"] {
+                let expected = processor.process_all(&id, input)?;
+                assert_eq!(processor.process_owned(&id, input.to_string())?, expected);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn output_is_independent_of_the_input() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().add_to_end("!")?.build();
+
+            let input = String::from("banana");
+            let copy = input.clone();
+            let mut output = processor.process_owned(&id, copy)?;
+            output.push('?');
+
+            assert_eq!(input, "banana");
+            assert_eq!(output, "banana!?");
+            Ok(())
+        }
+
+        #[test]
+        fn empty_pipeline_reuses_the_input_buffer() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().build();
+
+            let input = String::from("banana");
+            let ptr = input.as_ptr();
+            let output = processor.process_owned(&id, input)?;
+
+            assert_eq!(output, "banana");
+            assert_eq!(output.as_ptr(), ptr);
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_an_error() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.process_owned("missing", "a".to_string()).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    mod branch_report_tests {
+        use crate::{
+            api::{
+                AtpBuilderMethods,
+                AtpConditionalMethods,
+                atp_processor::{ AtpProcessor, AtpProcessorMethods },
+            },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        fn conditional_pipeline(processor: &mut AtpProcessor) -> Result<String, AtpError> {
+            Ok(
+                processor
+                    .create_pipeline()
+                    .if_do_contains_each("xy", |b| {
+                        b.to_uppercase_all()?;
+                        Ok(())
+                    })?
+                    .if_do_contains_each("banana", |b| {
+                        b.add_to_end("!")?;
+                        Ok(())
+                    })?
+                    .build()
+            )
+        }
+
+        #[test]
+        fn matching_condition_is_reported_as_taken() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = conditional_pipeline(&mut processor)?;
+
+            let (output, branches) = processor.branch_report(&id, "axyb")?;
+
+            assert_eq!(output, "AXYB");
+            assert_eq!(branches.len(), 2);
+            assert!(branches[0].instruction.starts_with("ifdc xy do"));
+            assert!(branches[0].taken);
+            assert!(!branches[1].taken);
+            Ok(())
+        }
+
+        #[test]
+        fn non_matching_condition_is_reported_as_skipped() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = conditional_pipeline(&mut processor)?;
+
+            let (output, branches) = processor.branch_report(&id, "banana")?;
+
+            assert_eq!(output, "banana!");
+            assert_eq!(
+                branches
+                    .iter()
+                    .map(|b| b.taken)
+                    .collect::<Vec<_>>(),
+                vec![false, true]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn reports_are_not_shared_between_runs() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = conditional_pipeline(&mut processor)?;
+
+            processor.branch_report(&id, "xy")?;
+            let (_, branches) = processor.branch_report(&id, "xy")?;
+
+            assert_eq!(branches.len(), 2);
+            assert_eq!(processor.process_all(&id, "xy"), Ok("XY".to_string()));
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_an_error() {
+            let mut processor = AtpProcessor::new();
+
+            let err = processor.branch_report("missing", "a").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_size_tests {
+        use crate::{
+            api::{ AtpBuilderMethods, atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+            bytecode::writer::BYTECODE_HEADER_SIZE,
+            tokens::{ InstructionMethods, transforms::{ atb::Atb, raw::Raw, rpt::Rpt, tbs::Tbs } },
+            utils::errors::{ AtpError, AtpErrorCode },
+        };
+
+        #[test]
+        fn empty_pipeline_is_only_the_header() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().build();
+
+            assert_eq!(processor.bytecode_size(&id)?, BYTECODE_HEADER_SIZE);
+            Ok(())
+        }
+
+        #[test]
+        fn zero_arg_tokens_have_a_fixed_size() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor
+                .create_pipeline()
+                .trim_both_sides()?
+                .to_uppercase_all()?
+                .delete_first()?
+                .build();
+
+            // u64 size + u32 opcode + u8 param count
+            assert_eq!(processor.bytecode_size(&id)?, BYTECODE_HEADER_SIZE + 3 * 13);
+            Ok(())
+        }
+
+        #[test]
+        fn parameterized_tokens_add_their_encoded_params() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor
+                .create_pipeline()
+                .trim_both_sides()?
+                .add_to_beginning("banana")?
+                .replace_all_with("a", "e")?
+                .repeat(3)?
+                .build();
+
+            let expected =
+                BYTECODE_HEADER_SIZE +
+                Tbs::default().to_bytecode().len() +
+                Atb::new("banana").to_bytecode().len() +
+                Raw::new("a", "e").unwrap().to_bytecode().len() +
+                Rpt::new(3).to_bytecode().len();
+
+            assert_eq!(processor.bytecode_size(&id)?, expected);
+            assert!(expected > BYTECODE_HEADER_SIZE + 4 * 13);
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_identifier_is_token_array_not_found() {
+            let processor = AtpProcessor::new();
+
+            let err = processor.bytecode_size("missing").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenArrayNotFound(_)));
+        }
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_file_tests {
+        use crate::{
+            api::atp_processor::{ AtpProcessor, AtpProcessorMethods },
+            text::reader::read_from_text,
+            utils::errors::AtpError,
+        };
+
+        #[test]
+        fn written_bytecode_reads_back_the_same_pipeline() -> Result<(), AtpError> {
+            let lines = [
+                "tbs;\n",
+                "raw a+ e;\n",
+                "sslt _ 1;\n",
+                "ate {{suffix}};\n",
+            ];
+
+            let mut processor = AtpProcessor::new();
+            let tokens = lines
+                .iter()
+                .map(|line| read_from_text(line))
+                .collect::<Result<Vec<_>, AtpError>>()?;
+            let id = processor.add_transform(tokens);
+
+            let mut path = std::env::temp_dir();
+            path.push(format!("atp_processor_roundtrip_{}.atpbc", std::process::id()));
+            std::fs::write(&path, b"").unwrap();
+
+            let written = processor.write_to_bytecode_file(&id, &path);
+            let read = written.and_then(|_| processor.read_from_bytecode_file(&path));
+            let _ = std::fs::remove_file(&path);
+            let read_id = read?;
+
+            assert_eq!(processor.get_text_transform_vec(&read_id)?, lines);
+            Ok(())
+        }
+    }
+}
@@ -9,6 +9,7 @@ pub struct ConditionalBuilderEach {
     token: Box<dyn InstructionMethods>,
     params: Vec<AtpParamTypes>,
     conditional_tokens: Vec<Box<dyn InstructionMethods>>,
+    inner_tokens: Vec<TokenWrapper>,
 }
 
 impl ConditionalBuilderEach {
@@ -17,26 +18,46 @@ impl ConditionalBuilderEach {
             token,
             params,
             conditional_tokens: Vec::new(),
+            inner_tokens: Vec::new(),
         }
     }
 
+    /// One conditional token for each pushed token.
     pub fn build(self) -> Vec<Box<dyn InstructionMethods>> {
         self.conditional_tokens
     }
+
+    /// A single conditional token holding every pushed token as its inner tokens,
+    /// `None` when nothing was pushed.
+    pub fn build_combined(self) -> Result<Option<Box<dyn InstructionMethods>>, AtpError> {
+        if self.inner_tokens.is_empty() {
+            return Ok(None);
+        }
+
+        let mut token = self.token;
+        let mut param_vec = self.params;
+        param_vec.extend(self.inner_tokens.into_iter().map(|t| t.into()));
+
+        token.from_params(&param_vec)?;
+
+        Ok(Some(token))
+    }
 }
 
 // push_token funciona normalmente para incrementar conditional_tokens
 impl AtpBuilderMethods for ConditionalBuilderEach {
     fn push_token(&mut self, t: impl Into<TokenWrapper>) -> Result<(), AtpError> {
+        let inner: TokenWrapper = t.into();
         let mut new_token: Box<dyn InstructionMethods> = self.token.clone();
 
         let mut param_vec = self.params.clone();
 
-        param_vec.push(t.into().into());
+        param_vec.push(inner.clone().into());
 
         new_token.from_params(&param_vec)?;
 
         self.conditional_tokens.push(new_token);
+        self.inner_tokens.push(inner);
 
         Ok(())
     }
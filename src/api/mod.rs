@@ -2,12 +2,15 @@ pub mod atp_builder;
 pub mod atp_processor;
 pub mod conditional_builder;
 pub mod block_builder;
+pub mod pipeline_spec;
+pub mod pipeline_op;
 
 use crate::api::block_builder::BlockBuilder;
 use crate::api::conditional_builder::ConditionalBuilderEach;
+use crate::api::pipeline_op::PipelineOp;
 use crate::globals::var::TokenWrapper;
 use crate::tokens::instructions::cblk::Cblk;
-use crate::tokens::instructions::ifdc;
+use crate::tokens::instructions::{ ifdc, interp, mapl, mapw };
 use crate::tokens::transforms::ate::Ate;
 use crate::tokens::transforms::tbs::Tbs;
 use crate::tokens::transforms::tls::Tls;
@@ -828,6 +831,31 @@ pub trait AtpBuilderMethods: Sized {
         self.push_token(tok)?;
         Ok(self)
     }
+    /// CFW - Capitalize First Word (with smart flag)
+    ///
+    /// Same as `capitalize_first_word`, but when `smart` is true letters after apostrophes
+    /// and hyphens are capitalized too.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use atp::builder::atp_builder::AtpBuilder;
+    /// use atp::builder::atp_processor::AtpProcessorMethods;
+    ///
+    /// let (mut processor, id) =
+    ///     AtpBuilder::new().capitalize_first_word_with(true).build();
+    ///
+    /// let input = "o'brien is here";
+    ///
+    /// assert_eq!(
+    ///     processor.process_all(&id,&input),
+    ///     Ok("O'Brien is here".to_string())
+    /// );
+    /// ```
+    fn capitalize_first_word_with(&mut self, smart: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(cfw::Cfw::default().with_smart(smart));
+        self.push_token(tok)?;
+        Ok(self)
+    }
     /// CLW - Capitalize Last Word
     ///
     /// Capitalizes the **last word** of the input string.
@@ -932,6 +960,22 @@ pub trait AtpBuilderMethods: Sized {
         self.push_token(tok)?;
         Ok(self)
     }
+    /// CTC - Capitalize Chunk (with smart flag)
+    ///
+    /// Same as `capitalize_chunk`, but when `smart` is true letters after apostrophes
+    /// and hyphens are capitalized too.
+    fn capitalize_chunk_with(
+        &mut self,
+        start_index: usize,
+        end_index: usize,
+        smart: bool
+    ) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(
+            ctc::Ctc::new(start_index, end_index)?.with_smart(smart)
+        );
+        self.push_token(tok)?;
+        Ok(self)
+    }
     /// CTR - Capitalize Range
     ///
     /// Capitalizes all characters in `input` from `start_index` (inclusive) to `end_index`
@@ -969,6 +1013,22 @@ pub trait AtpBuilderMethods: Sized {
         self.push_token(tok)?;
         Ok(self)
     }
+    /// CTR - Capitalize Range (with smart flag)
+    ///
+    /// Same as `capitalize_range`, but when `smart` is true letters after apostrophes
+    /// and hyphens are capitalized too, e.g. `"jean-paul"` becomes `"Jean-Paul"`.
+    fn capitalize_range_with(
+        &mut self,
+        start_index: usize,
+        end_index: usize,
+        smart: bool
+    ) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(
+            ctr::Ctr::new(start_index, end_index)?.with_smart(smart)
+        );
+        self.push_token(tok)?;
+        Ok(self)
+    }
     /// CTS - Capitalize Single Word
     ///
     /// Capitalizes the word located at the given `index` in `input`.
@@ -999,6 +1059,31 @@ pub trait AtpBuilderMethods: Sized {
         self.push_token(tok)?;
         Ok(self)
     }
+    /// CTS - Capitalize Single Word (with smart flag)
+    ///
+    /// Same as `capitalize_single_word`, but when `smart` is true letters after apostrophes
+    /// and hyphens are capitalized too.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use atp::builder::atp_builder::AtpBuilder;
+    /// use atp::builder::atp_processor::AtpProcessorMethods;
+    ///
+    /// let (mut processor, id) = AtpBuilder::new()
+    ///     .capitalize_single_word_with(1, true)
+    ///     .build();
+    ///
+    /// let input = "hello jean-paul";
+    /// assert_eq!(
+    ///     processor.process_all(&id, &input),
+    ///     Ok("hello Jean-Paul".to_string())
+    /// );
+    /// ```
+    fn capitalize_single_word_with(&mut self, index: usize, smart: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(cts::Cts::new(index).with_smart(smart));
+        self.push_token(tok)?;
+        Ok(self)
+    }
     /// URLE - URL Encode
     ///
     /// Converts the entire `input` string into its URL-encoded form
@@ -1115,6 +1200,27 @@ pub trait AtpBuilderMethods: Sized {
         Ok(self)
     }
 
+    /// Split Characters With
+    ///
+    /// Same as `split_characters`, but joins the characters with `separator` instead of a space.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().split_characters_with(",").build();
+    /// let input = "hello";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("h,e,l,l,o".to_string()));
+    /// ```
+    fn split_characters_with(&mut self, separator: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(splc::Splc::new(separator));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
     /// HTMLE - HTML Escape
     ///
     /// Escapes HTML special characters such as `<`, `>`, `"`, `'`, `&`.
@@ -1492,21 +1598,1821 @@ pub trait AtpBuilderMethods: Sized {
         self.push_token(tok)?;
         Ok(self)
     }
-}
 
-pub trait AtpConditionalMethods: AtpBuilderMethods {
-    fn if_do_contains_each<F>(&mut self, value: &str, f: F) -> Result<&mut Self, AtpError>
-        where F: FnOnce(&mut ConditionalBuilderEach) -> Result<(), AtpError>
-    {
-        let params = vec![AtpParamTypes::String(value.to_string())];
-        let token: Box<dyn InstructionMethods> = Box::new(ifdc::Ifdc::default());
-        let mut conditional_builder = ConditionalBuilderEach::new(token, params);
+    /// LSTRIPTO - Left Strip To
+    ///
+    /// Removes everything up to and including the first occurrence of `ch` in `input`
+    ///
+    /// If `ch` is not found, `input` is returned unchanged
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().strip_to("/").build();
+    /// let input = "home/user/file.txt";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("user/file.txt".to_string()));
+    /// ```
+    fn strip_to(&mut self, ch: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(lstripto::Lstripto::new(ch));
+        self.push_token(tok)?;
+        Ok(self)
+    }
 
-        f(&mut conditional_builder)?;
+    /// RSTRIPTO - Right Strip To
+    ///
+    /// Removes everything from the last occurrence of `ch` in `input` onward, including `ch`
+    ///
+    /// If `ch` is not found, `input` is returned unchanged
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().strip_from(".").build();
+    /// let input = "archive.tar.gz";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("archive.tar".to_string()));
+    /// ```
+    fn strip_from(&mut self, ch: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(rstripto::Rstripto::new(ch));
+        self.push_token(tok)?;
+        Ok(self)
+    }
 
-        let result = conditional_builder.build();
+    /// SLUG - Slugify
+    ///
+    /// Lowercases `input`, folds accented characters to ASCII, replaces every run of
+    /// non alphanumeric characters with a single `-` and trims leading/trailing hyphens
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_slug().build();
+    /// let input = "Héllo, World!";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("hello-world".to_string()));
+    /// ```
+    fn to_slug(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(slug::Slug::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
 
-        for token in result.into_iter() {
+    /// ABBR - Abbreviate
+    ///
+    /// Builds an acronym from the first character of each word in `input`, uppercased
+    /// and joined with `separator`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_acronym("").build();
+    /// let input = "portable document format";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("PDF".to_string()));
+    /// ```
+    fn to_acronym(&mut self, separator: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(abbr::Abbr::new(separator));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// PADMUL - Pad To Multiple
+    ///
+    /// Right-pads `input` with the first character of `fill` until its length is a multiple of `multiple`
+    ///
+    /// A `multiple` of 0 makes the token fail
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().pad_to_multiple("0", 4).build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana00".to_string()));
+    /// ```
+    fn pad_to_multiple(&mut self, fill: &str, multiple: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(padmul::Padmul::new(fill, multiple));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SWPW - Swap Words
+    ///
+    /// Swaps the words at indexes `a` and `b` in `input`
+    ///
+    /// If either index is out of range an `AtpError` is returned
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().swap_words(0, 2).build();
+    /// let input = "banana laranja cheia";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("cheia laranja banana".to_string()));
+    /// ```
+    fn swap_words(&mut self, a: usize, b: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(swpw::Swpw::new(a, b));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// REVEW - Reverse Each Word
+    ///
+    /// Reverses the characters of every word in `input`, keeping the word order
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().reverse_each_word().build();
+    /// let input = "abc def";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("cba fed".to_string()));
+    /// ```
+    fn reverse_each_word(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(revew::Revew::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SHESC - Shell Escape
+    ///
+    /// Wraps `input` in single quotes so it can be passed as a single shell argument,
+    /// escaping the single quotes inside it as `'\''`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_shell_escaped().build();
+    /// let input = "it's $HOME";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("'it'\\''s $HOME'".to_string()));
+    /// ```
+    fn to_shell_escaped(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(shesc::Shesc::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SHUNESC - Shell Unescape
+    ///
+    /// Reverts a shell quoted argument (like the ones produced by `to_shell_escaped`) back to plain text
+    ///
+    /// If a single quote is left open an `AtpError` is returned
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_shell_unescaped().build();
+    /// let input = "'it'\\''s $HOME'";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("it's $HOME".to_string()));
+    /// ```
+    fn to_shell_unescaped(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(shunesc::Shunesc::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CSVE - CSV Escape
+    ///
+    /// Escapes `input` as a single CSV field (RFC 4180), quoting it only when it contains
+    /// `,`, `"`, `\r` or `\n`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_csv_field().build();
+    /// let input = "banana, laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("\"banana, laranja\"".to_string()));
+    /// ```
+    fn to_csv_field(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(csve::Csve::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// NUMINC - Numeric Increment
+    ///
+    /// Adds `delta` to every integer found in `input`, results below zero saturate at 0
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().increment_numbers(1).build();
+    /// let input = "page 9 of 10";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("page 10 of 11".to_string()));
+    /// ```
+    fn increment_numbers(&mut self, delta: i64) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(numinc::Numinc::new(delta));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TGL - Toggle Case At
+    ///
+    /// Flips the case of the character at `index` in `input`
+    ///
+    /// If `index` is out of range an `AtpError` is returned
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().toggle_case_at(0).build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("Banana".to_string()));
+    /// ```
+    fn toggle_case_at(&mut self, index: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(tgl::Tgl::new(index));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// FINDIDX - Find Index
+    ///
+    /// Replaces `input` with the character index of the first match of `pattern`, or `-1` if there is none
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().find_index("an").build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("1".to_string()));
+    /// ```
+    fn find_index(&mut self, pattern: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(match findidx::Findidx::new(pattern) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        });
+
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CONTAINS - Contains
+    ///
+    /// Replaces `input` with `true` if it contains `text`, and with `false` otherwise
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().contains_bool("nan").build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("true".to_string()));
+    /// ```
+    fn contains_bool(&mut self, text: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(contains::Contains::new(text));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SHA256 - SHA-256 Digest
+    ///
+    /// Replaces `input` with its lowercase hex SHA-256 digest
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_sha256().build();
+    /// let input = "abc";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string()));
+    /// ```
+    fn to_sha256(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(sha256::Sha256Tok::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CRC32 - CRC-32 Checksum
+    ///
+    /// Replaces `input` with its CRC-32 checksum as an 8 digit lowercase hex string
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_crc32().build();
+    /// let input = "123456789";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("cbf43926".to_string()));
+    /// ```
+    fn to_crc32(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(crc32::Crc32Tok::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// DFLT - Default If Empty
+    ///
+    /// Replaces `input` with `value` when it is empty, if `trim` is true whitespace only inputs
+    /// count as empty too
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().default_if_empty("N/A", true).build();
+    /// let input = "   ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("N/A".to_string()));
+    /// ```
+    fn default_if_empty(&mut self, value: &str, trim: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(dflt::Dflt::new(value, trim));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// Trim Both Sides (ASCII)
+    ///
+    /// Same as `trim_both_sides`, but only ASCII whitespace is removed, so characters like U+00A0 are kept
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().trim_ascii_both().build();
+    /// let input = "  banana  ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana".to_string()));
+    /// ```
+    fn trim_ascii_both(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(tbsa::Tbsa::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// Trim Left Side (ASCII)
+    ///
+    /// Same as `trim_left_side`, but only ASCII whitespace is removed, so characters like U+00A0 are kept
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().trim_ascii_left().build();
+    /// let input = "  banana  ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana  ".to_string()));
+    /// ```
+    fn trim_ascii_left(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(tlsa::Tlsa::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// Trim Right Side (ASCII)
+    ///
+    /// Same as `trim_right_side`, but only ASCII whitespace is removed, so characters like U+00A0 are kept
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().trim_ascii_right().build();
+    /// let input = "  banana  ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("  banana".to_string()));
+    /// ```
+    fn trim_ascii_right(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(trsa::Trsa::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// Push Ops
+    ///
+    /// Pushes every operation of `ops` in order, the same as calling the matching
+    /// builder methods one by one (see [`PipelineOp`]).
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    /// use atp::builder::pipeline_op::PipelineOp;
+    ///
+    /// let (mut processor, id) = AtpBuilder::new()
+    ///     .push_ops(&[PipelineOp::Trim, PipelineOp::Upper])
+    ///     .build();
+    /// let input = "  banana  ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("BANANA".to_string()));
+    /// ```
+    fn push_ops(&mut self, ops: &[PipelineOp]) -> Result<&mut Self, AtpError> {
+        for op in ops {
+            match op {
+                PipelineOp::Trim => self.trim_both_sides()?,
+                PipelineOp::TrimLeft => self.trim_left_side()?,
+                PipelineOp::TrimRight => self.trim_right_side()?,
+                PipelineOp::Upper => self.to_uppercase_all()?,
+                PipelineOp::Lower => self.to_lowercase_all()?,
+                PipelineOp::Replace { from, to } => self.replace_all_with(from, to)?,
+                PipelineOp::ReplaceFirst { from, to } => self.replace_first_with(from, to)?,
+                PipelineOp::Append(text) => self.add_to_end(text)?,
+                PipelineOp::Prepend(text) => self.add_to_beginning(text)?,
+            };
+        }
+        Ok(self)
+    }
+
+    /// NOP - No Operation
+    ///
+    /// Adds a step that returns its input unchanged
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().nop().build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana".to_string()));
+    /// ```
+    fn nop(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(nop::Nop::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CMT - Comment
+    ///
+    /// Adds a comment step, it returns its input unchanged and is written as `# text` in `.atp` files
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().comment("strip later").build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana".to_string()));
+    /// ```
+    fn comment(&mut self, text: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(cmt::Cmt::new(text));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// DDPW - Dedupe Adjacent Words
+    ///
+    /// Collapses consecutive identical words, optionally ignoring case
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().dedupe_adjacent_words(false).build();
+    /// let input = "the the cat cat";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("the cat".to_string()));
+    /// ```
+    fn dedupe_adjacent_words(&mut self, case_insensitive: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(ddpw::Ddpw::new(case_insensitive));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TTLX - Title Case With Exceptions
+    ///
+    /// Title-cases every word except the comma separated `exceptions`, which stay lowercase unless first or last
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_title_case_except("of,the").build();
+    /// let input = "the lord of the rings";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("The Lord of the Rings".to_string()));
+    /// ```
+    fn to_title_case_except(&mut self, exceptions: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(ttlx::Ttlx::new(exceptions));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CHUNKEVERY - Chunk Every N Characters
+    ///
+    /// Inserts `separator` between every block of `n` characters
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().chunk_every(4, "-").build();
+    /// let input = "1234567890";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("1234-5678-90".to_string()));
+    /// ```
+    fn chunk_every(&mut self, n: usize, separator: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(chunkevery::Chunkevery::new(n, separator));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// ASCIIONLY - ASCII Only
+    ///
+    /// Removes every non-ASCII character
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().ascii_only().build();
+    /// let input = "olá, mundo!";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ol, mundo!".to_string()));
+    /// ```
+    fn ascii_only(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(asciionly::Asciionly::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// PRINTABLE - Printable Only
+    ///
+    /// Removes control characters, optionally keeping `\n` and `\t`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().printable_only(false).build();
+    /// let input = "a\0b";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ab".to_string()));
+    /// ```
+    fn printable_only(&mut self, keep_newlines: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(printable::Printable::new(keep_newlines));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// STRIPANSI - Strip ANSI Escape Codes
+    ///
+    /// Removes ANSI escape sequences such as terminal colors
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().strip_ansi().build();
+    /// let input = "\x1b[31mred\x1b[0m";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("red".to_string()));
+    /// ```
+    fn strip_ansi(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(stripansi::Stripansi::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// LNFIX - Line Prefix/Suffix
+    ///
+    /// Adds `prefix` and `suffix` around every line of the input
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().line_wrap("> ", "").build();
+    /// let input = "hello\nworld";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("> hello\n> world".to_string()));
+    /// ```
+    fn line_wrap(&mut self, prefix: &str, suffix: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(lnfix::Lnfix::new(prefix, suffix));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SPLITCASE - Split Case to Words
+    ///
+    /// Splits camelCase, PascalCase, snake_case or kebab-case text into lowercase space separated words
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().split_case_to_words().build();
+    /// let input = "bananaLaranjaCheiaDeCanja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana laranja cheia de canja".to_string()));
+    /// ```
+    fn split_case_to_words(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(splitcase::Splitcase::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// OVW - Overwrite
+    ///
+    /// Replaces the characters between `start` and `end` (inclusive) with `text`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().overwrite(0, 5, "maçã").build();
+    /// let input = "banana laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("maçã laranja".to_string()));
+    /// ```
+    fn overwrite(&mut self, start: usize, end: usize, text: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(ovw::Ovw::new(start, end, text)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// LIMIT - Limit Length
+    ///
+    /// Fails when the input has more than `max_chars` characters, otherwise passes it through
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().limit_length(6).build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana".to_string()));
+    /// ```
+    fn limit_length(&mut self, max_chars: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(limit::Limit::new(max_chars));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// REQUIRE - Require Match
+    ///
+    /// Fails when the input does not match `pattern`, otherwise passes it through
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().require_match(r"^\d+$").build();
+    /// let input = "2024";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("2024".to_string()));
+    /// ```
+    fn require_match(&mut self, pattern: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(match require::Require::new(pattern) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        });
+
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// WFREQ - Word Frequency
+    ///
+    /// Replaces the input with one `word:count` line per word, sorted by descending count
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().word_frequency(false).build();
+    /// let input = "b a b";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("b:2\na:1".to_string()));
+    /// ```
+    fn word_frequency(&mut self, case_insensitive: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(wfreq::Wfreq::new(case_insensitive));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// RMEL - Remove Empty Lines
+    ///
+    /// Removes empty lines, and whitespace-only lines unless `keep_whitespace_lines` is true
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().remove_empty_lines(false).build();
+    /// let input = "banana\n\n  \nlaranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana\nlaranja".to_string()));
+    /// ```
+    fn remove_empty_lines(&mut self, keep_whitespace_lines: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(rmel::Rmel::new(keep_whitespace_lines));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TRANSP - Transpose
+    ///
+    /// Transposes the `delimiter` separated rows of the input into columns
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().transpose(" ").build();
+    /// let input = "a b c\n1 2 3";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("a 1\nb 2\nc 3".to_string()));
+    /// ```
+    fn transpose(&mut self, delimiter: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(transp::Transp::new(delimiter));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// COLSEL - Column Select
+    ///
+    /// Keeps only the `index` column of each `delimiter` separated line, missing columns become empty
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().select_column(",", 1).build();
+    /// let input = "banana,3\nlaranja,5";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("3\n5".to_string()));
+    /// ```
+    fn select_column(&mut self, delimiter: &str, index: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(colsel::Colsel::new(delimiter, index));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// JSONP - Json Pretty
+    ///
+    /// Pretty prints the JSON input with 2 space indentation
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_json_pretty().build();
+    /// let input = r#"[1,2]"#;
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("[\n  1,\n  2\n]".to_string()));
+    /// ```
+    fn to_json_pretty(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(jsonp::Jsonp::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// JSONM - Json Minify
+    ///
+    /// Removes all the whitespace of the JSON input, object keys are sorted alphabetically
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_json_minified().build();
+    /// let input = "[\n  1,\n  2\n]";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok(r#"[1,2]"#.to_string()));
+    /// ```
+    fn to_json_minified(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(jsonm::Jsonm::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// RWW - Replace Whitespace With
+    ///
+    /// Replaces every run of whitespace in the input with `replacement`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().replace_whitespace_with("_").build();
+    /// let input = "banana  laranja\tcanja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana_laranja_canja".to_string()));
+    /// ```
+    fn replace_whitespace_with(&mut self, replacement: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(rww::Rww::new(replacement));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// ENSUREPFX - Ensure Prefix
+    ///
+    /// Prepends `prefix` to the input only if it is not already there
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().ensure_prefix("banana ").build();
+    /// let input = "laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana laranja".to_string()));
+    /// ```
+    fn ensure_prefix(&mut self, prefix: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(ensurepfx::Ensurepfx::new(prefix));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// ENSURESFX - Ensure Suffix
+    ///
+    /// Appends `suffix` to the input only if it is not already there
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().ensure_suffix(" laranja").build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana laranja".to_string()));
+    /// ```
+    fn ensure_suffix(&mut self, suffix: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(ensuresfx::Ensuresfx::new(suffix));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// STPPFX - Strip Prefix
+    ///
+    /// Removes `prefix` from the beginning of the input if it is there
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().strip_prefix_str("banana ").build();
+    /// let input = "banana laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("laranja".to_string()));
+    /// ```
+    fn strip_prefix_str(&mut self, prefix: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(stppfx::Stppfx::new(prefix));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// STPSFX - Strip Suffix
+    ///
+    /// Removes `suffix` from the end of the input if it is there
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().strip_suffix_str(" laranja").build();
+    /// let input = "banana laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana".to_string()));
+    /// ```
+    fn strip_suffix_str(&mut self, suffix: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(stpsfx::Stpsfx::new(suffix));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// MIRROR - Mirror
+    ///
+    /// Appends the reversed input to itself, `include_pivot` repeats the last character at the seam
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().mirror(false).build();
+    /// let input = "abc";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("abcba".to_string()));
+    /// ```
+    fn mirror(&mut self, include_pivot: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(mirror::Mirror::new(include_pivot));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// INTERLEAVE - Interleave
+    ///
+    /// Puts the next character of `other` after each character of the input, repeating `other` as needed
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().interleave("xy").build();
+    /// let input = "abc";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("axbycx".to_string()));
+    /// ```
+    fn interleave(&mut self, other: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(interleave::Interleave::new(other));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// WSEL - Word Select
+    ///
+    /// Replaces the input with its word at `index`, failing if that word does not exist
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().select_word(1).build();
+    /// let input = "banana laranja canja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("laranja".to_string()));
+    /// ```
+    fn select_word(&mut self, index: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(wsel::Wsel::new(index));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// WSLICE - Word Slice
+    ///
+    /// Keeps only the words between `start` and `end` (inclusive), `end` is clamped to the last word
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().slice_words(1, 2).build();
+    /// let input = "banana laranja cheia de canja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("laranja cheia".to_string()));
+    /// ```
+    fn slice_words(&mut self, start: usize, end: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(wslice::Wslice::new(start, end)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// LSLICE - Line Slice
+    ///
+    /// Keeps only the lines between `start` and `end` (inclusive), `end` is clamped to the last line
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().slice_lines(1, 2).build();
+    /// let input = "l0\nl1\nl2\nl3";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("l1\nl2".to_string()));
+    /// ```
+    fn slice_lines(&mut self, start: usize, end: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(lslice::Lslice::new(start, end)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// HEAD - Head
+    ///
+    /// Keeps only the first `n` lines of the input
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().head(2).build();
+    /// let input = "l0\nl1\nl2";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("l0\nl1".to_string()));
+    /// ```
+    fn head(&mut self, n: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(head::Head::new(n));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TAIL - Tail
+    ///
+    /// Keeps only the last `n` lines of the input
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().tail(2).build();
+    /// let input = "l0\nl1\nl2";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("l1\nl2".to_string()));
+    /// ```
+    fn tail(&mut self, n: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(tail::Tail::new(n));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SAMPLE - Sample Lines
+    ///
+    /// Keeps every `step`th line of the input, starting at the line of index `offset`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().sample_lines(2, 1).build();
+    /// let input = "l0\nl1\nl2\nl3";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("l1\nl3".to_string()));
+    /// ```
+    fn sample_lines(&mut self, step: usize, offset: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(sample::Sample::new(step, offset));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// NRMNL - Normalize Newlines
+    ///
+    /// Converts every line ending of the input to `style`, which can be `lf`, `crlf` or `cr`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().normalize_newlines("lf").build();
+    /// let input = "a\r\nb\rc";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("a\nb\nc".to_string()));
+    /// ```
+    fn normalize_newlines(&mut self, style: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(nrmnl::Nrmnl::new(style)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// QPE - Quoted Printable Encode
+    ///
+    /// Encodes the input as quoted-printable (RFC 2045)
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_quoted_printable().build();
+    /// let input = "café";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("caf=C3=A9".to_string()));
+    /// ```
+    fn to_quoted_printable(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(qpe::Qpe::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// QPD - Quoted Printable Decode
+    ///
+    /// Decodes quoted-printable (RFC 2045) input, failing on malformed `=XX` sequences
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().from_quoted_printable().build();
+    /// let input = "caf=C3=A9";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("café".to_string()));
+    /// ```
+    fn from_quoted_printable(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(qpd::Qpd::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// ROTN - Rotate Digits
+    ///
+    /// Rotates every digit of the input by `shift`, wrapping from 9 back to 0
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().rotate_digits(5).build();
+    /// let input = "pedido 1298";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("pedido 6743".to_string()));
+    /// ```
+    fn rotate_digits(&mut self, shift: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(rotn::Rotn::new(shift));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// EXPR - Expand Ranges
+    ///
+    /// Expands `X-Y` letter and digit ranges of the input into the full sequence
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().expand_ranges().build();
+    /// let input = "a-e 1-5";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("abcde 12345".to_string()));
+    /// ```
+    fn expand_ranges(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(expr::Expr::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TABS - Expand Tabs
+    ///
+    /// Replaces every tab with `width` spaces
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().expand_tabs(4).build();
+    /// let input = "a\tb";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("a    b".to_string()));
+    /// ```
+    fn expand_tabs(&mut self, width: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(tabs::Tabs::new(width)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+    /// TABS - Expand Tabs
+    ///
+    /// Same as `expand_tabs`, but when `columnar` is true each tab pads up to the next tab stop
+    /// (a multiple of `width`) of its line instead of always inserting `width` spaces
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().expand_tabs_with(4, true).build();
+    /// let input = "ab\tc";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ab  c".to_string()));
+    /// ```
+    fn expand_tabs_with(&mut self, width: usize, columnar: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(
+            tabs::Tabs::new(width)?.with_columnar(columnar)
+        );
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// MORSEE - Morse Encode
+    ///
+    /// Encodes the input as Morse code, separating letters with spaces and words with ` / `
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_morse().build();
+    /// let input = "SOS HELP";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("... --- ... / .... . .-.. .--.".to_string()));
+    /// ```
+    fn to_morse(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(morsee::Morsee::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+    /// MORSED - Morse Decode
+    ///
+    /// Decodes Morse code input, where words are separated by `/`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().from_morse().build();
+    /// let input = "... --- ...";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("SOS".to_string()));
+    /// ```
+    fn from_morse(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(morsed::Morsed::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// BINE - Binary Encode
+    ///
+    /// Encodes every UTF-8 byte of the input as 8 binary digits, separated by spaces
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().to_binary().build();
+    /// let input = "Hi";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("01001000 01101001".to_string()));
+    /// ```
+    fn to_binary(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(bine::Bine::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+    /// BIND - Binary Decode
+    ///
+    /// Decodes space separated groups of 8 binary digits back into text
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().from_binary().build();
+    /// let input = "01001000 01101001";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("Hi".to_string()));
+    /// ```
+    fn from_binary(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(bind::Bind::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// JOINW - Join Words
+    ///
+    /// Splits the input on whitespace, applies `word_case` (`none`, `lower`, `upper` or
+    /// `capitalize`) to every word and joins them with `separator`
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().join_words("-", "lower").build();
+    /// let input = "Banana Laranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana-laranja".to_string()));
+    /// ```
+    fn join_words(&mut self, separator: &str, word_case: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(joinw::Joinw::new(separator, word_case)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// MARK - Debug Mark
+    ///
+    /// Returns the input unchanged, writing `[mark: {label}] {input snippet}` to the debug output
+    /// when the pipeline runs in debug mode
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().mark("after trim").build();
+    /// let input = " banana ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok(" banana ".to_string()));
+    /// ```
+    fn mark(&mut self, label: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(mark::Mark::new(label));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// FOLD - Case Fold
+    ///
+    /// Applies Unicode case folding to the input, for case-insensitive comparisons
+    /// ("ß" becomes "ss")
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().case_fold().build();
+    /// let input = "Straße";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("strasse".to_string()));
+    /// ```
+    fn case_fold(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(fold::Fold::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// SUBMAP - Substitute Map
+    ///
+    /// Applies every `(key, value)` replacement of `pairs` in a single left-to-right pass, preferring
+    /// the longest key when they overlap. Replaced text is not scanned again
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().substitute_map(&[("a", "e"), ("e", "a")]).build();
+    /// let input = "banane";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("benena".to_string()));
+    /// ```
+    fn substitute_map(&mut self, pairs: &[(&str, &str)]) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(submap::Submap::new(pairs)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// REDACT - Redact Matches
+    ///
+    /// Replaces every match of `pattern` with `mask_char` repeated once per matched character
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().redact(r"\d", "#").build();
+    /// let input = "card 4321";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("card ####".to_string()));
+    /// ```
+    fn redact(&mut self, pattern: &str, mask_char: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(redact::Redact::new(pattern, mask_char)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// CNTM - Count Matches
+    ///
+    /// Replaces the input with the number of (non-overlapping) matches of `pattern` in it
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().count_matches("an").build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("2".to_string()));
+    /// ```
+    fn count_matches(&mut self, pattern: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(cntm::Cntm::new(pattern)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// PUNCTSP - Fix Punctuation Spacing
+    ///
+    /// Removes spaces before `, . ; : ! ?` and leaves a single space between them and the next word
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().fix_punctuation_spacing().build();
+    /// let input = "hello ,world";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("hello, world".to_string()));
+    /// ```
+    fn fix_punctuation_spacing(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(punctsp::Punctsp::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// WRAP - Word Wrap
+    ///
+    /// Wraps each line of the input to `width` characters, breaking only between words
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().wrap_words(10).build();
+    /// let input = "the quick brown fox";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("the quick\nbrown fox".to_string()));
+    /// ```
+    fn wrap_words(&mut self, width: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(wrap::Wrap::new(width)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// WRAP - Word Wrap
+    ///
+    /// Same as `wrap_words`, but when `hyphenate` is true words longer than `width` are broken
+    /// across lines with a trailing `-` instead of overflowing their line
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().wrap_words_with(4, true).build();
+    /// let input = "banana";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ban-\nana".to_string()));
+    /// ```
+    fn wrap_words_with(&mut self, width: usize, hyphenate: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(
+            wrap::Wrap::new(width)?.with_hyphenate(hyphenate)
+        );
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// INTERP - Interpolate Registers
+    ///
+    /// Replaces `${name}` placeholders of the input with the registers set on the processor,
+    /// placeholders without a register are kept as they are
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().interpolate_registers().build();
+    /// processor.set_register("name", "banana");
+    /// let input = "Hello ${name}, ${other}";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("Hello banana, ${other}".to_string()));
+    /// ```
+    fn interpolate_registers(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(interp::Interp::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+    /// INTERP - Interpolate Registers
+    ///
+    /// Same as `interpolate_registers`, but when `strict` is true a placeholder without a
+    /// register returns an error instead of being kept
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().interpolate_registers_with(true).build();
+    /// let input = "Hello ${name}";
+    ///
+    /// assert!(processor.process_all(&id, &input).is_err());
+    /// ```
+    fn interpolate_registers_with(&mut self, strict: bool) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(
+            interp::Interp::default().with_strict(strict)
+        );
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// TRLN - Trim Lines
+    ///
+    /// Removes leading and trailing whitespace from every line of the input
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().trim_lines().build();
+    /// let input = " banana \n\tlaranja ";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("banana\nlaranja".to_string()));
+    /// ```
+    fn trim_lines(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(trln::Trln::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// JUSTIFY - Justify Lines
+    ///
+    /// Stretches every line but the last to `width` characters by adding spaces between words
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().justify(9).build();
+    /// let input = "ab cd ef\nlast";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("ab  cd ef\nlast".to_string()));
+    /// ```
+    fn justify(&mut self, width: usize) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(justify::Justify::new(width));
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// COLALIGN - Align Columns
+    ///
+    /// Pads the `delimiter` separated cells of each line so the columns line up
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().align_columns(",").build();
+    /// let input = "a,bb\nccc,d";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("a  , bb\nccc, d ".to_string()));
+    /// ```
+    fn align_columns(&mut self, delimiter: &str) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(colalign::Colalign::new(delimiter)?);
+        self.push_token(tok)?;
+        Ok(self)
+    }
+
+    /// REVL - Reverse Lines
+    ///
+    /// Reverses the order of the lines of the input, keeping a trailing newline at the end
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new().reverse_lines().build();
+    /// let input = "banana\nlaranja";
+    ///
+    /// assert_eq!(processor.process_all(&id, &input), Ok("laranja\nbanana".to_string()));
+    /// ```
+    fn reverse_lines(&mut self) -> Result<&mut Self, AtpError> {
+        let tok: Box<dyn InstructionMethods> = Box::new(revl::Revl::default());
+        self.push_token(tok)?;
+        Ok(self)
+    }
+}
+
+pub trait AtpConditionalMethods: AtpBuilderMethods {
+    /// IFDC - If Do Contains
+    ///
+    /// Every token pushed inside `f` becomes an inner token of a single `ifdc`, so they all run
+    /// in sequence when `input` contains `value`, and none of them runs otherwise.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new()
+    ///     .if_do_contains_each("xy", |b| {
+    ///         b.delete_first()?.add_to_end("!")?;
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "xyz"), Ok("yz!".to_string()));
+    /// assert_eq!(processor.process_all(&id, "abc"), Ok("abc".to_string()));
+    /// ```
+    fn if_do_contains_each<F>(&mut self, value: &str, f: F) -> Result<&mut Self, AtpError>
+        where F: FnOnce(&mut ConditionalBuilderEach) -> Result<(), AtpError>
+    {
+        let params = vec![AtpParamTypes::String(value.to_string())];
+        let token: Box<dyn InstructionMethods> = Box::new(ifdc::Ifdc::default());
+        let mut conditional_builder = ConditionalBuilderEach::new(token, params);
+
+        f(&mut conditional_builder)?;
+
+        // A single ifdc, so the condition is checked once for all the inner tokens
+        if let Some(token) = conditional_builder.build_combined()? {
+            self.push_token(token)?;
+        }
+
+        Ok(self)
+    }
+
+    /// MAPL - Map Lines
+    ///
+    /// Every token pushed inside `f` is wrapped in its own `mapl`, so it is applied
+    /// to each line of `input` independently.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new()
+    ///     .map_lines_each(|b| {
+    ///         b.add_to_beginning("> ")?;
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "banana\nlaranja"), Ok("> banana\n> laranja".to_string()));
+    /// ```
+    fn map_lines_each<F>(&mut self, f: F) -> Result<&mut Self, AtpError>
+        where F: FnOnce(&mut ConditionalBuilderEach) -> Result<(), AtpError>
+    {
+        let token: Box<dyn InstructionMethods> = Box::new(mapl::Mapl::default());
+        let mut conditional_builder = ConditionalBuilderEach::new(token, Vec::new());
+
+        f(&mut conditional_builder)?;
+
+        for token in conditional_builder.build().into_iter() {
+            self.push_token(token)?;
+        }
+
+        Ok(self)
+    }
+
+    /// MAPW - Map Words
+    ///
+    /// Every token pushed inside `f` is wrapped in its own `mapw`, so it is applied
+    /// to each whitespace separated word of `input`. Words are joined back with single spaces.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use atp::builder::atp_builder::{AtpBuilder};
+    /// use atp::builder::atp_processor::{AtpProcessorMethods};
+    ///
+    /// let (mut processor, id) = AtpBuilder::new()
+    ///     .map_words_each(|b| {
+    ///         b.to_reverse()?;
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(processor.process_all(&id, "banana laranja"), Ok("ananab ajnaral".to_string()));
+    /// ```
+    fn map_words_each<F>(&mut self, f: F) -> Result<&mut Self, AtpError>
+        where F: FnOnce(&mut ConditionalBuilderEach) -> Result<(), AtpError>
+    {
+        let token: Box<dyn InstructionMethods> = Box::new(mapw::Mapw::default());
+        let mut conditional_builder = ConditionalBuilderEach::new(token, Vec::new());
+
+        f(&mut conditional_builder)?;
+
+        for token in conditional_builder.build().into_iter() {
             self.push_token(token)?;
         }
 
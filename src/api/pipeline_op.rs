@@ -0,0 +1,105 @@
+/// PipelineOp
+///
+/// A small set of common operations that can be pushed in bulk with
+/// `AtpBuilderMethods::push_ops`, useful when a pipeline is generated from data.
+///
+/// Each variant maps to the builder method with the same behavior:
+///
+/// | Variant | Builder method |
+/// |---|---|
+/// | `Trim` | `trim_both_sides` |
+/// | `TrimLeft` | `trim_left_side` |
+/// | `TrimRight` | `trim_right_side` |
+/// | `Upper` | `to_uppercase_all` |
+/// | `Lower` | `to_lowercase_all` |
+/// | `Replace { from, to }` | `replace_all_with` |
+/// | `ReplaceFirst { from, to }` | `replace_first_with` |
+/// | `Append(text)` | `add_to_end` |
+/// | `Prepend(text)` | `add_to_beginning` |
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PipelineOp {
+    Trim,
+    TrimLeft,
+    TrimRight,
+    Upper,
+    Lower,
+    Replace {
+        from: String,
+        to: String,
+    },
+    ReplaceFirst {
+        from: String,
+        to: String,
+    },
+    Append(String),
+    Prepend(String),
+}
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod pipeline_op_tests {
+    mod feature_tests {
+        use crate::api::{
+            AtpBuilderMethods,
+            atp_processor::{ AtpProcessor, AtpProcessorMethods },
+            pipeline_op::PipelineOp,
+        };
+        use crate::utils::errors::AtpError;
+
+        #[test]
+        fn push_ops_matches_individual_builder_calls() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let input = "  banana split  ";
+
+            let chained = processor
+                .create_pipeline()
+                .trim_both_sides()?
+                .to_uppercase_all()?
+                .replace_all_with("A", "o")?
+                .add_to_end("!")?
+                .build();
+
+            let bulk = processor
+                .create_pipeline()
+                .push_ops(
+                    &[
+                        PipelineOp::Trim,
+                        PipelineOp::Upper,
+                        PipelineOp::Replace { from: "A".to_string(), to: "o".to_string() },
+                        PipelineOp::Append("!".to_string()),
+                    ]
+                )?
+                .build();
+
+            assert_eq!(processor.process_all(&bulk, input)?, processor.process_all(&chained, input)?);
+            assert_eq!(processor.process_all(&bulk, input)?, "BoNoNo SPLIT!");
+            Ok(())
+        }
+
+        #[test]
+        fn push_ops_with_empty_slice_is_identity() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().push_ops(&[])?.build();
+
+            assert_eq!(processor.process_all(&id, " x ")?, " x ");
+            Ok(())
+        }
+
+        #[test]
+        fn push_ops_can_be_mixed_with_chained_calls() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor
+                .create_pipeline()
+                .add_to_beginning("<")?
+                .push_ops(&[PipelineOp::Lower, PipelineOp::ReplaceFirst {
+                    from: "a".to_string(),
+                    to: "4".to_string(),
+                }])?
+                .add_to_end(">")?
+                .build();
+
+            assert_eq!(processor.process_all(&id, "BANANA")?, "<b4nana>");
+            Ok(())
+        }
+    }
+}
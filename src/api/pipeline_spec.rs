@@ -0,0 +1,155 @@
+use serde::{ Deserialize, Serialize };
+
+use crate::{
+    globals::var::TokenWrapper,
+    text::reader::read_from_chunks,
+    utils::errors::{ AtpError, AtpErrorCode },
+};
+
+/// A single pipeline step in its data-driven form.
+///
+/// `op` is the token identifier (the same one used in `.atp` files) and `args`
+/// are its parameters, written exactly as they would appear after the identifier
+/// in an `.atp` line.
+///
+/// ```json
+/// { "op": "raw", "args": ["a", "b"] }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PipelineStep {
+    pub op: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl PipelineStep {
+    /// Describes an already built token, keeping variable references unresolved.
+    pub fn from_token(token: &TokenWrapper) -> Result<Self, AtpError> {
+        Ok(PipelineStep {
+            op: token.get_string_repr().to_string(),
+            args: token.get_unresolved_args(),
+        })
+    }
+}
+
+/// PipelineSpec
+///
+/// Serializable description of a pipeline, stored as a plain JSON list of steps.
+///
+/// ```json
+/// [
+///     { "op": "atb", "args": ["b"] },
+///     { "op": "raw", "args": ["a", "e"] }
+/// ]
+/// ```
+///
+/// Each step is resolved through `TOKEN_TABLE` with the same syntax rules used by
+/// the `.atp` text reader, so variables (`{{name}}`) and nested tokens work the same way.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PipelineSpec {
+    pub steps: Vec<PipelineStep>,
+}
+
+impl PipelineSpec {
+    pub fn from_json(json: &str) -> Result<Self, AtpError> {
+        serde_json::from_str(json).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed parsing pipeline JSON".into()),
+                "PipelineSpec::from_json",
+                e.to_string()
+            )
+        })
+    }
+
+    pub fn from_tokens(tokens: &[TokenWrapper]) -> Result<Self, AtpError> {
+        Ok(PipelineSpec {
+            steps: tokens.iter().map(PipelineStep::from_token).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, AtpError> {
+        serde_json::to_string(self).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed serializing pipeline JSON".into()),
+                "PipelineSpec::to_json",
+                e.to_string()
+            )
+        })
+    }
+
+    pub fn to_tokens(&self) -> Result<Vec<TokenWrapper>, AtpError> {
+        self.steps
+            .iter()
+            .map(|step| {
+                let mut chunks = Vec::with_capacity(step.args.len() + 1);
+                chunks.push(step.op.clone());
+                chunks.extend(step.args.iter().cloned());
+                read_from_chunks(&chunks)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod pipeline_spec_tests {
+    mod feature_tests {
+        use crate::{ api::pipeline_spec::{ PipelineSpec, PipelineStep }, utils::errors::AtpErrorCode };
+
+        #[test]
+        fn parses_a_list_of_steps() {
+            let spec = PipelineSpec::from_json(
+                r#"[{ "op": "raw", "args": ["a", "b"] }, { "op": "tua" }]"#
+            ).unwrap();
+
+            assert_eq!(spec.steps.len(), 2);
+            assert_eq!(spec.steps[0], PipelineStep {
+                op: "raw".to_string(),
+                args: vec!["a".to_string(), "b".to_string()],
+            });
+            assert!(spec.steps[1].args.is_empty());
+        }
+
+        #[test]
+        fn malformed_json_is_a_text_parsing_error() {
+            let err = PipelineSpec::from_json(r#"{ "op": "raw" "#).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+
+        #[test]
+        fn to_tokens_builds_one_token_per_step() {
+            let spec = PipelineSpec::from_json(
+                r#"[{ "op": "atb", "args": ["b"] }, { "op": "rfw", "args": ["a", "o"] }]"#
+            ).unwrap();
+
+            let tokens = spec.to_tokens().unwrap();
+
+            assert_eq!(tokens.len(), 2);
+            assert_eq!(tokens[0].get_string_repr(), "atb");
+            assert_eq!(tokens[1].get_string_repr(), "rfw");
+        }
+
+        #[test]
+        fn from_tokens_keeps_args_and_nested_tokens() {
+            let json =
+                r#"[{"op":"raw","args":["a","b"]},{"op":"blk","args":["x","assoc","tua"]}]"#;
+            let tokens = PipelineSpec::from_json(json).unwrap().to_tokens().unwrap();
+
+            let spec = PipelineSpec::from_tokens(&tokens).unwrap();
+
+            assert_eq!(spec.to_json().unwrap(), json.to_string());
+        }
+
+        #[test]
+        fn from_tokens_keeps_variable_references() {
+            let json = r#"[{"op":"ate","args":["{{suffix}}"]}]"#;
+            let tokens = PipelineSpec::from_json(json).unwrap().to_tokens().unwrap();
+
+            let spec = PipelineSpec::from_tokens(&tokens).unwrap();
+
+            assert_eq!(spec.steps[0].args, vec!["{{suffix}}".to_string()]);
+        }
+    }
+}
@@ -1,2 +1,87 @@
 pub mod reader;
 pub mod writer;
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod roundtrip_tests {
+    use crate::{
+        globals::{ table::{ QuerySource, QueryTarget, TOKEN_TABLE, TargetValue }, var::ValType },
+        text::reader::read_from_text,
+        tokens::InstructionMethods,
+        utils::test_helpers::bytecode_roundtrip,
+    };
+
+    /// Parses an `.atp` line and applies its params, so the token encodes them itself.
+    fn concrete_token(line: &str) -> Box<dyn InstructionMethods> {
+        let wrapper = read_from_text(line).unwrap();
+        let params = wrapper
+            .get_val_params()
+            .iter()
+            .map(|v| {
+                match v {
+                    ValType::Literal(p) => p.clone(),
+                    ValType::VarRef(_) => panic!("samples must not use variables"),
+                }
+            })
+            .collect();
+
+        let mut token = wrapper.get_default_token();
+        token.from_params(&params).unwrap();
+        token
+    }
+
+    // Covers every opcode registered in TOKEN_TABLE, new tokens are picked up automatically
+    // (0x2c is the only unused opcode).
+    #[test]
+    fn every_table_token_roundtrips_with_default_params() {
+        let mut failures = Vec::new();
+
+        for id in TOKEN_TABLE.identifiers() {
+            let token = match
+                TOKEN_TABLE.find((QuerySource::Identifier(id.into()), QueryTarget::Token)).unwrap()
+            {
+                TargetValue::Token(t) => t.into_box(),
+                _ => unreachable!("Invalid query result (Token)"),
+            };
+
+            if let Err(e) = bytecode_roundtrip(token.as_ref()) {
+                failures.push(format!("{}: {:?}", id, e));
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn parameterized_tokens_roundtrip() {
+        let samples = [
+            "atb banana;",
+            "raw a+ e;",
+            "rfw a o;",
+            "rcw a o 2;",
+            "rnw a o 1;",
+            "sslt _ 1;",
+            "dlc 1 3;",
+            "ctc 1 5 true;",
+            "ctc 1 5;",
+            "padl x 10;",
+            "ovw 1 3 abc;",
+            "lnfix < >;",
+            "tabs 8 true;",
+            "wrap 12 true;",
+            "interp true;",
+            "ifdc xy do atb laranja;",
+            "ifdc xy do rnw a o 1;",
+            "ifdc xy do tua atb laranja rnw a o 1;",
+            "ifdc xy do ate a ate b ate c ate d ate e ate f ate g ate h ate i ate j;",
+            "blk first assoc tua;",
+            "mapw do ate !;",
+            "numinc -3;",
+        ];
+
+        for line in samples {
+            let token = concrete_token(line);
+            assert_eq!(bytecode_roundtrip(token.as_ref()), Ok(()), "{}", line);
+        }
+    }
+}
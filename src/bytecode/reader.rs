@@ -3,7 +3,7 @@ use std::{ fs::OpenOptions, io::{ BufReader, Read }, path::Path };
 use crate::{
     globals::{
         table::{ QuerySource, QueryTarget, SyntaxToken, TOKEN_TABLE, TargetValue },
-        var::{ TokenWrapper, ValType },
+        var::{ TokenWrapper, ValType, get_max_param_count },
     },
     utils::{
         errors::{ AtpError, AtpErrorCode },
@@ -16,12 +16,16 @@ const PARAM_STRING: u32 = 0x01;
 const PARAM_USIZE: u32 = 0x02;
 const PARAM_TOKEN: u32 = 0x03;
 const PARAM_VARREF: u32 = 0x04;
+const PARAM_BOOL: u32 = 0x05;
+const PARAM_I64: u32 = 0x06;
 
 fn param_type_from_code(code: u32) -> Option<SyntaxToken> {
     match code {
         PARAM_STRING => Some(SyntaxToken::String),
         PARAM_USIZE => Some(SyntaxToken::Usize),
         PARAM_TOKEN => Some(SyntaxToken::Token),
+        PARAM_BOOL => Some(SyntaxToken::Bool),
+        PARAM_I64 => Some(SyntaxToken::I64),
         PARAM_VARREF => Some(SyntaxToken::String), // VarRef ocupa slot "string-like" no schema
         _ => None,
     }
@@ -37,6 +41,8 @@ fn bytecode_compatible(expected: &SyntaxToken, actual: &SyntaxToken, type_code:
         (SyntaxToken::String, SyntaxToken::String, PARAM_STRING) => true,
         (SyntaxToken::Usize, SyntaxToken::Usize, PARAM_USIZE) => true,
         (SyntaxToken::Token, SyntaxToken::Token, PARAM_TOKEN) => true,
+        (SyntaxToken::Bool, SyntaxToken::Bool, PARAM_BOOL) => true,
+        (SyntaxToken::I64, SyntaxToken::I64, PARAM_I64) => true,
 
         // VarRef: pode aparecer onde o schema espera String/Usize/Token
         (SyntaxToken::String, SyntaxToken::String, PARAM_VARREF) => true,
@@ -159,6 +165,36 @@ fn decode_param_record_to_valtype(param_record: &[u8]) -> Result<ValType, AtpErr
             b.copy_from_slice(payload);
             Ok(ValType::Literal(AtpParamTypes::Usize(usize::from_be_bytes(b))))
         }
+        PARAM_I64 => {
+            if payload.len() != 8 {
+                return Err(
+                    AtpError::new(
+                        AtpErrorCode::BytecodeParamParsingError("Invalid i64 payload size".into()),
+                        "decode_param_record_to_valtype(PARAM_I64)",
+                        format!("len={}", payload.len())
+                    )
+                );
+            }
+            let mut b = [0u8; 8];
+            b.copy_from_slice(payload);
+            Ok(ValType::Literal(AtpParamTypes::I64(i64::from_be_bytes(b))))
+        }
+        PARAM_BOOL => {
+            match payload {
+                [0] => Ok(ValType::Literal(AtpParamTypes::Bool(false))),
+                [1] => Ok(ValType::Literal(AtpParamTypes::Bool(true))),
+                _ =>
+                    Err(
+                        AtpError::new(
+                            AtpErrorCode::BytecodeParamParsingError(
+                                "Invalid bool payload".into()
+                            ),
+                            "decode_param_record_to_valtype(PARAM_BOOL)",
+                            format!("payload={:?}", payload)
+                        )
+                    ),
+            }
+        }
         PARAM_VARREF => {
             let name = utf8_string(payload, "decode_param_record_to_valtype(PARAM_VARREF)")?;
             Ok(ValType::VarRef(name))
@@ -312,10 +348,7 @@ pub fn read_bytecode_from_file(path: &Path) -> Result<Vec<TokenWrapper>, AtpErro
             .filter(|p| !p.optional)
             .filter(|p| !matches!(p.token, SyntaxToken::Literal(_)))
             .count();
-        let max_allowed = expected
-            .iter()
-            .filter(|p| !matches!(p.token, SyntaxToken::Literal(_)))
-            .count();
+        let max_allowed = get_max_param_count(&expected).unwrap_or(usize::MAX);
 
         if param_count < min_required || param_count > max_allowed {
             return Err(
@@ -441,7 +474,10 @@ pub fn read_bytecode_from_file(path: &Path) -> Result<Vec<TokenWrapper>, AtpErro
 
             let vt = decode_param_record_to_valtype(&param_record)?;
             params.push(vt);
-            expected_i += 1;
+            // A variadic param keeps matching until the params run out
+            if !expected[expected_i].variadic {
+                expected_i += 1;
+            }
         }
 
         // guardrail instruction_total_size
@@ -5,6 +5,10 @@ use crate::{
     utils::{ errors::AtpError, validations::check_file_path },
 };
 
+/// Size in bytes of the `.atpbc` header: magic number (8), protocol version (8) and
+/// instruction count (4).
+pub const BYTECODE_HEADER_SIZE: usize = 20;
+
 pub fn write_bytecode_to_file(path: &Path, tokens: Vec<TokenWrapper>) -> Result<(), AtpError> {
     check_file_path(path, Some("atpbc"))?;
 
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use crate::{
     globals::var::{ TokenWrapper, ValType },
     tokens::InstructionMethods,
-    utils::errors::{ AtpError, AtpErrorCode },
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::IndexPolicy },
 };
 #[derive(Clone)]
 pub enum VarValues {
@@ -16,6 +16,15 @@ pub enum ToClean {
     Var(String),
 }
 
+/// Outcome of a conditional instruction during a run, see `GlobalExecutionContext::record_branch`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BranchRecord {
+    /// The conditional instruction, as its `.atp` line without the trailing newline.
+    pub instruction: String,
+    /// Whether the condition matched and the inner token was executed.
+    pub taken: bool,
+}
+
 // First thought of a simple hashmap, but it wouldn't suffice my needs
 #[allow(dead_code)]
 pub struct VarEntry {
@@ -27,6 +36,18 @@ pub struct VarEntry {
 pub struct GlobalExecutionContext {
     variables: HashMap<String, VarEntry>,
     blocks: HashMap<String, Vec<TokenWrapper>>,
+    // Used by the case tokens for locale aware case mapping (e.g. Turkish dotted/dotless I).
+    locale: Option<String>,
+    // Used by the indexed tokens, `None` keeps each token's own behavior.
+    index_policy: Option<IndexPolicy>,
+    // Filled by the conditional tokens, in execution order, only while `record_branches` is set.
+    branches: Vec<BranchRecord>,
+    record_branches: bool,
+    // Set by the processor debug methods, `debug_log` is a no-op otherwise.
+    debug: bool,
+    debug_messages: Vec<String>,
+    // Named text values, read by the interpolation token as `${name}`.
+    registers: HashMap<String, String>,
 }
 
 // Variable Concept
@@ -62,7 +83,89 @@ pub trait GlobalContextMethods {
 
 impl GlobalExecutionContext {
     pub fn new() -> Self {
-        GlobalExecutionContext { variables: HashMap::new(), blocks: HashMap::new() }
+        GlobalExecutionContext {
+            variables: HashMap::new(),
+            blocks: HashMap::new(),
+            locale: None,
+            index_policy: None,
+            branches: Vec::new(),
+            record_branches: false,
+            debug: false,
+            debug_messages: Vec::new(),
+            registers: HashMap::new(),
+        }
+    }
+
+    pub fn set_locale(&mut self, locale: Option<&str>) {
+        self.locale = locale.map(|l| l.to_string());
+    }
+
+    pub fn get_locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn set_index_policy(&mut self, policy: Option<IndexPolicy>) {
+        self.index_policy = policy;
+    }
+
+    pub fn get_index_policy(&self) -> Option<IndexPolicy> {
+        self.index_policy
+    }
+
+    /// Records whether the conditional `instruction` executed its inner token.
+    /// Ignored unless branch recording is on, see `set_record_branches`.
+    pub fn record_branch(&mut self, instruction: &str, taken: bool) {
+        if self.record_branches {
+            self.branches.push(BranchRecord { instruction: instruction.to_string(), taken });
+        }
+    }
+
+    /// Set by `AtpProcessor::branch_report`, so regular runs don't pay for the records.
+    pub fn set_record_branches(&mut self, record: bool) {
+        self.record_branches = record;
+    }
+
+    pub fn is_recording_branches(&self) -> bool {
+        self.record_branches
+    }
+
+    /// Every branch recorded so far, in execution order.
+    pub fn get_branches(&self) -> &[BranchRecord] {
+        &self.branches
+    }
+
+    /// Removes and returns the recorded branches.
+    pub fn take_branches(&mut self) -> Vec<BranchRecord> {
+        std::mem::take(&mut self.branches)
+    }
+
+    pub fn set_debug(&mut self, debug: bool) {
+        self.debug = debug;
+    }
+
+    pub fn is_debug(&self) -> bool {
+        self.debug
+    }
+
+    /// Queues `message` for the processor debug output. Ignored when debug mode is off.
+    pub fn debug_log(&mut self, message: String) {
+        if self.debug {
+            self.debug_messages.push(message);
+        }
+    }
+
+    /// Removes and returns the queued debug messages.
+    pub fn take_debug_messages(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.debug_messages)
+    }
+
+    /// Stores `value` in the register `name`, replacing any previous value.
+    pub fn set_register(&mut self, name: &str, value: &str) {
+        self.registers.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn get_register(&self, name: &str) -> Option<&str> {
+        self.registers.get(name).map(|v| v.as_str())
     }
 }
 
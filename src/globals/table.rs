@@ -1,6 +1,9 @@
-use std::{ borrow::Cow, collections::HashMap, sync::{ Arc, LazyLock } };
+use std::{ borrow::Cow, collections::HashMap, sync::{ Arc, LazyLock, RwLock } };
 
-use crate::{ tokens::InstructionMethods, utils::errors::{ AtpError, AtpErrorCode } };
+use crate::{
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, params::AtpParamTypes },
+};
 
 use crate::tokens::{ instructions::*, transforms::* };
 
@@ -48,7 +51,8 @@ pub enum TargetValue {
     Syntax(Arc<[SyntaxDef]>),
 }
 
-pub struct TokenTable {
+#[derive(Default)]
+struct TokenMaps {
     id_to_code: HashMap<&'static str, u32>,
     code_to_id: HashMap<u32, &'static str>,
     id_to_token: HashMap<&'static str, TokenRef>,
@@ -57,81 +61,212 @@ pub struct TokenTable {
     code_to_syntax: HashMap<u32, Arc<[SyntaxDef]>>,
 }
 
-impl TokenTable {
-    pub fn find(
-        &self,
-        (query_source, query_target): (QuerySource, QueryTarget)
-    ) -> Result<TargetValue, AtpError> {
-        let err = || {
-            AtpError::new(
-                AtpErrorCode::TokenNotFound("Token Not Found in mapping".into()),
-                "TOKEN_TABLE.find()",
-                "query"
-            )
-        };
+impl TokenMaps {
+    fn insert(&mut self, id: &'static str, code: u32, token: TokenRef, syntax: Arc<[SyntaxDef]>) {
+        self.id_to_code.insert(id, code);
+        self.code_to_id.insert(code, id);
+
+        self.id_to_token.insert(id, token.clone_ref());
+        self.code_to_token.insert(code, token);
 
+        self.id_to_syntax.insert(id, syntax.clone());
+        self.code_to_syntax.insert(code, syntax);
+    }
+
+    fn find(&self, (query_source, query_target): (QuerySource, QueryTarget)) -> Option<TargetValue> {
         match (query_source, query_target) {
             // ✅ CORINGA: QuerySource e QueryTarget "iguais" (eco + valida existência)
             (QuerySource::Identifier(id), QueryTarget::Identifier) => {
-                // valida que existe
-                if self.id_to_code.contains_key(id.as_ref()) {
-                    // devolve o próprio id, mas como &'static str:
-                    // como seu mapa usa &'static str, pegamos pelo code_to_id via code:
-                    let code = *self.id_to_code.get(id.as_ref()).ok_or_else(err)?;
-                    let real_id = *self.code_to_id.get(&code).ok_or_else(err)?;
-                    Ok(TargetValue::Identifier(real_id))
-                } else {
-                    Err(err())
-                }
+                // devolve o próprio id, mas como &'static str (pegamos pelo code_to_id via code)
+                let code = self.id_to_code.get(id.as_ref())?;
+                self.code_to_id.get(code).map(|real_id| TargetValue::Identifier(real_id))
             }
             (QuerySource::Bytecode(code), QueryTarget::Bytecode) => {
-                // valida que existe
-                if self.code_to_id.contains_key(&code) {
-                    Ok(TargetValue::Bytecode(code))
-                } else {
-                    Err(err())
-                }
+                self.code_to_id.contains_key(&code).then_some(TargetValue::Bytecode(code))
             }
 
             // --- demais casos normais ---
             (QuerySource::Identifier(id), QueryTarget::Bytecode) => {
-                let code = *self.id_to_code.get(id.as_ref()).ok_or_else(err)?;
-                Ok(TargetValue::Bytecode(code))
+                self.id_to_code.get(id.as_ref()).map(|code| TargetValue::Bytecode(*code))
             }
             (QuerySource::Identifier(id), QueryTarget::Token) => {
-                let tok = self.id_to_token.get(id.as_ref()).ok_or_else(err)?;
-                Ok(TargetValue::Token(tok.clone_ref()))
+                self.id_to_token.get(id.as_ref()).map(|tok| TargetValue::Token(tok.clone_ref()))
             }
             (QuerySource::Bytecode(code), QueryTarget::Identifier) => {
-                let id = *self.code_to_id.get(&code).ok_or_else(err)?;
-                Ok(TargetValue::Identifier(id))
+                self.code_to_id.get(&code).map(|id| TargetValue::Identifier(id))
             }
             (QuerySource::Bytecode(code), QueryTarget::Token) => {
-                let tok = self.code_to_token.get(&code).ok_or_else(err)?;
-                Ok(TargetValue::Token(tok.clone_ref()))
+                self.code_to_token.get(&code).map(|tok| TargetValue::Token(tok.clone_ref()))
             }
             (QuerySource::Identifier(id), QueryTarget::Syntax) => {
-                let params = self.id_to_syntax.get(id.as_ref()).ok_or_else(err)?;
-                Ok(TargetValue::Syntax(params.clone()))
+                self.id_to_syntax.get(id.as_ref()).map(|params| TargetValue::Syntax(params.clone()))
             }
             (QuerySource::Bytecode(code), QueryTarget::Syntax) => {
-                let params = self.code_to_syntax.get(&code).ok_or_else(err)?;
-                Ok(TargetValue::Syntax(params.clone()))
+                self.code_to_syntax.get(&code).map(|params| TargetValue::Syntax(params.clone()))
             }
         }
     }
 }
+
+pub struct TokenTable {
+    builtin: TokenMaps,
+    /// Tokens added at runtime through `register_token`, looked up after the builtin ones.
+    custom: RwLock<TokenMaps>,
+}
+
+/// Identifiers of the tokens compiled into this build, sorted.
+///
+/// Entries of `TOKEN_TABLE` can be gated with `#[cfg(feature = "...")]`, so this list follows the
+/// enabled features and can be used to check whether a pipeline can run before loading it.
+/// Tokens added with `register_token` are listed too.
+pub fn available_tokens() -> Vec<&'static str> {
+    TOKEN_TABLE.identifiers()
+}
+
+/// Registers a custom token in `TOKEN_TABLE`, so `.atp` lines, bytecode files and pipeline specs
+/// can use it just like a builtin token.
+///
+/// `factory` builds the default instance of the token. Its `get_string_repr()` must be equal to
+/// `name`, and the params it holds define the token syntax (every param is required). With the
+/// `bytecode` feature the token is stored under its `get_opcode()`.
+///
+/// # Errors
+/// Returns `ValidationError` if `name` does not match the token identifier, or if the name or the
+/// opcode is already used by another token.
+pub fn register_token(
+    name: &'static str,
+    factory: fn() -> Box<dyn InstructionMethods>
+) -> Result<(), AtpError> {
+    let err = |message: String| {
+        AtpError::new(AtpErrorCode::ValidationError(message.into()), "register_token", name)
+    };
+
+    let token = factory();
+
+    if token.get_string_repr() != name {
+        return Err(
+            err(
+                format!(
+                    "Token identifier '{}' does not match the registered name",
+                    token.get_string_repr()
+                )
+            )
+        );
+    }
+
+    let syntax: Arc<[SyntaxDef]> = token
+        .get_params()
+        .iter()
+        .map(|param| {
+            SyntaxDef::req(match param {
+                AtpParamTypes::Usize(_) => SyntaxToken::Usize,
+                AtpParamTypes::Bool(_) => SyntaxToken::Bool,
+                AtpParamTypes::I64(_) => SyntaxToken::I64,
+                AtpParamTypes::Token(_) => SyntaxToken::Token,
+                AtpParamTypes::String(_) | AtpParamTypes::VarRef(_) => SyntaxToken::String,
+            })
+        })
+        .collect();
+
+    // A poisoned lock only means another thread panicked while holding it, the maps are still valid
+    let mut custom = TOKEN_TABLE.custom.write().unwrap_or_else(|e| e.into_inner());
+
+    #[cfg(feature = "bytecode")]
+    let code = token.get_opcode();
+    // Without bytecode the opcode is only used internally, so custom tokens get one past the
+    // builtin range
+    #[cfg(not(feature = "bytecode"))]
+    let code = 0x8000_0000 + (custom.id_to_code.len() as u32);
+
+    let builtin = &TOKEN_TABLE.builtin;
+
+    if builtin.id_to_code.contains_key(name) || custom.id_to_code.contains_key(name) {
+        return Err(err(format!("Token identifier '{}' is already registered", name)));
+    }
+    if builtin.code_to_id.contains_key(&code) || custom.code_to_id.contains_key(&code) {
+        return Err(err(format!("Token opcode 0x{:x} is already registered", code)));
+    }
+
+    custom.insert(name, code, TokenRef::Shared(Arc::from(token)), syntax);
+
+    Ok(())
+}
+
+impl TokenTable {
+    /// Identifiers of every registered token, sorted.
+    pub fn identifiers(&self) -> Vec<&'static str> {
+        let custom = self.custom.read().unwrap_or_else(|e| e.into_inner());
+
+        let mut ids: Vec<&'static str> = self.builtin.id_to_code
+            .keys()
+            .chain(custom.id_to_code.keys())
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Looks up a token by identifier or opcode.
+    ///
+    /// Lookups are exact: identifiers are compared as they are (case sensitive, no prefix or
+    /// fuzzy matching) and every identifier and opcode maps to a single token, so the result
+    /// never depends on the map iteration order. Builtin tokens are checked before the ones
+    /// added with `register_token`, which can't reuse a builtin identifier or opcode anyway.
+    ///
+    /// # Errors
+    /// Returns `TokenNotFound` when nothing matches the query.
+    pub fn find(&self, query: (QuerySource, QueryTarget)) -> Result<TargetValue, AtpError> {
+        if let Some(value) = self.builtin.find(query.clone()) {
+            return Ok(value);
+        }
+
+        self.custom
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .find(query)
+            .ok_or_else(|| {
+                AtpError::new(
+                    AtpErrorCode::TokenNotFound("Token Not Found in mapping".into()),
+                    "TOKEN_TABLE.find()",
+                    "query"
+                )
+            })
+    }
+
+    /// Default instance of the token identified by exactly `name`, see `find` for the lookup
+    /// rules.
+    ///
+    /// # Errors
+    /// Returns `TokenNotFound` if no token is registered under `name`.
+    pub fn find_exact(&self, name: &str) -> Result<Box<dyn InstructionMethods>, AtpError> {
+        match self.find((QuerySource::Identifier(name.to_string().into()), QueryTarget::Token)) {
+            Ok(TargetValue::Token(token)) => Ok(token.into_box()),
+            Ok(_) => unreachable!("Invalid query result (Token)"),
+            Err(_) =>
+                Err(
+                    AtpError::new(
+                        AtpErrorCode::TokenNotFound("Token Not Found in mapping".into()),
+                        "TOKEN_TABLE.find_exact()",
+                        name.to_string()
+                    )
+                ),
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SyntaxToken {
     String,
     Usize,
     Token,
+    Bool,
+    I64,
     Literal(&'static str),
 }
 
 pub struct SyntaxDef {
     pub token: SyntaxToken,
     pub optional: bool,
+    pub variadic: bool,
 }
 
 impl SyntaxDef {
@@ -139,12 +274,23 @@ impl SyntaxDef {
         SyntaxDef {
             token,
             optional: true,
+            variadic: false,
         }
     }
     pub fn req(token: SyntaxToken) -> Self {
         SyntaxDef {
             token,
             optional: false,
+            variadic: false,
+        }
+    }
+    /// Optional param that may repeat any number of times, only valid as the last entry of a
+    /// syntax and never for `SyntaxToken::Literal`.
+    pub fn many(token: SyntaxToken) -> Self {
+        SyntaxDef {
+            token,
+            optional: true,
+            variadic: true,
         }
     }
 }
@@ -152,7 +298,7 @@ impl SyntaxDef {
 macro_rules! define_token_table {
     (
         $vis:vis static $name:ident = [
-            $((
+            $($(#[$meta:meta])* (
                 $id:literal,
                 $code:expr,
                 $ctor:expr,
@@ -162,46 +308,25 @@ macro_rules! define_token_table {
         ];
     ) => {
         $vis static $name: LazyLock<TokenTable> = LazyLock::new(|| {
-            let mut id_to_code: HashMap<&'static str, u32> = HashMap::new();
-            let mut code_to_id: HashMap<u32, &'static str> = HashMap::new();
-            let mut id_to_token: HashMap<&'static str, TokenRef> = HashMap::new();
-            let mut code_to_token: HashMap<u32, TokenRef> = HashMap::new();
+            let mut builtin = TokenMaps::default();
 
-            let mut id_to_syntax: HashMap<&'static str, Arc<[SyntaxDef]>> = HashMap::new();
-            let mut code_to_syntax: HashMap<u32, Arc<[SyntaxDef]>> = HashMap::new();
-
-            $(
+            $($(#[$meta])* {
                 let token: TokenRef = ($ctor)();
-                let token_for_code = token.clone_ref();
 
-                if id_to_code.contains_key($id) {
+                if builtin.id_to_code.contains_key($id) {
                     panic!("define_token_table: duplicate identifier: {}", $id);
                 }
-                if code_to_id.contains_key(&$code) {
+                if builtin.code_to_id.contains_key(&$code) {
                     panic!("define_token_table: duplicate bytecode: 0x{:x} for {}", $code, $id);
                 }
 
                 // ✅ aqui é o ponto crítico: array -> slice
-
-                id_to_code.insert($id, $code);
-                code_to_id.insert($code, $id);
-
-                id_to_token.insert($id, token.clone_ref());
-                code_to_token.insert($code, token_for_code);
-
-                let arc_params = Arc::new($params);
-
-                id_to_syntax.insert($id, arc_params.clone());
-                code_to_syntax.insert($code, arc_params);
-            )*
+                builtin.insert($id, $code, token, Arc::new($params));
+            })*
 
             TokenTable {
-                id_to_code,
-                code_to_id,
-                id_to_token,
-                code_to_token,
-                id_to_syntax,
-                code_to_syntax,
+                builtin,
+                custom: RwLock::new(TokenMaps::default()),
             }
         });
     };
@@ -244,7 +369,7 @@ define_token_table! {
         ),
         (
             "rfw",
-            0x0b,
+            0x0c,
             || TokenRef::Shared(Arc::new(rfw::Rfw::default())),
             [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::String)],
         ),
@@ -260,7 +385,7 @@ define_token_table! {
         ),
         (
             "raw",
-            0x0c,
+            0x0b,
             || TokenRef::Shared(Arc::new(raw::Raw::default())),
             [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::String)],
         ),
@@ -317,7 +442,12 @@ define_token_table! {
             || TokenRef::Shared(Arc::new(tlcc::Tlcc::default())),
             [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
         ),
-        ("cfw", 0x18, || TokenRef::Shared(Arc::new(cfw::Cfw::default())), []),
+        (
+            "cfw",
+            0x18,
+            || TokenRef::Shared(Arc::new(cfw::Cfw::default())),
+            [SyntaxDef::opt(SyntaxToken::Bool)],
+        ),
         ("clw", 0x19, || TokenRef::Shared(Arc::new(clw::Clw::default())), []),
         (
             "sslt",
@@ -329,19 +459,27 @@ define_token_table! {
             "ctc",
             0x1b,
             || TokenRef::Shared(Arc::new(ctc::Ctc::default())),
-            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+            [
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::opt(SyntaxToken::Bool),
+            ],
         ),
         (
             "ctr",
             0x1c,
             || TokenRef::Shared(Arc::new(ctr::Ctr::default())),
-            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+            [
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::opt(SyntaxToken::Bool),
+            ],
         ),
         (
             "cts",
             0x1d,
             || TokenRef::Shared(Arc::new(cts::Cts::default())),
-            [SyntaxDef::req(SyntaxToken::Usize)],
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::opt(SyntaxToken::Bool)],
         ),
         (
             "rlw",
@@ -362,7 +500,12 @@ define_token_table! {
         ("urle", 0x20, || TokenRef::Shared(Arc::new(urle::Urle::default())), []),
         ("urld", 0x21, || TokenRef::Shared(Arc::new(urld::Urld::default())), []),
         ("rev", 0x22, || TokenRef::Shared(Arc::new(rev::Rev::default())), []),
-        ("splc", 0x23, || TokenRef::Shared(Arc::new(splc::Splc::default())), []),
+        (
+            "splc",
+            0x23,
+            || TokenRef::Shared(Arc::new(splc::Splc::default())),
+            [SyntaxDef::opt(SyntaxToken::String)],
+        ),
         ("htmle", 0x24, || TokenRef::Shared(Arc::new(htmle::Htmle::default())), []),
         ("htmlu", 0x25, || TokenRef::Shared(Arc::new(htmlu::Htmlu::default())), []),
         ("jsone", 0x26, || TokenRef::Shared(Arc::new(jsone::Jsone::default())), []),
@@ -415,6 +558,7 @@ define_token_table! {
                 SyntaxDef::req(SyntaxToken::String),
                 SyntaxDef::req(SyntaxToken::Literal("do")),
                 SyntaxDef::req(SyntaxToken::Token),
+                SyntaxDef::many(SyntaxToken::Token),
             ],
         ),
         (
@@ -430,8 +574,452 @@ define_token_table! {
         (
             "cblk",
             0x35,
-            || TokenRef::Shared(Arc::new(blk::Blk::default())),
+            || TokenRef::Shared(Arc::new(cblk::Cblk::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "mapl",
+            0x36,
+            || TokenRef::Shared(Arc::new(mapl::Mapl::default())),
+            [SyntaxDef::req(SyntaxToken::Literal("do")), SyntaxDef::req(SyntaxToken::Token)],
+        ),
+        (
+            "mapw",
+            0x37,
+            || TokenRef::Shared(Arc::new(mapw::Mapw::default())),
+            [SyntaxDef::req(SyntaxToken::Literal("do")), SyntaxDef::req(SyntaxToken::Token)],
+        ),
+        (
+            "lstripto",
+            0x38,
+            || TokenRef::Shared(Arc::new(lstripto::Lstripto::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "rstripto",
+            0x39,
+            || TokenRef::Shared(Arc::new(rstripto::Rstripto::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("slug", 0x3a, || TokenRef::Shared(Arc::new(slug::Slug::default())), []),
+        (
+            "abbr",
+            0x3b,
+            || TokenRef::Shared(Arc::new(abbr::Abbr::default())),
             [SyntaxDef::req(SyntaxToken::String)],
         ),
+        (
+            "padmul",
+            0x3c,
+            || TokenRef::Shared(Arc::new(padmul::Padmul::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "swpw",
+            0x3d,
+            || TokenRef::Shared(Arc::new(swpw::Swpw::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        ("revew", 0x3e, || TokenRef::Shared(Arc::new(revew::Revew::default())), []),
+        ("shesc", 0x3f, || TokenRef::Shared(Arc::new(shesc::Shesc::default())), []),
+        ("shunesc", 0x40, || TokenRef::Shared(Arc::new(shunesc::Shunesc::default())), []),
+        ("csve", 0x41, || TokenRef::Shared(Arc::new(csve::Csve::default())), []),
+        (
+            "numinc",
+            0x42,
+            || TokenRef::Shared(Arc::new(numinc::Numinc::default())),
+            [SyntaxDef::req(SyntaxToken::I64)],
+        ),
+        (
+            "tgl",
+            0x43,
+            || TokenRef::Shared(Arc::new(tgl::Tgl::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "findidx",
+            0x44,
+            || TokenRef::Shared(Arc::new(findidx::Findidx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "contains",
+            0x45,
+            || TokenRef::Shared(Arc::new(contains::Contains::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("sha256", 0x46, || TokenRef::Shared(Arc::new(sha256::Sha256Tok::default())), []),
+        ("crc32", 0x47, || TokenRef::Shared(Arc::new(crc32::Crc32Tok::default())), []),
+        (
+            "dflt",
+            0x48,
+            || TokenRef::Shared(Arc::new(dflt::Dflt::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        ("tbsa", 0x49, || TokenRef::Shared(Arc::new(tbsa::Tbsa::default())), []),
+        ("tlsa", 0x4a, || TokenRef::Shared(Arc::new(tlsa::Tlsa::default())), []),
+        ("trsa", 0x4b, || TokenRef::Shared(Arc::new(trsa::Trsa::default())), []),
+        ("nop", 0x4c, || TokenRef::Shared(Arc::new(nop::Nop::default())), []),
+        (
+            "cmt",
+            0x4d,
+            || TokenRef::Shared(Arc::new(cmt::Cmt::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "ddpw",
+            0x4e,
+            || TokenRef::Shared(Arc::new(ddpw::Ddpw::default())),
+            [SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        (
+            "ttlx",
+            0x4f,
+            || TokenRef::Shared(Arc::new(ttlx::Ttlx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "chunkevery",
+            0x50,
+            || TokenRef::Shared(Arc::new(chunkevery::Chunkevery::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("asciionly", 0x51, || TokenRef::Shared(Arc::new(asciionly::Asciionly::default())), []),
+        (
+            "printable",
+            0x52,
+            || TokenRef::Shared(Arc::new(printable::Printable::default())),
+            [SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        ("stripansi", 0x53, || TokenRef::Shared(Arc::new(stripansi::Stripansi::default())), []),
+        (
+            "lnfix",
+            0x54,
+            || TokenRef::Shared(Arc::new(lnfix::Lnfix::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("splitcase", 0x55, || TokenRef::Shared(Arc::new(splitcase::Splitcase::default())), []),
+        (
+            "ovw",
+            0x56,
+            || TokenRef::Shared(Arc::new(ovw::Ovw::default())),
+            [
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::req(SyntaxToken::Usize),
+                SyntaxDef::req(SyntaxToken::String),
+            ],
+        ),
+        (
+            "limit",
+            0x57,
+            || TokenRef::Shared(Arc::new(limit::Limit::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "require",
+            0x58,
+            || TokenRef::Shared(Arc::new(require::Require::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "wfreq",
+            0x59,
+            || TokenRef::Shared(Arc::new(wfreq::Wfreq::default())),
+            [SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        (
+            "rmel",
+            0x5a,
+            || TokenRef::Shared(Arc::new(rmel::Rmel::default())),
+            [SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        (
+            "transp",
+            0x5b,
+            || TokenRef::Shared(Arc::new(transp::Transp::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "colsel",
+            0x5c,
+            || TokenRef::Shared(Arc::new(colsel::Colsel::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        ("jsonp", 0x5d, || TokenRef::Shared(Arc::new(jsonp::Jsonp::default())), []),
+        ("jsonm", 0x5e, || TokenRef::Shared(Arc::new(jsonm::Jsonm::default())), []),
+        (
+            "rww",
+            0x5f,
+            || TokenRef::Shared(Arc::new(rww::Rww::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "ensurepfx",
+            0x60,
+            || TokenRef::Shared(Arc::new(ensurepfx::Ensurepfx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "ensuresfx",
+            0x61,
+            || TokenRef::Shared(Arc::new(ensuresfx::Ensuresfx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "stppfx",
+            0x62,
+            || TokenRef::Shared(Arc::new(stppfx::Stppfx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "stpsfx",
+            0x63,
+            || TokenRef::Shared(Arc::new(stpsfx::Stpsfx::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "mirror",
+            0x64,
+            || TokenRef::Shared(Arc::new(mirror::Mirror::default())),
+            [SyntaxDef::req(SyntaxToken::Bool)],
+        ),
+        (
+            "interleave",
+            0x65,
+            || TokenRef::Shared(Arc::new(interleave::Interleave::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "wsel",
+            0x66,
+            || TokenRef::Shared(Arc::new(wsel::Wsel::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "wslice",
+            0x67,
+            || TokenRef::Shared(Arc::new(wslice::Wslice::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "lslice",
+            0x68,
+            || TokenRef::Shared(Arc::new(lslice::Lslice::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "head",
+            0x69,
+            || TokenRef::Shared(Arc::new(head::Head::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "tail",
+            0x6a,
+            || TokenRef::Shared(Arc::new(tail::Tail::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "sample",
+            0x6b,
+            || TokenRef::Shared(Arc::new(sample::Sample::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "nrmnl",
+            0x6c,
+            || TokenRef::Shared(Arc::new(nrmnl::Nrmnl::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("qpe", 0x6d, || TokenRef::Shared(Arc::new(qpe::Qpe::default())), []),
+        ("qpd", 0x6e, || TokenRef::Shared(Arc::new(qpd::Qpd::default())), []),
+        (
+            "rotn",
+            0x6f,
+            || TokenRef::Shared(Arc::new(rotn::Rotn::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        ("expr", 0x70, || TokenRef::Shared(Arc::new(expr::Expr::default())), []),
+        (
+            "tabs",
+            0x71,
+            || TokenRef::Shared(Arc::new(tabs::Tabs::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::opt(SyntaxToken::Bool)],
+        ),
+        ("morsee", 0x72, || TokenRef::Shared(Arc::new(morsee::Morsee::default())), []),
+        ("morsed", 0x73, || TokenRef::Shared(Arc::new(morsed::Morsed::default())), []),
+        ("bine", 0x74, || TokenRef::Shared(Arc::new(bine::Bine::default())), []),
+        ("bind", 0x75, || TokenRef::Shared(Arc::new(bind::Bind::default())), []),
+        (
+            "joinw",
+            0x76,
+            || TokenRef::Shared(Arc::new(joinw::Joinw::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "mark",
+            0x77,
+            || TokenRef::Shared(Arc::new(mark::Mark::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("fold", 0x78, || TokenRef::Shared(Arc::new(fold::Fold::default())), []),
+        (
+            "submap",
+            0x79,
+            || TokenRef::Shared(Arc::new(submap::Submap::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "redact",
+            0x7a,
+            || TokenRef::Shared(Arc::new(redact::Redact::default())),
+            [SyntaxDef::req(SyntaxToken::String), SyntaxDef::req(SyntaxToken::String)],
+        ),
+        (
+            "cntm",
+            0x7b,
+            || TokenRef::Shared(Arc::new(cntm::Cntm::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("punctsp", 0x7c, || TokenRef::Shared(Arc::new(punctsp::Punctsp::default())), []),
+        (
+            "wrap",
+            0x7d,
+            || TokenRef::Shared(Arc::new(wrap::Wrap::default())),
+            [SyntaxDef::req(SyntaxToken::Usize), SyntaxDef::opt(SyntaxToken::Bool)],
+        ),
+        (
+            "interp",
+            0x7e,
+            || TokenRef::Shared(Arc::new(interp::Interp::default())),
+            [SyntaxDef::opt(SyntaxToken::Bool)],
+        ),
+        ("trln", 0x7f, || TokenRef::Shared(Arc::new(trln::Trln::default())), []),
+        (
+            "justify",
+            0x80,
+            || TokenRef::Shared(Arc::new(justify::Justify::default())),
+            [SyntaxDef::req(SyntaxToken::Usize)],
+        ),
+        (
+            "colalign",
+            0x81,
+            || TokenRef::Shared(Arc::new(colalign::Colalign::default())),
+            [SyntaxDef::req(SyntaxToken::String)],
+        ),
+        ("revl", 0x82, || TokenRef::Shared(Arc::new(revl::Revl::default())), []),
     ];
 }
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_tokens_includes_known_tokens() {
+        let tokens = available_tokens();
+
+        assert!(!tokens.is_empty());
+        assert!(tokens.contains(&"tua"));
+        assert!(tokens.contains(&"atb"));
+    }
+
+    #[test]
+    fn available_tokens_can_all_be_found() {
+        for id in available_tokens() {
+            assert!(
+                TOKEN_TABLE.find((QuerySource::Identifier(id.into()), QueryTarget::Token)).is_ok(),
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn every_token_string_repr_matches_its_identifier() {
+        let mut reprs = std::collections::HashSet::new();
+
+        for id in available_tokens() {
+            let token = TOKEN_TABLE.find_exact(id).unwrap();
+
+            assert_eq!(token.get_string_repr(), id);
+            assert!(reprs.insert(token.get_string_repr()), "duplicated repr: {}", id);
+        }
+    }
+
+    #[test]
+    fn find_exact_only_matches_the_exact_identifier() {
+        assert_eq!(TOKEN_TABLE.find_exact("tua").unwrap().get_string_repr(), "tua");
+
+        for name in ["TUA", "tu", "tua ", ""] {
+            let err = TOKEN_TABLE.find_exact(name).err().unwrap();
+
+            assert!(matches!(err.error_code, AtpErrorCode::TokenNotFound(_)));
+            assert_eq!(err.input, name);
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Turev {
+        params: Vec<AtpParamTypes>,
+    }
+
+    impl InstructionMethods for Turev {
+        fn get_params(&self) -> &Vec<AtpParamTypes> {
+            &self.params
+        }
+        fn get_string_repr(&self) -> &'static str {
+            "turev"
+        }
+        fn to_atp_line(&self) -> Cow<'static, str> {
+            "turev;\n".into()
+        }
+        fn transform(
+            &self,
+            input: &str,
+            _: &mut crate::context::execution_context::GlobalExecutionContext
+        ) -> Result<String, AtpError> {
+            Ok(input.to_uppercase().chars().rev().collect())
+        }
+        fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+            crate::utils::validations::check_vec_len(&params, 0, "turev", "")
+        }
+        #[cfg(feature = "bytecode")]
+        fn get_opcode(&self) -> u32 {
+            0x1000
+        }
+        #[cfg(feature = "bytecode")]
+        fn to_bytecode(&self) -> Vec<u8> {
+            use crate::to_bytecode;
+            let result: Vec<u8> = to_bytecode!(self.get_opcode(), []);
+            result
+        }
+    }
+
+    #[test]
+    fn register_token_makes_custom_tokens_parseable() {
+        use crate::context::execution_context::GlobalExecutionContext;
+        use crate::text::reader::read_from_text;
+
+        register_token("turev", || Box::new(Turev::default())).unwrap();
+
+        let token = read_from_text("turev;").unwrap();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert!(available_tokens().contains(&"turev"));
+        assert_eq!(token.apply_token("banana", &mut ctx), Ok("ANANAB".to_string()));
+
+        let err = register_token("turev", || Box::new(Turev::default())).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+    }
+
+    #[test]
+    fn register_token_rejects_builtin_names_and_mismatched_ids() {
+        let err = register_token("rev", || Box::new(rev::Rev::default())).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+
+        let err = register_token("notrev", || Box::new(rev::Rev::default())).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::ValidationError(_)));
+    }
+}
@@ -3,7 +3,6 @@ use std::ops::Deref;
 use crate::{
     context::execution_context::{ GlobalContextMethods, GlobalExecutionContext, VarValues },
     globals::table::{ QuerySource, QueryTarget, SyntaxDef, SyntaxToken, TOKEN_TABLE, TargetValue },
-    to_bytecode,
     tokens::{ InstructionMethods, transforms::dlf::Dlf },
     utils::{ errors::{ AtpError, AtpErrorCode }, params::AtpParamTypes },
 };
@@ -47,6 +46,52 @@ impl TokenWrapper {
     pub fn get_default_token(&self) -> Box<dyn InstructionMethods> {
         self.token.clone()
     }
+    pub fn get_val_params(&self) -> &Vec<ValType> {
+        &self.params
+    }
+    /// Same as `InstructionMethods::get_args`, but read from the wrapper params, so it also
+    /// works for tokens that were parsed and not applied yet. Variables are kept as `{{name}}`,
+    /// and syntax literals (e.g. `assoc`) and the params of nested tokens are included.
+    pub fn get_unresolved_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        self.push_unresolved_args(&mut args);
+        args
+    }
+
+    fn push_unresolved_args(&self, out: &mut Vec<String>) {
+        // Tokens missing from `TOKEN_TABLE` have no syntax literals, only their params are listed
+        let syntax = match
+            TOKEN_TABLE.find((
+                QuerySource::Identifier(self.get_string_repr().into()),
+                QueryTarget::Syntax,
+            ))
+        {
+            Ok(TargetValue::Syntax(x)) => Some(x),
+            _ => None,
+        };
+        let mut defs = iter_syntax(syntax.as_deref().unwrap_or(&[]));
+
+        for param in self.params.iter() {
+            // Literals expected before this param, stopping at the def it fills
+            for def in defs.by_ref() {
+                match def.token {
+                    SyntaxToken::Literal(literal) => out.push(literal.to_string()),
+                    _ => {
+                        break;
+                    }
+                }
+            }
+
+            match param {
+                ValType::VarRef(name) => out.push(format!("{{{{{}}}}}", name)),
+                ValType::Literal(AtpParamTypes::Token(inner)) => {
+                    out.push(inner.get_string_repr().to_string());
+                    inner.push_unresolved_args(out);
+                }
+                ValType::Literal(param) => out.push(param.to_string()),
+            }
+        }
+    }
     pub fn new(token: Box<dyn InstructionMethods>, params: Option<Vec<ValType>>) -> Self {
         match params {
             Some(param_vec) => { TokenWrapper { params: param_vec, token } }
@@ -118,18 +163,38 @@ impl TokenWrapper {
         Ok(t.to_bytecode())
     }
 
+    /// Encodes the token from the wrapper params, keeping variables as `VarRef` params so
+    /// they are resolved again when the bytecode is executed.
     pub fn to_bytecode_unresolved(&self) -> Result<Vec<u8>, AtpError> {
-        let result: Vec<u8> = Vec::new();
-        let mut unresolved_params: Vec<AtpParamTypes> = Vec::new();
+        let param_count: u8 = self.params
+            .len()
+            .try_into()
+            .map_err(|_| {
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters("Param count exceeds u8::MAX".into()),
+                    "to_bytecode_unresolved",
+                    self.to_atp_line()
+                )
+            })?;
+
+        // Body = [opcode u32][param_count u8][params...]
+        let mut body: Vec<u8> = Vec::new();
+        body.extend_from_slice(&self.get_opcode().to_be_bytes());
+        body.push(param_count);
+
+        let mut context = GlobalExecutionContext::new();
+
         for val in self.params.iter() {
-            match val {
-                ValType::Literal(x) => unresolved_params.push(x.clone()),
-                ValType::VarRef(name) =>
-                    unresolved_params.push(AtpParamTypes::VarRef(name.to_string())),
-            }
+            let param = match val {
+                ValType::Literal(x) => x.clone(),
+                ValType::VarRef(name) => AtpParamTypes::VarRef(name.to_string()),
+            };
+            param.write_as_instruction_param(&mut body, &mut context)?;
         }
 
-        let x = to_bytecode!(self.get_opcode(), []);
+        let mut result: Vec<u8> = Vec::with_capacity(8 + body.len());
+        result.extend_from_slice(&(body.len() as u64).to_be_bytes());
+        result.extend_from_slice(&body);
 
         Ok(result)
     }
@@ -147,6 +212,35 @@ pub fn get_effective_param_types(expected: &[SyntaxDef]) -> Vec<SyntaxToken> {
         .collect()
 }
 
+/// Number of non-literal params that are not marked as optional.
+pub fn get_required_param_count(expected: &[SyntaxDef]) -> usize {
+    expected
+        .iter()
+        .filter(|ip| !ip.optional && !matches!(ip.token, SyntaxToken::Literal(_)))
+        .count()
+}
+
+/// Iterates `expected` repeating a variadic param endlessly, callers stop once their input ends.
+pub fn iter_syntax(expected: &[SyntaxDef]) -> impl Iterator<Item = &SyntaxDef> {
+    expected.iter().flat_map(|ip| std::iter::repeat_n(ip, if ip.variadic { usize::MAX } else { 1 }))
+}
+
+/// Number of non-literal params the syntax accepts, `None` if it ends in a variadic param.
+pub fn get_max_param_count(expected: &[SyntaxDef]) -> Option<usize> {
+    if expected.iter().any(|ip| ip.variadic) {
+        return None;
+    }
+    Some(get_effective_param_types(expected).len())
+}
+
+/// Type expected for the `i`-th non-literal param, repeating a trailing variadic param.
+pub fn get_effective_param_type(expected: &[SyntaxDef], i: usize) -> Option<SyntaxToken> {
+    iter_syntax(expected)
+        .filter(|ip| !matches!(ip.token, SyntaxToken::Literal(_)))
+        .nth(i)
+        .map(|ip| ip.token)
+}
+
 impl ValType {
     #[allow(dead_code)]
     fn resolve_variables(
@@ -161,13 +255,13 @@ impl ValType {
             QueryTarget::Syntax,
         ))?;
 
-        let expected_params = get_effective_param_types(
-            &(match query_result {
-                TargetValue::Syntax(x) => x,
-                _ => unreachable!("Unreachable Code"),
-            })
-        );
-        if values.len() != expected_params.len() {
+        let syntax = match query_result {
+            TargetValue::Syntax(x) => x,
+            _ => unreachable!("Unreachable Code"),
+        };
+        let required = get_required_param_count(&syntax);
+        let max = get_max_param_count(&syntax);
+        if values.len() < required || max.is_some_and(|max| values.len() > max) {
             return Err(
                 AtpError::new(
                     AtpErrorCode::InvalidParameters("Param count mismatch".into()),
@@ -175,12 +269,17 @@ impl ValType {
                     format!(
                         "token={}, expected={}, got={}",
                         t.get_string_repr(),
-                        expected_params.len(),
+                        max.map_or_else(|| format!("{}..", required), |max| max.to_string()),
                         values.len()
                     )
                 )
             );
         }
+        let expected_params: Vec<SyntaxToken> = iter_syntax(&syntax)
+            .filter(|ip| !matches!(ip.token, SyntaxToken::Literal(_)))
+            .take(values.len())
+            .map(|ip| ip.token)
+            .collect();
 
         for (i, v) in values.iter().enumerate() {
             match v {
@@ -195,6 +294,12 @@ impl ValType {
                         (AtpParamTypes::Token(_), SyntaxToken::Token) => {
                             result.push(literal.clone());
                         }
+                        (AtpParamTypes::Bool(_), SyntaxToken::Bool) => {
+                            result.push(literal.clone());
+                        }
+                        (AtpParamTypes::I64(_), SyntaxToken::I64) => {
+                            result.push(literal.clone());
+                        }
                         _ => {
                             return Err(
                                 AtpError::new(
@@ -27,6 +27,7 @@ pub mod text;
 // Public
 
 pub mod api;
+pub use utils::validations::IndexPolicy;
 
 // Bytecode
 #[cfg(feature = "bytecode")]
@@ -48,4 +48,36 @@ macro_rules! parse_args {
         }
         }
     };
+    ($params:expr, $idx:expr, Bool, $msg:expr) => {
+        {
+        use crate::utils::params::AtpParamTypes;
+        use crate::utils::errors::{AtpError, AtpErrorCode};
+        match &$params[$idx] {
+            AtpParamTypes::Bool(payload) => *payload,
+            _ => {
+                return Err(AtpError::new(
+                    AtpErrorCode::InvalidParameters($msg.into()),
+                    "",
+                    "",
+                ));
+            }
+        }
+        }
+    };
+    ($params:expr, $idx:expr, I64, $msg:expr) => {
+        {
+        use crate::utils::params::AtpParamTypes;
+        use crate::utils::errors::{AtpError, AtpErrorCode};
+        match &$params[$idx] {
+            AtpParamTypes::I64(payload) => *payload,
+            _ => {
+                return Err(AtpError::new(
+                    AtpErrorCode::InvalidParameters($msg.into()),
+                    "",
+                    "",
+                ));
+            }
+        }
+        }
+    };
 }
@@ -1,10 +1,14 @@
 #[macro_export]
 macro_rules! to_bytecode {
     ($opcode:expr, [$($param:expr),* $(,)?]) => {
+        $crate::to_bytecode!($opcode, vec![$($param),*])
+    };
+    // Params built at runtime, e.g. tokens with a variable number of params
+    ($opcode:expr, $params:expr) => {
         {
         use crate::context::execution_context::GlobalExecutionContext;
         // Coleta os params pra contar e iterar
-        let params_vec: Vec<crate::utils::params::AtpParamTypes> = vec![$($param),*];
+        let params_vec: Vec<crate::utils::params::AtpParamTypes> = $params;
 
         let opcode_u32: u32 = $opcode;
         let param_count_u8: u8 = params_vec
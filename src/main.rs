@@ -1,18 +1,60 @@
 use atp::{
     api::atp_processor::{ AtpProcessor, AtpProcessorMethods },
     utils::{
-        cli::{ process_input_by_chunks, process_input_line_by_line, process_input_single_chunk },
-        errors::AtpError,
+        cli::{
+            ChunkUnit,
+            process_input_by_chunks,
+            process_input_line_by_line,
+            process_input_single_chunk,
+        },
+        errors::{ AtpError, AtpErrorCode },
     },
 };
 use clap::{ Arg, ArgAction, Command, value_parser };
-use std::{ fs::OpenOptions, io::{ self, Error, Read, Write }, path::PathBuf };
+use std::{ fs::OpenOptions, io::{ self, Read, Write }, path::PathBuf };
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum ReadMode {
     All,
     Line,
-    Chunk(usize),
+    Chunk(usize, ChunkUnit),
+}
+
+/// Parses the `--read-mode` value, valid modes are `all`, `line` and `chunk-N`.
+///
+/// `chunk-N` accepts an unit suffix: `c` for characters, `b` for bytes and `g` for graphemes,
+/// without a suffix the chunk size is measured in characters.
+fn parse_read_mode(s: &str) -> Result<ReadMode, AtpError> {
+    let invalid_mode = |reason: &str| {
+        AtpError::new(
+            AtpErrorCode::InvalidParameters(
+                format!("{}, valid modes are 'all', 'line' and 'chunk-N'", reason).into()
+            ),
+            "--read-mode",
+            s.to_string()
+        )
+    };
+
+    match s {
+        "all" => Ok(ReadMode::All),
+        "line" => Ok(ReadMode::Line),
+        _ =>
+            match s.strip_prefix("chunk-") {
+                Some(size) => {
+                    let (num, unit) = match size.char_indices().last() {
+                        Some((i, 'c')) => (&size[..i], ChunkUnit::Chars),
+                        Some((i, 'b')) => (&size[..i], ChunkUnit::Bytes),
+                        Some((i, 'g')) => (&size[..i], ChunkUnit::Graphemes),
+                        _ => (size, ChunkUnit::Chars),
+                    };
+
+                    num.parse::<usize>()
+                        .map(|n| ReadMode::Chunk(n, unit))
+                        .map_err(|_| invalid_mode("Invalid chunk size"))
+                }
+                None => Err(invalid_mode("Unknown read mode")),
+            }
+    }
 }
 
 fn build_cli() -> Command {
@@ -57,25 +99,9 @@ fn build_cli() -> Command {
                 .default_value("all")
                 .required(false)
                 .value_name("READ_MODE")
-                .value_parser(
-                    |s: &str| -> Result<ReadMode, Error> {
-                        if s == "all" {
-                            Ok(ReadMode::All)
-                        } else if s == "line" {
-                            Ok(ReadMode::Line)
-                        } else if let Some(num) = s.strip_prefix("chunk-") {
-                            num.parse::<usize>()
-                                .map(ReadMode::Chunk)
-                                .map_err(|_|
-                                    Error::new(io::ErrorKind::InvalidInput, "Chunk size inválido")
-                                )
-                        } else {
-                            Err(Error::new(io::ErrorKind::InvalidInput, "Modo inválido"))
-                        }
-                    }
-                )
+                .value_parser(parse_read_mode)
                 .help(
-                    "Input Read mode, default value is 'all', meaning it will read all file contents as a single string, other possible values are 'line', to read the file line by line, and 'chunk-X', meaning it will read the file in chunks of X characters"
+                    "Input Read mode, default value is 'all', meaning it will read all file contents as a single string, other possible values are 'line', to read the file line by line, and 'chunk-X', meaning it will read the file in chunks of X characters, use 'chunk-Xc', 'chunk-Xb' or 'chunk-Xg' to measure the chunks in characters, bytes or graphemes"
                 )
         )
         .arg(
@@ -97,7 +123,27 @@ fn build_cli() -> Command {
                 .required(false)
                 .value_name("debug")
                 .action(ArgAction::SetTrue)
-                .help("Determines whether ATP will run in debug mode or not, default is false")
+                .help(
+                    "Determines whether ATP will run in debug mode or not, default is false. Debug output is written to stderr"
+                )
+        )
+        .arg(
+            Arg::new("validate")
+                .long("validate")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Only checks that the ATP file is structurally sound, without reading or processing any input"
+                )
+        )
+        .arg(
+            Arg::new("lossy")
+                .long("lossy")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Replaces invalid UTF-8 sequences in the input with U+FFFD instead of failing, default is false"
+                )
         )
 }
 
@@ -108,10 +154,15 @@ fn process_by_mode(
     debug: bool,
     processor: &mut AtpProcessor
 ) -> Result<String, AtpError> {
+    if debug {
+        // Keeps the step by step log out of the result when it is written to stdout
+        processor.set_debug_writer(Box::new(io::stderr()));
+    }
+
     match read_mode {
         ReadMode::All => process_input_single_chunk(processor, id, data, debug),
         ReadMode::Line => process_input_line_by_line(processor, id, data, debug),
-        ReadMode::Chunk(s) => process_input_by_chunks(processor, id, data, *s, debug),
+        ReadMode::Chunk(s, unit) => process_input_by_chunks(processor, id, data, *s, *unit, debug),
     }
 }
 
@@ -124,6 +175,8 @@ fn main() -> Result<(), AtpError> {
     let atp_mode = matches.get_one::<String>("mode").unwrap();
     let read_mode = matches.get_one::<ReadMode>("read_mode").unwrap();
     let debug = matches.get_one::<bool>("debug").unwrap();
+    let validate = matches.get_one::<bool>("validate").unwrap();
+    let lossy = matches.get_one::<bool>("lossy").unwrap();
 
     if atp_mode == &"b" && file.extension().expect("Could not get input extension") != "atpbc" {
         panic!("You're using mode 'b'(bytecode), so the atp file must have the .atpbc extension!");
@@ -133,9 +186,23 @@ fn main() -> Result<(), AtpError> {
         panic!("ATP file does not exists!");
     }
 
-    let data: String = match input {
+    if *validate {
+        let mut processor = AtpProcessor::new();
+        let id = if atp_mode == &"b" {
+            processor.read_from_bytecode_file(file)?
+        } else {
+            processor.read_from_text_file(file)?
+        };
+
+        processor.validate_pipeline(&id)?;
+        println!("Pipeline is valid: {}", file.display());
+
+        return Ok(());
+    }
+
+    let bytes: Vec<u8> = match input {
         Some(path) => {
-            let mut b = String::new();
+            let mut b = Vec::new();
 
             if !file.exists() {
                 panic!("The specified file does not exists");
@@ -146,19 +213,27 @@ fn main() -> Result<(), AtpError> {
                 .open(path)
                 .expect(&format!("Error opening file {}", path.display().to_string()));
 
-            file.read_to_string(&mut b).expect("Error reading input file");
+            file.read_to_end(&mut b).expect("Error reading input file");
 
             b
         }
         None => {
-            let mut b = String::new();
+            let mut b = Vec::new();
 
-            io::stdin().read_to_string(&mut b).expect("Error while reading from stdin");
+            io::stdin().read_to_end(&mut b).expect("Error while reading from stdin");
 
             b
         }
     };
 
+    let data: String = if *lossy {
+        String::from_utf8_lossy(&bytes).into_owned()
+    } else {
+        String::from_utf8(bytes).expect(
+            "Input is not valid UTF-8, use --lossy to replace invalid sequences"
+        )
+    };
+
     let mut result: String = String::new();
 
     if atp_mode == &"b" {
@@ -255,5 +330,90 @@ mod atp_tests {
             assert_eq!(*read_mode, ReadMode::Line);
             assert_eq!(*debug, true);
         }
+
+        #[test]
+        fn test_validate_flag() {
+            let parser = build_cli();
+
+            let arg_vec = shell_words::split("atp -f ./instructions.atp --validate").unwrap();
+            let m = parser.clone().try_get_matches_from(arg_vec).unwrap();
+            assert_eq!(*m.get_one::<bool>("validate").unwrap(), true);
+
+            let arg_vec = shell_words::split("atp -f ./instructions.atp").unwrap();
+            let m = parser.try_get_matches_from(arg_vec).unwrap();
+            assert_eq!(*m.get_one::<bool>("validate").unwrap(), false);
+            assert_eq!(*m.get_one::<bool>("lossy").unwrap(), false);
+        }
+
+        #[test]
+        fn test_lossy_flag() {
+            let parser = build_cli();
+
+            let arg_vec = shell_words::split("atp -f ./instructions.atp --lossy").unwrap();
+            let m = parser.try_get_matches_from(arg_vec).unwrap();
+            assert_eq!(*m.get_one::<bool>("lossy").unwrap(), true);
+        }
+    }
+
+    mod read_mode_tests {
+        use crate::{ ReadMode, parse_read_mode };
+        use atp::utils::{ cli::ChunkUnit, errors::AtpErrorCode };
+
+        #[test]
+        fn parses_valid_modes() {
+            assert_eq!(parse_read_mode("all").unwrap(), ReadMode::All);
+            assert_eq!(parse_read_mode("line").unwrap(), ReadMode::Line);
+            assert_eq!(parse_read_mode("chunk-16").unwrap(), ReadMode::Chunk(16, ChunkUnit::Chars));
+        }
+
+        #[test]
+        fn parses_chunk_unit_suffixes() {
+            assert_eq!(
+                parse_read_mode("chunk-100c").unwrap(),
+                ReadMode::Chunk(100, ChunkUnit::Chars)
+            );
+            assert_eq!(
+                parse_read_mode("chunk-100b").unwrap(),
+                ReadMode::Chunk(100, ChunkUnit::Bytes)
+            );
+            assert_eq!(
+                parse_read_mode("chunk-100g").unwrap(),
+                ReadMode::Chunk(100, ChunkUnit::Graphemes)
+            );
+        }
+
+        #[test]
+        fn rejects_unknown_chunk_unit() {
+            for mode in ["chunk-100x", "chunk-c", "chunk-10bg"] {
+                let err = parse_read_mode(mode).unwrap_err();
+
+                assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+                assert_eq!(err.input, mode);
+            }
+        }
+
+        #[test]
+        fn rejects_non_numeric_chunk_size() {
+            let err = parse_read_mode("chunk-abc").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+            assert!(err.error_code.to_string().contains("chunk-N"));
+            assert_eq!(err.input, "chunk-abc");
+        }
+
+        #[test]
+        fn rejects_unknown_mode() {
+            let err = parse_read_mode("words").unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+            assert!(err.error_code.to_string().contains("'all', 'line' and 'chunk-N'"));
+        }
+
+        #[test]
+        fn cli_rejects_unknown_mode() {
+            let arg_vec = shell_words::split("atp -f ./instructions.atp -r words").unwrap();
+
+            assert!(crate::build_cli().try_get_matches_from(arg_vec).is_err());
+        }
     }
 }
@@ -5,6 +5,7 @@ use crate::{
         table::{ QuerySource, QueryTarget, TOKEN_TABLE, TargetValue },
         var::{ TokenWrapper },
     },
+    tokens::{ InstructionMethods, transforms::cmt::Cmt },
     utils::{
         errors::{ AtpError, AtpErrorCode },
         params::AtpParamTypes,
@@ -13,6 +14,12 @@ use crate::{
 };
 
 pub fn read_from_text(token_string: &str) -> Result<TokenWrapper, AtpError> {
+    // Comment lines are kept as is, they don't need `;` and aren't split like the other tokens.
+    if let Some(comment) = token_string.trim_start().strip_prefix('#') {
+        let token: Box<dyn InstructionMethods> = Box::new(Cmt::new(comment.trim()));
+        return Ok(token.into());
+    }
+
     let chunks = match
         shell_words::split(
             &token_string
@@ -43,13 +50,27 @@ pub fn read_from_text(token_string: &str) -> Result<TokenWrapper, AtpError> {
         }
     };
 
+    read_from_chunks(&chunks)
+}
+
+/// Builds a token from an already split instruction, where `chunks[0]` is the
+/// token identifier and the remaining chunks are its arguments.
+pub fn read_from_chunks(chunks: &[String]) -> Result<TokenWrapper, AtpError> {
+    let identifier = chunks.first().ok_or_else(|| {
+        AtpError::new(
+            AtpErrorCode::TextParsingError("Missing token identifier".into()),
+            "read_from_chunks",
+            ""
+        )
+    })?;
+
     let token_query = TOKEN_TABLE.find((
-        QuerySource::Identifier(chunks[0].clone().into()),
+        QuerySource::Identifier(identifier.clone().into()),
         QueryTarget::Token,
     ))?;
 
     let token_param_types = match
-        TOKEN_TABLE.find((QuerySource::Identifier(chunks[0].clone().into()), QueryTarget::Syntax))?
+        TOKEN_TABLE.find((QuerySource::Identifier(identifier.clone().into()), QueryTarget::Syntax))?
     {
         TargetValue::Syntax(p) => p,
         _ => unreachable!(" Invalid Query result"),
@@ -33,6 +33,15 @@ impl InstructionMethods for Blk {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         return &self.params;
     }
+    fn get_args(&self) -> Vec<String> {
+        let mut args = vec![
+            self.block_name.clone(),
+            "assoc".to_string(),
+            self.inner.get_string_repr().to_string()
+        ];
+        args.extend(self.inner.get_unresolved_args());
+        args
+    }
     fn get_opcode(&self) -> u32 {
         0x34
     }
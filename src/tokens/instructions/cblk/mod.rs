@@ -27,6 +27,9 @@ impl InstructionMethods for Cblk {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         return &self.params;
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.block_name.to_string()]
+    }
     fn get_opcode(&self) -> u32 {
         0x35
     }
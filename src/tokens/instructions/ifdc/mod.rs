@@ -18,7 +18,15 @@ use crate::utils::params::AtpParamTypes;
 
 /// Ifdc - If Do Contains
 ///
-/// if `input` contains `text`, the `inner` token will be executed, otherwise `input` is returned with no changes
+/// if `input` contains `text`, the `inner` tokens will be executed in sequence, otherwise `input` is returned with no changes
+///
+/// The condition is checked once, so either every inner token runs or none of them does.
+/// In `.atp` files the inner tokens follow `do` one after the other, e.g.
+/// `ifdc xy do tua atb laranja;`, with no limit on how many. Inner tokens cannot carry tokens
+/// of their own, so an `ifdc` nested inside another one is rejected by the parser.
+///
+/// While the execution context records branches, whether this one was taken is recorded too, see
+/// [`AtpProcessor::branch_report`](crate::api::atp_processor::AtpProcessor::branch_report).
 ///
 /// # Example
 ///
@@ -31,10 +39,10 @@ use crate::utils::params::AtpParamTypes;
 /// assert_eq!(token.transform("banana"), Ok("banana".to_string())); // Does nothing
 ///
 /// ```
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct Ifdc {
     text: String,
-    inner: TokenWrapper,
+    inner: Vec<TokenWrapper>,
     params: Vec<AtpParamTypes>,
 }
 
@@ -43,17 +51,46 @@ impl Ifdc {
         Ifdc {
             text: text.to_string(),
             params: vec![text.to_string().into(), inner.clone().into()],
-            inner,
+            inner: vec![inner],
         }
     }
+
+    /// Appends `inner` to the tokens executed when the condition matches.
+    pub fn push_inner(mut self, inner: TokenWrapper) -> Self {
+        self.params.push(inner.clone().into());
+        self.inner.push(inner);
+        self
+    }
+}
+
+impl Default for Ifdc {
+    fn default() -> Self {
+        Ifdc::new("", TokenWrapper::default())
+    }
 }
 
 impl InstructionMethods for Ifdc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         return &self.params;
     }
+    fn get_args(&self) -> Vec<String> {
+        let mut args = vec![self.text.clone(), "do".to_string()];
+        for inner in self.inner.iter() {
+            args.push(inner.get_string_repr().to_string());
+            args.extend(inner.get_unresolved_args());
+        }
+        args
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
-        format!("ifdc {} do {}", self.text, self.inner.to_atp_line()).into()
+        let inner: Vec<String> = self.inner
+            .iter()
+            .map(|t| {
+                let line = t.to_text_line_unresolved().unwrap_or_else(|_| t.to_atp_line().into());
+                line.trim_end().trim_end_matches(';').to_string()
+            })
+            .collect();
+
+        format!("ifdc {} do {};\n", self.text, inner.join(" ")).into()
     }
 
     fn get_string_repr(&self) -> &'static str {
@@ -61,11 +98,21 @@ impl InstructionMethods for Ifdc {
     }
 
     fn transform(&self, input: &str, c: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        if input.contains(&self.text) {
-            return Ok(self.inner.transform(input, &mut *c)?);
+        let taken = input.contains(&self.text);
+        if c.is_recording_branches() {
+            c.record_branch(self.to_atp_line().trim_end(), taken);
+        }
+
+        if !taken {
+            return Ok(input.to_string());
         }
 
-        Ok(input.to_string())
+        let mut result = input.to_string();
+        for inner in self.inner.iter() {
+            result = inner.apply_token(&result, &mut *c)?;
+        }
+
+        Ok(result)
     }
 
     #[cfg(feature = "bytecode")]
@@ -77,21 +124,24 @@ impl InstructionMethods for Ifdc {
 
         use crate::utils::params::AtpParamTypesJoin;
 
-        check_vec_len(&params, 2, "ifdc", params.join(""))?;
+        check_vec_len(&params, params.len().max(2), "ifdc", params.join(""))?;
 
         self.text = parse_args!(params, 0, String, "");
 
-        self.inner = parse_args!(params, 1, Token, "");
+        let mut inner = Vec::with_capacity(params.len() - 1);
+        for (i, _) in params.iter().enumerate().skip(1) {
+            inner.push(parse_args!(params, i, Token, ""));
+        }
+        self.inner = inner;
+        self.params = params.clone();
 
         Ok(())
     }
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
-        let result = to_bytecode!(self.get_opcode(), [
-            AtpParamTypes::String(self.text.clone()),
-            AtpParamTypes::Token(self.inner.clone()),
-        ]);
+        let mut params = vec![AtpParamTypes::String(self.text.clone())];
+        params.extend(self.inner.iter().map(|t| AtpParamTypes::Token(t.clone())));
 
-        result
+        to_bytecode!(self.get_opcode(), params)
     }
 }
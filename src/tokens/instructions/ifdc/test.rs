@@ -29,6 +29,25 @@ mod tests {
         assert_eq!(b, "banana".to_string(), "does nothing when not contains");
     }
 
+    #[test]
+    fn get_args_flattens_inner_token() {
+        use crate::tokens::transforms::raw::Raw;
+
+        let inner: Box<dyn InstructionMethods> = Box::new(Raw::new("a", "b").unwrap());
+        let token = Ifdc::new("xy", TokenWrapper::new(inner, None));
+
+        assert_eq!(
+            token.get_args(),
+            vec![
+                "xy".to_string(),
+                "do".to_string(),
+                "raw".to_string(),
+                "a".to_string(),
+                "b".to_string()
+            ]
+        );
+    }
+
     #[cfg(feature = "bytecode")]
     mod bytecode_tests {
         use super::*;
@@ -62,4 +81,116 @@ mod tests {
             assert_eq!(t.from_params(&params), Ok(()));
         }
     }
+
+    #[test]
+    fn transform_records_whether_the_branch_was_taken() {
+        use crate::tokens::transforms::atb::Atb;
+
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_record_branches(true);
+        let inner: Box<dyn InstructionMethods> = Box::new(Atb::new("laranja"));
+        let token = Ifdc::new("xy", TokenWrapper::new(inner, None));
+
+        token.transform("larryxy", &mut ctx).unwrap();
+        token.transform("banana", &mut ctx).unwrap();
+
+        let taken: Vec<bool> = ctx
+            .get_branches()
+            .iter()
+            .map(|b| b.taken)
+            .collect();
+        assert_eq!(taken, vec![true, false]);
+        assert_eq!(ctx.get_branches()[0].instruction, "ifdc xy do atb laranja;");
+        assert_eq!(ctx.take_branches().len(), 2);
+        assert!(ctx.get_branches().is_empty());
+    }
+
+    #[test]
+    fn transform_records_nothing_unless_asked_to() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Ifdc::new("xy", TokenWrapper::default());
+
+        token.transform("xy", &mut ctx).unwrap();
+        token.transform("banana", &mut ctx).unwrap();
+
+        assert!(ctx.get_branches().is_empty());
+    }
+
+    #[test]
+    fn transform_runs_every_inner_token_when_condition_matches() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let token = read_from_text("ifdc xy do raw xy _ tua ate !;").unwrap();
+
+        // The first inner token removes "xy", the others still run since the condition is
+        // only checked once
+        assert_eq!(token.apply_token("axyb", &mut ctx), Ok("A_B!".to_string()));
+    }
+
+    #[test]
+    fn transform_runs_no_inner_token_when_condition_fails() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_record_branches(true);
+        let token = read_from_text("ifdc xy do raw a _ tua ate !;").unwrap();
+
+        assert_eq!(token.apply_token("banana", &mut ctx), Ok("banana".to_string()));
+        assert!(!ctx.get_branches()[0].taken);
+    }
+
+    #[test]
+    fn to_atp_line_lists_every_inner_token() {
+        use crate::tokens::transforms::{ atb::Atb, tua::Tua };
+
+        let first: Box<dyn InstructionMethods> = Box::new(Tua::default());
+        let second: Box<dyn InstructionMethods> = Box::new(Atb::new("laranja"));
+        let token = Ifdc::new("xy", first.into()).push_inner(second.into());
+
+        assert_eq!(token.to_atp_line(), "ifdc xy do tua atb laranja;\n");
+        assert_eq!(
+            token.get_args(),
+            vec![
+                "xy".to_string(),
+                "do".to_string(),
+                "tua".to_string(),
+                "atb".to_string(),
+                "laranja".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn from_params_accepts_any_number_of_inner_tokens() {
+        use crate::utils::params::AtpParamTypes;
+
+        let mut t = Ifdc::default();
+        let mut params = vec![AtpParamTypes::String("xy".to_string())];
+        params.extend((0..20).map(|_| AtpParamTypes::Token(TokenWrapper::default())));
+
+        assert_eq!(t.from_params(&params), Ok(()));
+        assert_eq!(t.get_params().len(), 21);
+    }
+
+    #[test]
+    fn text_accepts_any_number_of_inner_tokens() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let line = format!("ifdc xy do {};", vec!["ate !"; 12].join(" "));
+        let token = read_from_text(&line).unwrap();
+
+        assert_eq!(token.apply_token("xy", &mut ctx), Ok(format!("xy{}", "!".repeat(12))));
+    }
+
+    #[test]
+    fn text_rejects_nested_ifdc() {
+        use crate::text::reader::read_from_text;
+
+        let result = read_from_text("ifdc xy do ifdc a do tua atb z;");
+
+        let err = result.err().expect("nested ifdc should not parse");
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
 }
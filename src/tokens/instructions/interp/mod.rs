@@ -0,0 +1,145 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// INTERP - Interpolate Registers
+///
+/// Replaces every `${name}` placeholder of `input` with the value of the register `name` of the
+/// execution context. Processors fill the registers of each run with the values given to
+/// [`AtpProcessor::set_register`](crate::api::atp_processor::AtpProcessor::set_register).
+///
+/// Placeholders naming a register that was never set are left untouched, unless `strict` is set,
+/// in which case they return an `AtpError`. A `${` without a closing `}` is kept as plain text.
+///
+/// The values come from the context, so the bytecode of `interp` has no params, except for the
+/// `strict` flag, which is only encoded (as a single Bool) when it is set.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::context::execution_context::GlobalExecutionContext;
+/// use atp::tokens::{InstructionMethods, instructions::interp::Interp};
+///
+/// let mut context = GlobalExecutionContext::new();
+/// context.set_register("name", "banana");
+///
+/// let token = Interp::default();
+///
+/// assert_eq!(
+///     token.transform("I like ${name}, not ${fruit}", &mut context),
+///     Ok("I like banana, not ${fruit}".to_string())
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct Interp {
+    pub strict: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Interp {
+    /// Opts into returning an error for placeholders without a register.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.params.clear();
+        if strict {
+            self.params.push(strict.into());
+        }
+        self.strict = strict;
+        self
+    }
+}
+
+impl InstructionMethods for Interp {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        if self.strict { vec![self.strict.to_string()] } else { vec![] }
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        if self.strict { "interp true;\n".into() } else { "interp;\n".into() }
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let mut result = String::with_capacity(input.len());
+        let mut rest = input;
+
+        while let Some(start) = rest.find("${") {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+
+            let end = match after.find('}') {
+                Some(end) => end,
+                None => {
+                    rest = &rest[start..];
+                    break;
+                }
+            };
+
+            let name = &after[..end];
+            match context.get_register(name) {
+                Some(value) => result.push_str(value),
+                None if self.strict => {
+                    return Err(
+                        AtpError::new(
+                            AtpErrorCode::VariableNotFound("Register not found".into()),
+                            "interp",
+                            name.to_string()
+                        )
+                    );
+                }
+                None => result.push_str(&rest[start..start + end + 3]),
+            }
+
+            rest = &after[end + 1..];
+        }
+
+        result.push_str(rest);
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "interp"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, if params.len() == 1 { 1 } else { 0 }, "interp", "")?;
+
+        self.strict = match params.len() {
+            1 => parse_args!(params, 0, Bool, "Strict flag should be of bool type"),
+            _ => false,
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7e
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        if self.strict {
+            to_bytecode!(self.get_opcode(), [AtpParamTypes::Bool(true)])
+        } else {
+            to_bytecode!(self.get_opcode(), [])
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, instructions::interp::Interp };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    fn context_with_registers() -> GlobalExecutionContext {
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_register("fruit", "banana");
+        ctx.set_register("n", "3");
+        ctx
+    }
+
+    #[test]
+    fn get_string_repr_is_interp() {
+        let t = Interp::default();
+        assert_eq!(t.get_string_repr(), "interp");
+    }
+
+    #[test]
+    fn transform_replaces_stored_registers() {
+        let mut ctx = context_with_registers();
+        let t = Interp::default();
+        assert_eq!(
+            t.transform("${n} ${fruit}s, ${n}${n}", &mut ctx),
+            Ok("3 bananas, 33".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_uses_latest_register_value() {
+        let mut ctx = context_with_registers();
+        ctx.set_register("fruit", "laranja");
+        let t = Interp::default();
+        assert_eq!(t.transform("${fruit}", &mut ctx), Ok("laranja".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_unknown_placeholders() {
+        let mut ctx = context_with_registers();
+        let t = Interp::default();
+        assert_eq!(
+            t.transform("${fruit} and ${missing}", &mut ctx),
+            Ok("banana and ${missing}".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_strict_rejects_unknown_placeholders() {
+        let mut ctx = context_with_registers();
+        let t = Interp::default().with_strict(true);
+        let err = t.transform("${fruit} and ${missing}", &mut ctx).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::VariableNotFound(_)));
+        assert_eq!(err.input, "missing");
+    }
+
+    #[test]
+    fn transform_keeps_unclosed_placeholder() {
+        let mut ctx = context_with_registers();
+        let t = Interp::default().with_strict(true);
+        assert_eq!(t.transform("$fruit ${fruit", &mut ctx), Ok("$fruit ${fruit".to_string()));
+    }
+
+    #[test]
+    fn from_params_reads_optional_strict_flag() {
+        let mut t = Interp::default();
+        t.from_params(&vec![AtpParamTypes::Bool(true)]).unwrap();
+        assert!(t.strict);
+
+        t.from_params(&vec![]).unwrap();
+        assert!(!t.strict);
+    }
+
+    #[test]
+    fn to_atp_line_includes_flag_only_when_strict() {
+        assert_eq!(Interp::default().to_atp_line().as_ref(), "interp;\n");
+        assert_eq!(Interp::default().with_strict(true).to_atp_line().as_ref(), "interp true;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Interp::default();
+        let params = vec![AtpParamTypes::Bool(true), AtpParamTypes::Bool(true)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7e() {
+            let t = Interp::default();
+            assert_eq!(t.get_opcode(), 0x7e);
+        }
+
+        #[test]
+        fn to_bytecode_has_no_params() {
+            let t = Interp::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7e);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+#[cfg(feature = "bytecode")]
+use crate::to_bytecode;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    globals::var::TokenWrapper,
+    tokens::{ InstructionMethods },
+};
+
+use crate::utils::errors::{ AtpError };
+
+use crate::utils::params::AtpParamTypes;
+
+/// Mapl - Map Lines
+///
+/// Splits `input` on `'\n'`, applies the `inner` token to each line independently and joins the lines back.
+///
+/// Blank lines are kept, so the number of lines in the output is always the same as in `input`.
+///
+/// See Also:
+///
+/// - [`Mapw` - Map Words](crate::tokens::instructions::mapw)
+///
+/// # Example
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, instructions::mapl::Mapl, transforms::atb::Atb};
+///
+/// let token = Mapl::new(Box::new(Atb::new("> ")).into());
+///
+/// assert_eq!(token.transform("banana\nlaranja"), Ok("> banana\n> laranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Mapl {
+    inner: TokenWrapper,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Mapl {
+    pub fn new(inner: TokenWrapper) -> Self {
+        Mapl {
+            params: vec![inner.clone().into()],
+            inner,
+        }
+    }
+}
+
+impl InstructionMethods for Mapl {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        let mut args = vec!["do".to_string(), self.inner.get_string_repr().to_string()];
+        args.extend(self.inner.get_unresolved_args());
+        args
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("mapl do {}", self.inner.to_atp_line()).into()
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "mapl"
+    }
+
+    fn transform(&self, input: &str, c: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let lines = input
+            .split('\n')
+            .map(|line| self.inner.apply_token(line, &mut *c))
+            .collect::<Result<Vec<String>, AtpError>>()?;
+
+        Ok(lines.join("\n"))
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x36
+    }
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::{ parse_args, utils::validations::check_vec_len };
+
+        check_vec_len(&params, 1, "mapl", "")?;
+
+        self.inner = parse_args!(params, 0, Token, "Inner should be of token type");
+        self.params = params.clone();
+
+        Ok(())
+    }
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        let result = to_bytecode!(self.get_opcode(), [AtpParamTypes::Token(self.inner.clone())]);
+
+        result
+    }
+}
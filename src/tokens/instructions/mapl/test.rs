@@ -0,0 +1,135 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{ AtpBuilderMethods, AtpConditionalMethods };
+    use crate::api::atp_processor::{ AtpProcessor, AtpProcessorMethods };
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::globals::var::TokenWrapper;
+    use crate::text::reader::read_from_text;
+    use crate::tokens::instructions::mapl::Mapl;
+    use crate::tokens::transforms::{ atb::Atb, tua::Tua };
+    use crate::tokens::InstructionMethods;
+    use crate::utils::errors::{ AtpError, AtpErrorCode };
+
+    fn wrap(token: Box<dyn InstructionMethods>) -> TokenWrapper {
+        TokenWrapper::new(token, None)
+    }
+
+    #[test]
+    fn get_string_repr_is_mapl() {
+        assert_eq!(Mapl::default().get_string_repr(), "mapl");
+    }
+
+    #[test]
+    fn to_atp_line_contains_inner_token() {
+        let token = Mapl::new(wrap(Box::new(Tua::default())));
+        assert_eq!(token.to_atp_line().as_ref(), "mapl do tua;\n");
+    }
+
+    #[test]
+    fn transform_applies_tua_per_line_and_keeps_blank_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapl::new(wrap(Box::new(Tua::default())));
+
+        assert_eq!(
+            token.transform("banana\n\nlaranja\n", &mut ctx),
+            Ok("BANANA\n\nLARANJA\n".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_applies_inner_token_once_per_line() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapl::new(wrap(Box::new(Atb::new("> "))));
+
+        assert_eq!(
+            token.transform("banana\n\nlaranja", &mut ctx),
+            Ok("> banana\n> \n> laranja".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_propagates_inner_errors() {
+        use crate::tokens::transforms::dls::Dls;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapl::new(wrap(Box::new(Dls::new(3))));
+
+        let err = token.transform("banana\nab", &mut ctx).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn read_from_text_parses_nested_token() -> Result<(), AtpError> {
+        let mut ctx = GlobalExecutionContext::new();
+        let wrapper = read_from_text("mapl do atb x;")?;
+
+        assert_eq!(wrapper.apply_token("a\nb", &mut ctx)?, "xa\nxb".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn map_lines_each_builds_one_mapl_per_token() -> Result<(), AtpError> {
+        let mut processor = AtpProcessor::new();
+
+        let id = processor
+            .create_pipeline()
+            .map_lines_each(|b| {
+                b.add_to_beginning("> ")?.to_uppercase_all()?;
+                Ok(())
+            })?
+            .build();
+
+        assert_eq!(processor.process_all(&id, "banana\n\nlaranja")?, "> BANANA\n> \n> LARANJA");
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+        use crate::utils::params::AtpParamTypes;
+
+        #[test]
+        fn opcode_ok() {
+            assert_eq!(Mapl::default().get_opcode(), 0x36);
+        }
+
+        #[test]
+        fn from_params_rejects_wrong_len() {
+            let mut t = Mapl::default();
+
+            let err = t.from_params(&vec![]).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+        }
+
+        #[test]
+        fn from_params_rejects_non_token_param() {
+            let mut t = Mapl::default();
+
+            let err = t.from_params(&vec![AtpParamTypes::String("tua".to_string())]).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        }
+
+        #[test]
+        fn to_bytecode_has_header_and_one_token_param() {
+            let t = Mapl::new(wrap(Box::new(Tua::default())));
+            let bc = t.to_bytecode();
+
+            assert!(bc.len() >= 13);
+
+            let total = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total, bc.len() - 8);
+            assert_eq!(opcode, 0x36);
+            assert_eq!(bc[12], 1);
+
+            let param_type = u32::from_be_bytes(bc[21..25].try_into().unwrap());
+            assert_eq!(param_type, 0x03);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+#[cfg(feature = "bytecode")]
+use crate::to_bytecode;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    globals::var::TokenWrapper,
+    tokens::{ InstructionMethods },
+};
+
+use crate::utils::errors::{ AtpError };
+
+use crate::utils::params::AtpParamTypes;
+
+/// Mapw - Map Words
+///
+/// Splits `input` on whitespace, applies the `inner` token to each word independently and joins the words back with single spaces.
+///
+/// See Also:
+///
+/// - [`Mapl` - Map Lines](crate::tokens::instructions::mapl)
+///
+/// # Example
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, instructions::mapw::Mapw, transforms::rev::Rev};
+///
+/// let token = Mapw::new(Box::new(Rev::default()).into());
+///
+/// assert_eq!(token.transform("banana  laranja"), Ok("ananab ajnaral".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Mapw {
+    inner: TokenWrapper,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Mapw {
+    pub fn new(inner: TokenWrapper) -> Self {
+        Mapw {
+            params: vec![inner.clone().into()],
+            inner,
+        }
+    }
+}
+
+impl InstructionMethods for Mapw {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        let mut args = vec!["do".to_string(), self.inner.get_string_repr().to_string()];
+        args.extend(self.inner.get_unresolved_args());
+        args
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("mapw do {}", self.inner.to_atp_line()).into()
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "mapw"
+    }
+
+    fn transform(&self, input: &str, c: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let words = input
+            .split_whitespace()
+            .map(|word| self.inner.apply_token(word, &mut *c))
+            .collect::<Result<Vec<String>, AtpError>>()?;
+
+        Ok(words.join(" "))
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x37
+    }
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::{ parse_args, utils::validations::check_vec_len };
+
+        check_vec_len(&params, 1, "mapw", "")?;
+
+        self.inner = parse_args!(params, 0, Token, "Inner should be of token type");
+        self.params = params.clone();
+
+        Ok(())
+    }
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        let result = to_bytecode!(self.get_opcode(), [AtpParamTypes::Token(self.inner.clone())]);
+
+        result
+    }
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{ AtpBuilderMethods, AtpConditionalMethods };
+    use crate::api::atp_processor::{ AtpProcessor, AtpProcessorMethods };
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::globals::var::TokenWrapper;
+    use crate::text::reader::read_from_text;
+    use crate::tokens::instructions::mapw::Mapw;
+    use crate::tokens::transforms::{ rev::Rev, tua::Tua };
+    use crate::tokens::InstructionMethods;
+    use crate::utils::errors::{ AtpError, AtpErrorCode };
+
+    fn wrap(token: Box<dyn InstructionMethods>) -> TokenWrapper {
+        TokenWrapper::new(token, None)
+    }
+
+    #[test]
+    fn get_string_repr_is_mapw() {
+        assert_eq!(Mapw::default().get_string_repr(), "mapw");
+    }
+
+    #[test]
+    fn to_atp_line_contains_inner_token() {
+        let token = Mapw::new(wrap(Box::new(Rev::default())));
+        assert_eq!(token.to_atp_line().as_ref(), "mapw do rev;\n");
+    }
+
+    #[test]
+    fn get_args_matches_the_atp_line_order() {
+        let token = Mapw::new(read_from_text("raw a {{b}};").unwrap());
+
+        assert_eq!(
+            token.get_args(),
+            vec!["do".to_string(), "raw".to_string(), "a".to_string(), "{{b}}".to_string()]
+        );
+    }
+
+    #[test]
+    fn transform_reverses_each_word_keeping_word_order() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapw::new(wrap(Box::new(Rev::default())));
+
+        assert_eq!(
+            token.transform("banana laranja cheia", &mut ctx),
+            Ok("ananab ajnaral aiehc".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_collapses_whitespace_into_single_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapw::new(wrap(Box::new(Tua::default())));
+
+        assert_eq!(token.transform("  banana\t\nlaranja  ", &mut ctx), Ok("BANANA LARANJA".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let token = Mapw::new(wrap(Box::new(Rev::default())));
+
+        assert_eq!(token.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn read_from_text_parses_nested_token() -> Result<(), AtpError> {
+        let mut ctx = GlobalExecutionContext::new();
+        let wrapper = read_from_text("mapw do rev;")?;
+
+        assert_eq!(wrapper.apply_token("abc def", &mut ctx)?, "cba fed".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn map_words_each_builds_one_mapw_per_token() -> Result<(), AtpError> {
+        let mut processor = AtpProcessor::new();
+
+        let id = processor
+            .create_pipeline()
+            .map_words_each(|b| {
+                b.to_reverse()?.add_to_end("!")?;
+                Ok(())
+            })?
+            .build();
+
+        assert_eq!(processor.process_all(&id, "banana laranja")?, "ananab! ajnaral!");
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+        use crate::utils::params::AtpParamTypes;
+
+        #[test]
+        fn opcode_ok() {
+            assert_eq!(Mapw::default().get_opcode(), 0x37);
+        }
+
+        #[test]
+        fn from_params_rejects_wrong_len() {
+            let mut t = Mapw::default();
+
+            let err = t.from_params(&vec![]).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+        }
+
+        #[test]
+        fn from_params_rejects_non_token_param() {
+            let mut t = Mapw::default();
+
+            let err = t.from_params(&vec![AtpParamTypes::String("rev".to_string())]).unwrap_err();
+
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        }
+
+        #[test]
+        fn to_bytecode_has_header_and_one_token_param() {
+            let t = Mapw::new(wrap(Box::new(Rev::default())));
+            let bc = t.to_bytecode();
+
+            let total = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total, bc.len() - 8);
+            assert_eq!(opcode, 0x37);
+            assert_eq!(bc[12], 1);
+
+            let param_type = u32::from_be_bytes(bc[21..25].try_into().unwrap());
+            assert_eq!(param_type, 0x03);
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub mod ifdc;
 pub mod blk;
 pub mod cblk;
+pub mod mapl;
+pub mod mapw;
+pub mod interp;
@@ -33,6 +33,14 @@ pub trait InstructionMethods: InstructionMethodsClone + Send + Sync {
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError>;
 
     fn get_params(&self) -> &Vec<AtpParamTypes>;
+
+    /// get_args
+    ///
+    /// Returns the token arguments as strings, in the same order they appear in an .atp line.
+    /// Tokens without parameters keep the default empty vec.
+    fn get_args(&self) -> Vec<String> {
+        Vec::new()
+    }
     /// BytecodeMethods
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8>;
@@ -0,0 +1,96 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// ABBR - Abbreviate
+///
+/// Builds an acronym from `input` by taking the first character of each word, uppercased,
+/// and joining them with `separator` (usually empty).
+///
+/// See Also:
+///
+/// - [`TUA` - To Uppercase All](crate::tokens::transforms::tua)
+/// - [`TUCW` - To Uppercase Word](crate::tokens::transforms::tucw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::abbr::Abbr};
+///
+/// let token = Abbr::new("");
+///
+/// assert_eq!(token.transform("portable document format"), Ok("PDF".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Abbr {
+    pub separator: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Abbr {
+    pub fn new(separator: &str) -> Self {
+        Abbr {
+            separator: separator.to_string(),
+            params: vec![separator.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Abbr {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.separator.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("abbr {};\n", self.separator).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(
+            input
+                .split_whitespace()
+                .filter_map(|w| w.chars().next())
+                .map(|c| c.to_uppercase().collect::<String>())
+                .collect::<Vec<_>>()
+                .join(&self.separator)
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "abbr"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "abbr", "")?;
+
+        self.separator = parse_args!(params, 0, String, "Separator should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3b
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.separator.clone()),
+        ])
+    }
+}
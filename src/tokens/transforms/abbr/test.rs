@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::abbr::Abbr };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_abbr() {
+        let t = Abbr::default();
+        assert_eq!(t.get_string_repr(), "abbr");
+    }
+
+    #[test]
+    fn transform_empty_separator() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Abbr::new("");
+        assert_eq!(t.transform("portable document format", &mut ctx), Ok("PDF".to_string()));
+    }
+
+    #[test]
+    fn transform_dot_separator() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Abbr::new(".");
+        assert_eq!(t.transform("portable document format", &mut ctx), Ok("P.D.F".to_string()));
+    }
+
+    #[test]
+    fn transform_words_starting_with_multibyte_characters() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Abbr::new("");
+        assert_eq!(t.transform("élan ñandu über", &mut ctx), Ok("ÉÑÜ".to_string()));
+    }
+
+    #[test]
+    fn transform_ignores_extra_whitespace() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Abbr::new("-");
+        assert_eq!(t.transform("  as   soon as\tpossible ", &mut ctx), Ok("A-S-A-P".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Abbr::new(".");
+        assert_eq!(t.to_atp_line().as_ref(), "abbr .;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_single_string() {
+        let mut t = Abbr::default();
+        t.from_params(&vec![AtpParamTypes::String("/".to_string())]).unwrap();
+        assert_eq!(t.separator, "/");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Abbr::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3b() {
+            let t = Abbr::default();
+            assert_eq!(t.get_opcode(), 0x3b);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Abbr::new(".");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3b);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// ASCIIONLY - ASCII Only
+///
+/// Removes every non-ASCII character of `input`.
+///
+/// Accented letters are dropped, not folded, so `café` becomes `caf`.
+///
+/// See Also:
+///
+/// - [`SLUG` - Slugify](crate::tokens::transforms::slug)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::asciionly::Asciionly};
+///
+/// let token = Asciionly::default();
+///
+/// assert_eq!(token.transform("olá, mundo! 🌍"), Ok("ol, mundo! ".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Asciionly {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Asciionly {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "asciionly;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.chars().filter(char::is_ascii).collect())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "asciionly"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "asciionly", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x51
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
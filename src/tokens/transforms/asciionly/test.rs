@@ -0,0 +1,79 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::asciionly::Asciionly };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_asciionly() {
+        let t = Asciionly::default();
+        assert_eq!(t.get_string_repr(), "asciionly");
+    }
+
+    #[test]
+    fn transform_drops_emoji_and_accents() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Asciionly::default();
+        assert_eq!(
+            t.transform("Crème brûlée 🍮 costs $5.00!", &mut ctx),
+            Ok("Crme brle  costs $5.00!".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_keeps_ascii_control_and_punctuation() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Asciionly::default();
+        assert_eq!(t.transform("a\tb\n~{}", &mut ctx), Ok("a\tb\n~{}".to_string()));
+    }
+
+    #[test]
+    fn transform_only_non_ascii_becomes_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Asciionly::default();
+        assert_eq!(t.transform("çãõ🙂", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Asciionly::default();
+        assert_eq!(t.to_atp_line().as_ref(), "asciionly;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Asciionly::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x51() {
+            let t = Asciionly::default();
+            assert_eq!(t.get_opcode(), 0x51);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Asciionly::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x51);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -46,6 +46,9 @@ impl InstructionMethods for Atb {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         return &self.params;
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("atb {};\n", self.text).into()
     }
@@ -42,6 +42,9 @@ impl InstructionMethods for Ate {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("ate {};\n", self.text).into()
     }
@@ -93,6 +93,12 @@ mod tests {
     // ============================
     // Bytecode-only tests (separados)
     // ============================
+    #[test]
+    fn get_args_returns_text() {
+        let t = Ate::new("banana");
+        assert_eq!(t.get_args(), vec!["banana".to_string()]);
+    }
+
     #[cfg(feature = "bytecode")]
     mod bytecode_tests {
         use super::*;
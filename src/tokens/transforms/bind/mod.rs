@@ -0,0 +1,100 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// BIND - Binary Decode
+///
+/// Decodes whitespace separated groups of 8 binary digits back into text, each group being one
+/// UTF-8 byte.
+///
+/// It will throw an `AtpError` if a group is not exactly 8 `0`/`1` digits, or if the decoded bytes
+/// are not valid UTF-8.
+///
+/// See Also:
+///
+/// - [`BINE` - Binary Encode](crate::tokens::transforms::bine)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::bind::Bind};
+///
+/// let token = Bind::default();
+///
+/// assert_eq!(token.transform("01001000 01101001"), Ok("Hi".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Bind {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Bind {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "bind;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let malformed = |message: String| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError(message.into()),
+                self.to_atp_line(),
+                input.to_string()
+            )
+        };
+
+        let mut decoded: Vec<u8> = Vec::new();
+
+        for group in input.split_whitespace() {
+            let byte = match group.len() {
+                8 if group.bytes().all(|b| b == b'0' || b == b'1') => {
+                    u8::from_str_radix(group, 2).ok()
+                }
+                _ => None,
+            };
+
+            match byte {
+                Some(b) => decoded.push(b),
+                None => {
+                    return Err(malformed(format!("Malformed binary group '{}'", group)));
+                }
+            }
+        }
+
+        String::from_utf8(decoded).map_err(|_| {
+            malformed("Decoded binary is not valid UTF-8".to_string())
+        })
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "bind"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "bind", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x75
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
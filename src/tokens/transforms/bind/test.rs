@@ -0,0 +1,82 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::bind::Bind };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_bind() {
+        let t = Bind::default();
+        assert_eq!(t.get_string_repr(), "bind");
+    }
+
+    #[test]
+    fn transform_decodes_groups() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Bind::default();
+        assert_eq!(t.transform("01000001\n01000010  ", &mut ctx), Ok("AB".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_decodes_multibyte_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Bind::default();
+        assert_eq!(t.transform("11000011 10101001", &mut ctx), Ok("é".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_malformed_groups() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Bind::default();
+
+        for input in ["0100001", "010000010", "01000002", "0b000001"] {
+            let err = t.transform(input, &mut ctx).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)), "{}", input);
+        }
+    }
+
+    #[test]
+    fn transform_rejects_invalid_utf8() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Bind::default().transform("11000011", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Bind::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x75() {
+            let t = Bind::default();
+            assert_eq!(t.get_opcode(), 0x75);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Bind::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x75);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
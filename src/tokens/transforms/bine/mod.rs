@@ -0,0 +1,75 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// BINE - Binary Encode
+///
+/// Encodes each UTF-8 byte of `input` as a group of 8 binary digits, groups are separated by a
+/// space.
+///
+/// See Also:
+///
+/// - [`BIND` - Binary Decode](crate::tokens::transforms::bind)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::bine::Bine};
+///
+/// let token = Bine::default();
+///
+/// assert_eq!(token.transform("Hi"), Ok("01001000 01101001".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Bine {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Bine {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "bine;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(
+            input
+                .bytes()
+                .map(|b| format!("{:08b}", b))
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "bine"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "bine", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x74
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::bine::Bine };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_bine() {
+        let t = Bine::default();
+        assert_eq!(t.get_string_repr(), "bine");
+    }
+
+    #[test]
+    fn transform_encodes_ascii_bytes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Bine::default();
+        assert_eq!(t.transform("AB", &mut ctx), Ok("01000001 01000010".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_encodes_every_utf8_byte() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Bine::default();
+        assert_eq!(t.transform("é", &mut ctx), Ok("11000011 10101001".to_string()));
+    }
+
+    #[test]
+    fn transform_round_trips_through_bind() {
+        use crate::tokens::transforms::bind::Bind;
+
+        let mut ctx = GlobalExecutionContext::new();
+
+        for input in ["banana split", "maçã 🍎"] {
+            let encoded = Bine::default().transform(input, &mut ctx).unwrap();
+            assert_eq!(Bind::default().transform(&encoded, &mut ctx), Ok(input.to_string()));
+        }
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Bine::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x74() {
+            let t = Bine::default();
+            assert_eq!(t.get_opcode(), 0x74);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Bine::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x74);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
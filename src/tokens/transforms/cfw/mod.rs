@@ -6,7 +6,15 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, transforms::capitalize, validations::check_vec_len },
+    utils::{
+        errors::AtpError,
+        transforms::{
+            SMART_CAPITALIZE_BOUNDARIES,
+            capitalize_smart_with_locale,
+            capitalize_with_locale,
+        },
+        validations::check_vec_len,
+    },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -14,6 +22,8 @@ use crate::utils::params::AtpParamTypes;
 ///
 /// Capitalizes the first word of `input`
 ///
+/// When `smart` is set, letters after apostrophes and hyphens in that word are capitalized too
+///
 /// # Example
 ///
 /// ```rust
@@ -24,9 +34,22 @@ use crate::utils::params::AtpParamTypes;
 /// ```
 #[derive(Clone, Default)]
 pub struct Cfw {
+    pub smart: bool,
     params: Vec<AtpParamTypes>,
 }
 
+impl Cfw {
+    /// Opts into `capitalize_smart`, which also capitalizes letters after apostrophes and hyphens.
+    pub fn with_smart(mut self, smart: bool) -> Self {
+        self.params.truncate(0);
+        if smart {
+            self.params.push(smart.into());
+        }
+        self.smart = smart;
+        self
+    }
+}
+
 impl InstructionMethods for Cfw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
@@ -34,18 +57,41 @@ impl InstructionMethods for Cfw {
     fn get_string_repr(&self) -> &'static str {
         "cfw"
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        Ok(capitalize(input))
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
+        if !self.smart {
+            return Ok(capitalize_with_locale(input, locale));
+        }
+
+        let end = input.find(char::is_whitespace).unwrap_or(input.len());
+        let first = capitalize_smart_with_locale(
+            &input[..end],
+            SMART_CAPITALIZE_BOUNDARIES,
+            locale
+        );
+        Ok(format!("{}{}", first, &input[end..]))
     }
 
     fn to_atp_line(&self) -> Cow<'static, str> {
-        "cfw;\n".into()
+        if self.smart { "cfw true;\n".into() } else { "cfw;\n".into() }
     }
 
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
         use crate::utils::params::AtpParamTypesJoin;
 
-        check_vec_len(&params, 0, "cfw", params.join(""))?;
+        if params.is_empty() {
+            self.smart = false;
+            return Ok(());
+        }
+
+        check_vec_len(&params, 1, "cfw", params.join(""))?;
+
+        self.smart = parse_args!(params, 0, Bool, "Smart flag should be of bool type");
         Ok(())
     }
     #[cfg(feature = "bytecode")]
@@ -55,7 +101,11 @@ impl InstructionMethods for Cfw {
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
-        let result: Vec<u8> = to_bytecode!(self.get_opcode(), []);
+        let result: Vec<u8> = if self.smart {
+            to_bytecode!(self.get_opcode(), [AtpParamTypes::Bool(true)])
+        } else {
+            to_bytecode!(self.get_opcode(), [])
+        };
         result
     }
 }
@@ -63,15 +63,49 @@ mod tests {
     }
 
     #[test]
-    fn from_params_rejects_any_params() {
+    fn from_params_rejects_more_than_the_smart_flag() {
         let mut t = Cfw::default();
-        let params = vec![AtpParamTypes::String("x".to_string())];
+        let params = vec![AtpParamTypes::Bool(true), AtpParamTypes::String("x".to_string())];
 
         let err = t.from_params(&params).unwrap_err();
 
         assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
     }
 
+    #[test]
+    fn from_params_rejects_non_bool_flag() {
+        let mut t = Cfw::default();
+        let params = vec![AtpParamTypes::String("x".to_string())];
+
+        assert!(t.from_params(&params).is_err());
+    }
+
+    #[test]
+    fn from_params_accepts_smart_flag() {
+        let mut t = Cfw::default();
+
+        assert_eq!(t.from_params(&vec![AtpParamTypes::Bool(true)]), Ok(()));
+        assert!(t.smart);
+    }
+
+    #[test]
+    fn transform_smart_capitalizes_after_apostrophe_in_first_word_only() {
+        let t = Cfw::default().with_smart(true);
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("o'brien o'neil", &mut ctx).unwrap(), "O'Brien o'neil");
+        assert_eq!(t.to_atp_line().as_ref(), "cfw true;\n");
+    }
+
+    #[test]
+    fn transform_without_smart_keeps_plain_capitalize() {
+        let t = Cfw::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("o'brien", &mut ctx).unwrap(), "O'brien");
+    }
+
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
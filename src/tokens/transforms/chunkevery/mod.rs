@@ -0,0 +1,113 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CHUNKEVERY - Chunk Every N Characters
+///
+/// Groups the characters of `input` into blocks of `n` characters joined by `separator`.
+/// The last block holds the remaining characters when the length is not a multiple of `n`.
+///
+/// It will throw an `AtpError` if `n` is 0.
+///
+/// See Also:
+///
+/// - [`SPLC` - Split Characters](crate::tokens::transforms::splc)
+/// - [`PADMUL` - Pad To Multiple](crate::tokens::transforms::padmul)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::chunkevery::Chunkevery};
+///
+/// let token = Chunkevery::new(4, " ");
+///
+/// assert_eq!(token.transform("1234567890"), Ok("1234 5678 90".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Chunkevery {
+    pub n: usize,
+    pub separator: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Chunkevery {
+    pub fn new(n: usize, separator: &str) -> Self {
+        Chunkevery {
+            n,
+            separator: separator.to_string(),
+            params: vec![n.into(), separator.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Chunkevery {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.n.to_string(), self.separator.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("chunkevery {} {};\n", self.n, self.separator).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.n == 0 {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters("Chunk size should be greater than zero".into()),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        let chars = input.chars().collect::<Vec<_>>();
+
+        Ok(
+            chars
+                .chunks(self.n)
+                .map(|c| c.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join(&self.separator)
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "chunkevery"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "chunkevery", "")?;
+
+        self.n = parse_args!(params, 0, Usize, "Chunk size should be of usize type");
+        self.separator = parse_args!(params, 1, String, "Separator should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x50
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.n),
+            AtpParamTypes::String(self.separator.clone()),
+        ])
+    }
+}
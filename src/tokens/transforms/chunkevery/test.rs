@@ -0,0 +1,92 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::chunkevery::Chunkevery };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_chunkevery() {
+        let t = Chunkevery::default();
+        assert_eq!(t.get_string_repr(), "chunkevery");
+    }
+
+    #[test]
+    fn transform_exact_multiple() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Chunkevery::new(3, "-");
+        assert_eq!(t.transform("abcdef", &mut ctx), Ok("abc-def".to_string()));
+    }
+
+    #[test]
+    fn transform_remainder_group() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Chunkevery::new(4, " ");
+        assert_eq!(t.transform("1234567890", &mut ctx), Ok("1234 5678 90".to_string()));
+    }
+
+    #[test]
+    fn transform_counts_chars_not_bytes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Chunkevery::new(2, "|");
+        assert_eq!(t.transform("áéíóú", &mut ctx), Ok("áé|íó|ú".to_string()));
+    }
+
+    #[test]
+    fn transform_n_larger_than_input_is_unchanged() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Chunkevery::new(10, "-");
+        assert_eq!(t.transform("abc", &mut ctx), Ok("abc".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_zero_n_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Chunkevery::new(0, "-");
+        let err = t.transform("abc", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Chunkevery::new(4, "-");
+        assert_eq!(t.to_atp_line().as_ref(), "chunkevery 4 -;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Chunkevery::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x50() {
+            let t = Chunkevery::default();
+            assert_eq!(t.get_opcode(), 0x50);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Chunkevery::new(4, "-");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x50);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, transforms::capitalize, validations::check_vec_len },
+    utils::{ errors::AtpError, transforms::capitalize_with_locale, validations::check_vec_len },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -37,12 +37,17 @@ impl InstructionMethods for Clw {
     fn get_string_repr(&self) -> &'static str {
         "clw"
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
         let mut v: Vec<String> = input
             .split(' ')
             .rev()
             .enumerate()
-            .map(|(i, c)| if i == 0 { capitalize(c) } else { c.to_string() })
+            .map(|(i, c)| if i == 0 { capitalize_with_locale(c, locale) } else { c.to_string() })
             .collect::<Vec<_>>();
 
         v.reverse();
@@ -0,0 +1,91 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CMT - Comment
+///
+/// Returns `input` unchanged, it only carries a `text` used to annotate `.atp` files.
+///
+/// Besides the `cmt "text";` form, every `.atp` line starting with `#` is read as a comment,
+/// which is also the form written back by `to_atp_line`.
+///
+/// See Also:
+///
+/// - [`NOP` - No Operation](crate::tokens::transforms::nop)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::cmt::Cmt};
+///
+/// let token = Cmt::new("uppercase everything below");
+///
+/// assert_eq!(token.transform("banana"), Ok("banana".to_string()));
+/// assert_eq!(token.to_atp_line(), "# uppercase everything below\n");
+/// ```
+#[derive(Clone, Default)]
+pub struct Cmt {
+    pub text: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Cmt {
+    pub fn new(text: &str) -> Self {
+        Cmt {
+            text: text.to_string(),
+            params: vec![text.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Cmt {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        if self.text.is_empty() { "#\n".into() } else { format!("# {}\n", self.text).into() }
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "cmt"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "cmt", "")?;
+
+        self.text = parse_args!(params, 0, String, "Comment should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4d
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.text.clone()),
+        ])
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::cmt::Cmt };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_cmt() {
+        let t = Cmt::default();
+        assert_eq!(t.get_string_repr(), "cmt");
+    }
+
+    #[test]
+    fn to_atp_line_keeps_comment_text() {
+        let t = Cmt::new("remove the extension, see issue 12");
+        assert_eq!(t.to_atp_line().as_ref(), "# remove the extension, see issue 12\n");
+        assert_eq!(Cmt::default().to_atp_line().as_ref(), "#\n");
+    }
+
+    #[test]
+    fn transform_is_identity() {
+        let t = Cmt::new("anything");
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("  banana ", &mut ctx), Ok("  banana ".to_string()));
+    }
+
+    #[test]
+    fn hash_lines_are_read_as_comments() {
+        use crate::text::reader::read_from_text;
+
+        let token = read_from_text("  # don't touch this line").unwrap();
+
+        assert_eq!(token.get_string_repr(), "cmt");
+        assert_eq!(token.to_text_line_unresolved().unwrap(), "# don't touch this line\n");
+    }
+
+    #[test]
+    fn cmt_keyword_is_read_as_comment() {
+        use crate::text::reader::read_from_text;
+
+        let token = read_from_text("cmt \"hello world\";").unwrap();
+
+        assert_eq!(token.to_text_line_unresolved().unwrap(), "# hello world\n");
+    }
+
+    #[test]
+    fn comment_lines_do_not_affect_file_output() {
+        use crate::text::reader::read_from_file;
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("atp_cmt_{}.atp", std::process::id()));
+        std::fs::write(&path, "# trim first\ntbs;\n# then shout\ncmt note;\ntua;\n").unwrap();
+
+        let tokens = read_from_file(&path);
+        let _ = std::fs::remove_file(&path);
+        let tokens = tokens.unwrap();
+
+        let mut ctx = GlobalExecutionContext::new();
+        let mut output = "  banana  ".to_string();
+        for token in tokens.iter() {
+            output = token.apply_token(&output, &mut ctx).unwrap();
+        }
+
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(output, "BANANA");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Cmt::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x4d() {
+            let t = Cmt::default();
+            assert_eq!(t.get_opcode(), 0x4d);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Cmt::new("note");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x4d);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::{ borrow::Cow, sync::Arc };
+
+use regex::Regex;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, regex_cache::get_or_compile, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CNTM - Count Matches
+///
+/// Replaces `input` with the number of matches of `pattern` in it, written in decimal.
+///
+/// Matches are counted like `Regex::find_iter`, so they never overlap: after a match the search
+/// goes on from its end (`aa` matches "aaaa" twice, not three times).
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::cntm::Cntm};
+///
+/// let token = Cntm::new(r"an").unwrap();
+///
+/// assert_eq!(token.transform("banana"), Ok("2".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Cntm {
+    pub pattern: Arc<Regex>,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Cntm {
+    pub fn new(pattern: &str) -> Result<Self, AtpError> {
+        Ok(Cntm { pattern: get_or_compile(pattern)?, params: vec![pattern.to_string().into()] })
+    }
+}
+
+impl Default for Cntm {
+    fn default() -> Self {
+        Cntm { pattern: Arc::new(Regex::new("").unwrap()), params: vec!["".to_string().into()] }
+    }
+}
+
+impl InstructionMethods for Cntm {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("cntm {};\n", self.pattern).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(self.pattern.find_iter(input).count().to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "cntm"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "cntm", "")?;
+
+        let pattern: String = parse_args!(params, 0, String, "Pattern should be of string type");
+        self.pattern = get_or_compile(&pattern)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7b
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [AtpParamTypes::String(self.pattern.to_string())])
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::cntm::Cntm };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_cntm() {
+        let t = Cntm::default();
+        assert_eq!(t.get_string_repr(), "cntm");
+    }
+
+    #[test]
+    fn transform_counts_zero_one_and_many_matches() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Cntm::new(r"\d+").unwrap();
+
+        assert_eq!(t.transform("banana", &mut ctx), Ok("0".to_string()));
+        assert_eq!(t.transform("banana 12", &mut ctx), Ok("1".to_string()));
+        assert_eq!(t.transform("1 banana, 22 laranjas, 333 canjas", &mut ctx), Ok("3".to_string()));
+    }
+
+    #[test]
+    fn transform_does_not_count_overlapping_matches() {
+        let mut ctx = GlobalExecutionContext::new();
+
+        // find_iter resumes after each match, so "aaaa" has 2 matches of "aa" and "ana" is
+        // found once in "banana"
+        assert_eq!(Cntm::new("aa").unwrap().transform("aaaa", &mut ctx), Ok("2".to_string()));
+        assert_eq!(Cntm::new("ana").unwrap().transform("banana", &mut ctx), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn transform_counts_empty_matches_between_chars() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Cntm::new("").unwrap().transform("abc", &mut ctx), Ok("4".to_string()));
+    }
+
+    #[test]
+    fn new_rejects_invalid_pattern() {
+        let err = Cntm::new("[").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_sets_pattern() {
+        let mut ctx = GlobalExecutionContext::new();
+        let mut t = Cntm::default();
+
+        t.from_params(&vec![AtpParamTypes::String("a".to_string())]).unwrap();
+
+        assert_eq!(t.transform("banana", &mut ctx), Ok("3".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Cntm::new(r"\w+").unwrap().to_atp_line().as_ref(), "cntm \\w+;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Cntm::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7b() {
+            let t = Cntm::default();
+            assert_eq!(t.get_opcode(), 0x7b);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Cntm::new(r"\w+").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7b);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// COLALIGN - Align Columns
+///
+/// Splits every line of `input` on `delimiter` and pads each cell with spaces up to the widest cell
+/// of its column, joining the cells back with `delimiter` followed by a space.
+///
+/// Cells are trimmed before measuring, rows with less columns than the others get empty padded
+/// cells, and empty lines are kept empty. An empty `delimiter` returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`colsel` - Select Columns](crate::tokens::transforms::colsel)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::colalign::Colalign};
+///
+/// let token = Colalign::new(",").unwrap();
+///
+/// assert_eq!(token.transform("a,bb\nccc,d"), Ok("a  , bb\nccc, d ".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Colalign {
+    pub delimiter: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Colalign {
+    pub fn new(delimiter: &str) -> Result<Self, AtpError> {
+        check_delimiter(delimiter)?;
+
+        Ok(Colalign {
+            delimiter: delimiter.to_string(),
+            params: vec![delimiter.to_string().into()],
+        })
+    }
+}
+
+fn check_delimiter(delimiter: &str) -> Result<(), AtpError> {
+    if delimiter.is_empty() {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Delimiter should not be empty".into()),
+                "colalign",
+                delimiter.to_string()
+            )
+        );
+    }
+    Ok(())
+}
+
+impl Default for Colalign {
+    fn default() -> Self {
+        Colalign { delimiter: ",".to_string(), params: vec![",".to_string().into()] }
+    }
+}
+
+impl InstructionMethods for Colalign {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.delimiter.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("colalign {};\n", self.delimiter).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let rows: Vec<Vec<&str>> = input
+            .split('\n')
+            .map(|line| {
+                if line.is_empty() {
+                    Vec::new()
+                } else {
+                    line.split(self.delimiter.as_str())
+                        .map(|cell| cell.trim())
+                        .collect()
+                }
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = Vec::new();
+        for row in rows.iter() {
+            for (i, cell) in row.iter().enumerate() {
+                let len = cell.chars().count();
+                match widths.get_mut(i) {
+                    Some(width) => *width = (*width).max(len),
+                    None => widths.push(len),
+                }
+            }
+        }
+
+        let separator = format!("{} ", self.delimiter);
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                if row.is_empty() {
+                    return String::new();
+                }
+
+                widths
+                    .iter()
+                    .enumerate()
+                    .map(|(i, width)| {
+                        let cell = row.get(i).copied().unwrap_or("");
+                        format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(&separator)
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "colalign"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "colalign", "")?;
+
+        self.delimiter = parse_args!(params, 0, String, "Delimiter should be of string type");
+        check_delimiter(&self.delimiter)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x81
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.delimiter.clone()),
+        ])
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::colalign::Colalign };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_colalign() {
+        let t = Colalign::default();
+        assert_eq!(t.get_string_repr(), "colalign");
+    }
+
+    #[test]
+    fn transform_pads_ragged_cells_to_the_column_width() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colalign::new("|").unwrap();
+        assert_eq!(
+            t.transform("name|age\nbanana|3\nfig | 120", &mut ctx),
+            Ok("name  | age\nbanana| 3  \nfig   | 120".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_pads_missing_cells() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colalign::new(",").unwrap();
+        assert_eq!(
+            t.transform("a,bb,c\ndd\ne,f", &mut ctx),
+            Ok("a , bb, c\ndd,   ,  \ne , f ,  ".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_keeps_empty_lines_and_multichar_delimiters() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colalign::new("::").unwrap();
+        assert_eq!(
+            t.transform("ab::c\n\nd::eee", &mut ctx),
+            Ok("ab:: c  \n\nd :: eee".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_is_idempotent() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colalign::new(",").unwrap();
+        let once = t.transform("a,bb\nccc,d\ne", &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()));
+    }
+
+    #[test]
+    fn new_rejects_empty_delimiter() {
+        let err = Colalign::new("").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+
+        let mut t = Colalign::default();
+        let err = t.from_params(&vec![AtpParamTypes::String(String::new())]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Colalign::new("|").unwrap().to_atp_line().as_ref(), "colalign |;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Colalign::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x81() {
+            let t = Colalign::default();
+            assert_eq!(t.get_opcode(), 0x81);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Colalign::new("|").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x81);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// COLSEL - Column Select
+///
+/// Splits each line of `input` by `delimiter` and keeps only the cell at `index` (0-based), like `cut -f`.
+/// Lines that do not have that column become empty.
+///
+/// A trailing newline at the end of `input` is preserved, an empty `delimiter` returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`TRANSP` - Transpose](crate::tokens::transforms::transp)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::colsel::Colsel};
+///
+/// let token = Colsel::new(",", 1);
+///
+/// assert_eq!(token.transform("banana,3\nlaranja,5"), Ok("3\n5".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Colsel {
+    pub delimiter: String,
+    pub index: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Colsel {
+    pub fn new(delimiter: &str, index: usize) -> Self {
+        Colsel {
+            delimiter: delimiter.to_string(),
+            index,
+            params: vec![delimiter.to_string().into(), index.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Colsel {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.delimiter.to_string(), self.index.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("colsel {} {};\n", self.delimiter, self.index).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.delimiter.is_empty() {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters("Delimiter should not be empty".into()),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        let body = input.strip_suffix('\n');
+
+        let mut result = body
+            .unwrap_or(input)
+            .split('\n')
+            .map(|line| line.split(self.delimiter.as_str()).nth(self.index).unwrap_or(""))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "colsel"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "colsel", "")?;
+
+        self.delimiter = parse_args!(params, 0, String, "Delimiter should be of string type");
+        self.index = parse_args!(params, 1, Usize, "Index should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5c
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.delimiter.clone()),
+            AtpParamTypes::Usize(self.index),
+        ])
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::colsel::Colsel };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_colsel() {
+        let t = Colsel::default();
+        assert_eq!(t.get_string_repr(), "colsel");
+    }
+
+    #[test]
+    fn transform_selects_present_column() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colsel::new(" ", 1);
+        assert_eq!(
+            t.transform("banana 3 amarela\nlaranja 5 laranja", &mut ctx),
+            Ok("3\n5".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_first_column() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colsel::new(" ", 0);
+        assert_eq!(t.transform("a b\nc d\n", &mut ctx), Ok("a\nc\n".to_string()));
+    }
+
+    #[test]
+    fn transform_missing_column_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colsel::new(",", 2);
+        assert_eq!(t.transform("a,b,c\nd\ne,f,g", &mut ctx), Ok("c\n\ng".to_string()));
+    }
+
+    #[test]
+    fn transform_custom_delimiter() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Colsel::new("::", 1);
+        assert_eq!(
+            t.transform("root::x::0\nuser::y::1000", &mut ctx),
+            Ok("x\ny".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_rejects_empty_delimiter() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Colsel::new("", 0).transform("a b", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Colsel::new(",", 2).to_atp_line().as_ref(), "colsel , 2;\n");
+    }
+
+    #[test]
+    fn from_params_reads_delimiter_and_index() {
+        let mut t = Colsel::default();
+        t.from_params(&vec![AtpParamTypes::String(";".to_string()), AtpParamTypes::Usize(3)]).unwrap();
+        assert_eq!(t.delimiter, ";");
+        assert_eq!(t.index, 3);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Colsel::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5c() {
+            let t = Colsel::default();
+            assert_eq!(t.get_opcode(), 0x5c);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Colsel::new(",", 2);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5c);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
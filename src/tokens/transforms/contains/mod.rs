@@ -0,0 +1,90 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CONTAINS - Contains
+///
+/// Replaces `input` with `true` if it contains `text`, and with `false` otherwise.
+///
+/// An empty `text` is contained in every input.
+///
+/// See Also:
+///
+/// - [`FINDIDX` - Find Index](crate::tokens::transforms::findidx)
+/// - [`SSLT` - Split Select](crate::tokens::transforms::sslt)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::contains::Contains};
+///
+/// let token = Contains::new("nan");
+///
+/// assert_eq!(token.transform("banana"), Ok("true".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Contains {
+    pub text: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Contains {
+    pub fn new(text: &str) -> Self {
+        Contains {
+            text: text.to_string(),
+            params: vec![text.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Contains {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("contains {};\n", self.text).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.contains(self.text.as_str()).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "contains"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "contains", "")?;
+
+        self.text = parse_args!(params, 0, String, "Text should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x45
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.text.clone()),
+        ])
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::contains::Contains };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_contains() {
+        let t = Contains::default();
+        assert_eq!(t.get_string_repr(), "contains");
+    }
+
+    #[test]
+    fn transform_present_substring_is_true() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Contains::new("nan");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("true".to_string()));
+    }
+
+    #[test]
+    fn transform_absent_substring_is_false() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Contains::new("laranja");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("false".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_needle_is_always_true() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Contains::new("");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("true".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("true".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Contains::new("nan");
+        assert_eq!(t.to_atp_line().as_ref(), "contains nan;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_single_string() {
+        let mut t = Contains::default();
+        t.from_params(&vec![AtpParamTypes::String("x".to_string())]).unwrap();
+        assert_eq!(t.text, "x");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Contains::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x45() {
+            let t = Contains::default();
+            assert_eq!(t.get_opcode(), 0x45);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Contains::new("nan");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x45);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CRC32 - CRC-32 Checksum
+///
+/// Replaces `input` with the CRC-32 (IEEE) checksum of its UTF-8 bytes, as an 8 digit lowercase hex string.
+///
+/// It is not a cryptographic hash, use it only for quick change detection.
+///
+/// See Also:
+///
+/// - [`SHA256` - SHA-256 Digest](crate::tokens::transforms::sha256)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::crc32::Crc32Tok};
+///
+/// let token = Crc32Tok::default();
+///
+/// assert_eq!(token.transform("123456789"), Ok("cbf43926".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Crc32Tok {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Crc32Tok {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "crc32;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(format!("{:08x}", crc32fast::hash(input.as_bytes())))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "crc32"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "crc32", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x47
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
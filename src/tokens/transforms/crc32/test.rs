@@ -0,0 +1,79 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::crc32::Crc32Tok };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_crc32() {
+        let t = Crc32Tok::default();
+        assert_eq!(t.get_string_repr(), "crc32");
+    }
+
+    #[test]
+    fn transform_empty_string_is_zero() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Crc32Tok::default();
+        assert_eq!(t.transform("", &mut ctx), Ok("00000000".to_string()));
+    }
+
+    #[test]
+    fn transform_check_value() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Crc32Tok::default();
+        assert_eq!(t.transform("123456789", &mut ctx), Ok("cbf43926".to_string()));
+    }
+
+    #[test]
+    fn transform_known_sentence() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Crc32Tok::default();
+        assert_eq!(
+            t.transform("The quick brown fox jumps over the lazy dog", &mut ctx),
+            Ok("414fa339".to_string())
+        );
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Crc32Tok::default();
+        assert_eq!(t.to_atp_line().as_ref(), "crc32;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Crc32Tok::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x47() {
+            let t = Crc32Tok::default();
+            assert_eq!(t.get_opcode(), 0x47);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Crc32Tok::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x47);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// CSVE - CSV Escape
+///
+/// Escapes `input` as a single CSV field following RFC 4180.
+///
+/// Fields containing `,`, `"`, `\r` or `\n` are wrapped in double quotes and the quotes inside them
+/// are doubled, every other field is returned unchanged.
+///
+/// See Also:
+///
+/// - [`SHESC` - Shell Escape](crate::tokens::transforms::shesc)
+/// - [`JSONE` - JSON Escape](crate::tokens::transforms::jsone)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::csve::Csve};
+///
+/// let token = Csve::default();
+///
+/// assert_eq!(token.transform("banana, laranja"), Ok("\"banana, laranja\"".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Csve {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Csve {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "csve;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if !input.contains([',', '"', '\r', '\n']) {
+            return Ok(input.to_string());
+        }
+
+        Ok(format!("\"{}\"", input.replace('"', "\"\"")))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "csve"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "csve", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x41
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::csve::Csve };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_csve() {
+        let t = Csve::default();
+        assert_eq!(t.get_string_repr(), "csve");
+    }
+
+    #[test]
+    fn transform_plain_field_is_unquoted() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Csve::default();
+        assert_eq!(t.transform("banana laranja", &mut ctx), Ok("banana laranja".to_string()));
+    }
+
+    #[test]
+    fn transform_field_with_comma_is_quoted() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Csve::default();
+        assert_eq!(t.transform("banana, laranja", &mut ctx), Ok("\"banana, laranja\"".to_string()));
+    }
+
+    #[test]
+    fn transform_doubles_embedded_quotes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Csve::default();
+        assert_eq!(t.transform("say \"hi\"", &mut ctx), Ok("\"say \"\"hi\"\"\"".to_string()));
+    }
+
+    #[test]
+    fn transform_field_with_newline_is_quoted() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Csve::default();
+        assert_eq!(t.transform("a\r\nb", &mut ctx), Ok("\"a\r\nb\"".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Csve::default();
+        assert_eq!(t.to_atp_line().as_ref(), "csve;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Csve::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x41() {
+            let t = Csve::default();
+            assert_eq!(t.get_opcode(), 0x41);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Csve::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x41);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -8,7 +8,11 @@ use crate::utils::errors::AtpError;
 use crate::utils::validations::check_vec_len;
 use crate::{
     tokens::InstructionMethods,
-    utils::transforms::{ capitalize },
+    utils::transforms::{
+        SMART_CAPITALIZE_BOUNDARIES,
+        capitalize_smart_with_locale,
+        capitalize_with_locale,
+    },
     utils::validations::check_chunk_bound_indexes,
 };
 
@@ -23,6 +27,8 @@ use crate::utils::{ params::AtpParamTypes };
 /// - If `start_index` is out of bounds for the number of characters in the input, an `AtpError` is returned.
 /// - If `end_index` exceeds the input's length, it will be clamped to the input's character count.
 ///
+/// When `smart` is set, letters after apostrophes and hyphens are capitalized too.
+///
 /// # Example
 ///
 /// ```rust
@@ -35,6 +41,7 @@ use crate::utils::{ params::AtpParamTypes };
 pub struct Ctc {
     pub start_index: usize,
     pub end_index: usize,
+    pub smart: bool,
     params: Vec<AtpParamTypes>,
 }
 
@@ -44,19 +51,38 @@ impl Ctc {
         Ok(Ctc {
             start_index,
             end_index,
+            smart: false,
             params: vec![start_index.into(), end_index.into()],
         })
     }
+
+    /// Opts into `capitalize_smart`, which also capitalizes letters after apostrophes and hyphens.
+    pub fn with_smart(mut self, smart: bool) -> Self {
+        self.params.truncate(2);
+        if smart {
+            self.params.push(smart.into());
+        }
+        self.smart = smart;
+        self
+    }
 }
 
 impl InstructionMethods for Ctc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "ctc"
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
         let len = input.chars().count();
 
         let mut end = self.end_index;
@@ -92,7 +118,13 @@ impl InstructionMethods for Ctc {
         // Capitalize all words in the slice
         let capitalized_chunk = slice
             .split_whitespace()
-            .map(|w| capitalize(w))
+            .map(|w| {
+                if self.smart {
+                    capitalize_smart_with_locale(w, SMART_CAPITALIZE_BOUNDARIES, locale)
+                } else {
+                    capitalize_with_locale(w, locale)
+                }
+            })
             .collect::<Vec<_>>()
             .join(" ");
 
@@ -106,16 +138,24 @@ impl InstructionMethods for Ctc {
     }
 
     fn to_atp_line(&self) -> Cow<'static, str> {
-        format!("ctc {} {};\n", self.start_index, self.end_index).into()
+        if self.smart {
+            format!("ctc {} {} true;\n", self.start_index, self.end_index).into()
+        } else {
+            format!("ctc {} {};\n", self.start_index, self.end_index).into()
+        }
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
         use crate::parse_args;
         use crate::utils::params::AtpParamTypesJoin;
 
-        check_vec_len(&params, 2, "ctc", params.join(""))?;
+        check_vec_len(&params, if params.len() == 3 { 3 } else { 2 }, "ctc", params.join(""))?;
 
         self.start_index = parse_args!(params, 0, Usize, "Index should be of usize type");
         self.end_index = parse_args!(params, 1, Usize, "Index should be of usize type");
+        self.smart = match params.len() {
+            3 => parse_args!(params, 2, Bool, "Smart flag should be of bool type"),
+            _ => false,
+        };
 
         return Ok(());
     }
@@ -126,10 +166,18 @@ impl InstructionMethods for Ctc {
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
-        let result: Vec<u8> = to_bytecode!(self.get_opcode(), [
-            AtpParamTypes::Usize(self.start_index),
-            AtpParamTypes::Usize(self.end_index),
-        ]);
+        let result: Vec<u8> = if self.smart {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.start_index),
+                AtpParamTypes::Usize(self.end_index),
+                AtpParamTypes::Bool(true),
+            ])
+        } else {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.start_index),
+                AtpParamTypes::Usize(self.end_index),
+            ])
+        };
         result
     }
 }
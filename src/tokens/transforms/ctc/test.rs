@@ -120,6 +120,7 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
@@ -184,4 +185,20 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn transform_smart_capitalizes_apostrophes_and_hyphens() {
+        let t = Ctc::new(0, 30).unwrap().with_smart(true);
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("o'brien jean-paul", &mut ctx).unwrap(), "O'Brien Jean-Paul");
+    }
+
+    #[test]
+    fn with_smart_false_drops_the_flag_param() {
+        let t = Ctc::new(0, 3).unwrap().with_smart(true).with_smart(false);
+
+        assert_eq!(t.get_params().len(), 2);
+        assert_eq!(t.to_atp_line().as_ref(), "ctc 0 3;\n");
+    }
 }
@@ -10,7 +10,11 @@ use crate::utils::params::AtpParamTypes;
 use crate::utils::validations::check_vec_len;
 use crate::{
     tokens::InstructionMethods,
-    utils::transforms::capitalize,
+    utils::transforms::{
+        SMART_CAPITALIZE_BOUNDARIES,
+        capitalize_smart_with_locale,
+        capitalize_with_locale,
+    },
     utils::validations::check_chunk_bound_indexes,
 };
 
@@ -24,6 +28,8 @@ use crate::{
 /// If `start_index` is out of bounds for the number of words in the `input``, an `AtpError` is returned.
 /// If `end_index` is out of bound for the number of words in the input, it's clamped up to the number of words in `input`
 ///
+/// When `smart` is set, letters after apostrophes and hyphens are capitalized too.
+///
 /// # Example
 ///
 /// ```rust
@@ -36,6 +42,7 @@ use crate::{
 pub struct Ctr {
     pub start_index: usize,
     pub end_index: usize,
+    pub smart: bool,
     params: Vec<AtpParamTypes>,
 }
 
@@ -45,19 +52,38 @@ impl Ctr {
         Ok(Ctr {
             start_index,
             end_index,
+            smart: false,
             params: vec![start_index.into(), end_index.into()],
         })
     }
+
+    /// Opts into `capitalize_smart`, which also capitalizes letters after apostrophes and hyphens.
+    pub fn with_smart(mut self, smart: bool) -> Self {
+        self.params.truncate(2);
+        if smart {
+            self.params.push(smart.into());
+        }
+        self.smart = smart;
+        self
+    }
 }
 
 impl InstructionMethods for Ctr {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "ctr"
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
         if input.trim().is_empty() {
             return Ok("".to_string());
         }
@@ -76,7 +102,13 @@ impl InstructionMethods for Ctr {
             .split_whitespace()
             .enumerate()
             .map(|(i, c)| {
-                if (self.start_index..=end).contains(&i) { capitalize(c) } else { c.to_string() }
+                if !(self.start_index..=end).contains(&i) {
+                    c.to_string()
+                } else if self.smart {
+                    capitalize_smart_with_locale(c, SMART_CAPITALIZE_BOUNDARIES, locale)
+                } else {
+                    capitalize_with_locale(c, locale)
+                }
             })
             .collect::<Vec<_>>()
             .join(" ");
@@ -85,15 +117,23 @@ impl InstructionMethods for Ctr {
     }
 
     fn to_atp_line(&self) -> Cow<'static, str> {
-        format!("ctr {} {};\n", self.start_index, self.end_index).into()
+        if self.smart {
+            format!("ctr {} {} true;\n", self.start_index, self.end_index).into()
+        } else {
+            format!("ctr {} {};\n", self.start_index, self.end_index).into()
+        }
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
         use crate::parse_args;
 
-        check_vec_len(&params, 2, "ctr", "")?;
+        check_vec_len(&params, if params.len() == 3 { 3 } else { 2 }, "ctr", "")?;
 
         self.start_index = parse_args!(params, 0, Usize, "Index should be of usize type");
         self.end_index = parse_args!(params, 1, Usize, "Index should be of usize type");
+        self.smart = match params.len() {
+            3 => parse_args!(params, 2, Bool, "Smart flag should be of bool type"),
+            _ => false,
+        };
 
         return Ok(());
     }
@@ -104,10 +144,18 @@ impl InstructionMethods for Ctr {
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
-        let result: Vec<u8> = to_bytecode!(self.get_opcode(), [
-            AtpParamTypes::Usize(self.start_index),
-            AtpParamTypes::Usize(self.end_index),
-        ]);
+        let result: Vec<u8> = if self.smart {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.start_index),
+                AtpParamTypes::Usize(self.end_index),
+                AtpParamTypes::Bool(true),
+            ])
+        } else {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.start_index),
+                AtpParamTypes::Usize(self.end_index),
+            ])
+        };
         result
     }
 }
@@ -110,6 +110,7 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
@@ -174,4 +175,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn transform_smart_capitalizes_apostrophes_and_hyphens() {
+        let t = Ctr::new(0, 1).unwrap().with_smart(true);
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("o'brien jean-paul x", &mut ctx).unwrap(), "O'Brien Jean-Paul x");
+        assert_eq!(t.to_atp_line().as_ref(), "ctr 0 1 true;\n");
+    }
+
+    #[test]
+    fn text_line_with_smart_flag_parses() {
+        use crate::text::reader::read_from_text;
+
+        let token = read_from_text("ctr 0 1 true;").unwrap();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(token.apply_token("o'brien jean-paul", &mut ctx).unwrap(), "O'Brien Jean-Paul");
+    }
 }
@@ -6,7 +6,14 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ transforms::capitalize, validations::{ check_index_against_input, check_vec_len } },
+    utils::{
+        transforms::{
+            SMART_CAPITALIZE_BOUNDARIES,
+            capitalize_smart_with_locale,
+            capitalize_with_locale,
+        },
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
 };
 
 use crate::utils::errors::{ AtpError };
@@ -22,6 +29,8 @@ use crate::utils::params::AtpParamTypes;
 ///
 /// If `i` is out of bounds for the number of words in the input, an `AtpError` is returned.
 ///
+/// When `smart` is set, letters after apostrophes and hyphens in the word are capitalized too.
+///
 /// # Example
 ///
 /// ```rust
@@ -33,12 +42,23 @@ use crate::utils::params::AtpParamTypes;
 #[derive(Clone, Default)]
 pub struct Cts {
     pub index: usize,
+    pub smart: bool,
     params: Vec<AtpParamTypes>,
 }
 
 impl Cts {
     pub fn new(index: usize) -> Self {
-        Cts { index, params: vec![index.into()] }
+        Cts { index, smart: false, params: vec![index.into()] }
+    }
+
+    /// Opts into `capitalize_smart`, which also capitalizes letters after apostrophes and hyphens.
+    pub fn with_smart(mut self, smart: bool) -> Self {
+        self.params.truncate(1);
+        if smart {
+            self.params.push(smart.into());
+        }
+        self.smart = smart;
+        self
     }
 }
 
@@ -46,19 +66,35 @@ impl InstructionMethods for Cts {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "cts"
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        check_index_against_input(self.index, input)?;
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Word, context)? else {
+            return Ok(input.to_string());
+        };
         let v = input.split_whitespace().collect::<Vec<_>>();
 
         Ok(
             v
                 .iter()
                 .enumerate()
-                .map(|(index, word)| {
-                    if index == self.index { capitalize(word) } else { word.to_string() }
+                .map(|(i, word)| {
+                    if i != index {
+                        word.to_string()
+                    } else if self.smart {
+                        capitalize_smart_with_locale(word, SMART_CAPITALIZE_BOUNDARIES, locale)
+                    } else {
+                        capitalize_with_locale(word, locale)
+                    }
                 })
                 .collect::<Vec<_>>()
                 .join(" ")
@@ -66,14 +102,22 @@ impl InstructionMethods for Cts {
     }
 
     fn to_atp_line(&self) -> Cow<'static, str> {
-        format!("cts {};\n", self.index).into()
+        if self.smart {
+            format!("cts {} true;\n", self.index).into()
+        } else {
+            format!("cts {};\n", self.index).into()
+        }
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
         use crate::parse_args;
 
-        check_vec_len(&params, 1, "cts", "")?;
+        check_vec_len(&params, if params.len() == 2 { 2 } else { 1 }, "cts", "")?;
 
         self.index = parse_args!(params, 0, Usize, "Index should be of usize type");
+        self.smart = match params.len() {
+            2 => parse_args!(params, 1, Bool, "Smart flag should be of bool type"),
+            _ => false,
+        };
 
         return Ok(());
     }
@@ -84,7 +128,14 @@ impl InstructionMethods for Cts {
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
-        let result: Vec<u8> = to_bytecode!(self.get_opcode(), [AtpParamTypes::Usize(self.index)]);
+        let result: Vec<u8> = if self.smart {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.index),
+                AtpParamTypes::Bool(true),
+            ])
+        } else {
+            to_bytecode!(self.get_opcode(), [AtpParamTypes::Usize(self.index)])
+        };
         result
     }
 }
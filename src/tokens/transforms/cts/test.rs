@@ -68,10 +68,25 @@ mod tests {
         assert!(got.is_err());
     }
 
+    #[test]
+    fn transform_index_counts_words_not_chars() {
+        let t = Cts::new(3);
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert!(t.transform("one two", &mut ctx).is_err());
+
+        ctx.set_index_policy(Some(crate::utils::validations::IndexPolicy::Clamp));
+        assert_eq!(t.transform("one two", &mut ctx), Ok("one Two".to_string()));
+    }
+
     #[test]
     fn from_params_rejects_wrong_param_count() {
         let mut t = Cts::default();
-        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(2)];
+        let params = vec![
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Bool(true),
+            AtpParamTypes::Usize(2)
+        ];
 
         let err = t.from_params(&params).unwrap_err();
 
@@ -105,6 +120,7 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
@@ -155,4 +171,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn transform_smart_capitalizes_after_hyphen() {
+        let t = Cts::new(1).with_smart(true);
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("hello jean-paul", &mut ctx).unwrap(), "hello Jean-Paul");
+        assert_eq!(t.to_atp_line().as_ref(), "cts 1 true;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_smart_flag() {
+        let mut t = Cts::default();
+        let params = vec![AtpParamTypes::Usize(0), AtpParamTypes::Bool(true)];
+
+        assert_eq!(t.from_params(&params), Ok(()));
+        assert!(t.smart);
+    }
 }
@@ -0,0 +1,105 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// DDPW - Dedupe Adjacent Words
+///
+/// Collapses runs of consecutive identical words of `input` into a single word.
+///
+/// Words are split with `split_whitespace` and joined back with a single space.
+/// If `case_insensitive` is true, `The the` counts as a repeated word and the first spelling is kept.
+/// Repeated words that are not next to each other are kept.
+///
+/// See Also:
+///
+/// - [`RMWS` - Remove Whitespace](crate::tokens::transforms::rmws)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::ddpw::Ddpw};
+///
+/// let token = Ddpw::new(false);
+///
+/// assert_eq!(token.transform("the the cat cat"), Ok("the cat".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Ddpw {
+    pub case_insensitive: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Ddpw {
+    pub fn new(case_insensitive: bool) -> Self {
+        Ddpw {
+            case_insensitive,
+            params: vec![case_insensitive.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Ddpw {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.case_insensitive.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("ddpw {};\n", self.case_insensitive).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut result: Vec<&str> = Vec::new();
+
+        for word in input.split_whitespace() {
+            let repeated = match result.last() {
+                Some(previous) if self.case_insensitive => previous.to_lowercase() == word.to_lowercase(),
+                Some(previous) => *previous == word,
+                None => false,
+            };
+
+            if !repeated {
+                result.push(word);
+            }
+        }
+
+        Ok(result.join(" "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "ddpw"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "ddpw", "")?;
+
+        self.case_insensitive = parse_args!(params, 0, Bool, "Case insensitive flag should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4e
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Bool(self.case_insensitive),
+        ])
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::ddpw::Ddpw };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_ddpw() {
+        let t = Ddpw::default();
+        assert_eq!(t.get_string_repr(), "ddpw");
+    }
+
+    #[test]
+    fn transform_case_sensitive_collapses_exact_repeats() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ddpw::new(false);
+        assert_eq!(t.transform("the the cat cat", &mut ctx), Ok("the cat".to_string()));
+        assert_eq!(t.transform("The the cat", &mut ctx), Ok("The the cat".to_string()));
+    }
+
+    #[test]
+    fn transform_case_insensitive_keeps_first_spelling() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ddpw::new(true);
+        assert_eq!(t.transform("The the THE cat Cat", &mut ctx), Ok("The cat".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_non_adjacent_duplicates() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ddpw::new(true);
+        assert_eq!(t.transform("a b a b", &mut ctx), Ok("a b a b".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ddpw::new(false);
+        assert_eq!(t.transform("   ", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Ddpw::new(true);
+        assert_eq!(t.to_atp_line().as_ref(), "ddpw true;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_non_bool_flag() {
+        let mut t = Ddpw::default();
+        let err = t.from_params(&vec![AtpParamTypes::Usize(1)]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Ddpw::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x4e() {
+            let t = Ddpw::default();
+            assert_eq!(t.get_opcode(), 0x4e);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Ddpw::new(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x4e);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
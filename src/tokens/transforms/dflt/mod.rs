@@ -0,0 +1,100 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// DFLT - Default If Empty
+///
+/// Replaces `input` with `value` when it is empty, otherwise `input` is returned unchanged.
+///
+/// If `trim` is true, inputs made only of whitespace are considered empty as well.
+///
+/// See Also:
+///
+/// - [`RPT` - Repeat](crate::tokens::transforms::rpt)
+/// - [`TBS` - Trim both sides](crate::tokens::transforms::tbs)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::dflt::Dflt};
+///
+/// let token = Dflt::new("N/A", true);
+///
+/// assert_eq!(token.transform("   "), Ok("N/A".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Dflt {
+    pub value: String,
+    pub trim: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Dflt {
+    pub fn new(value: &str, trim: bool) -> Self {
+        Dflt {
+            value: value.to_string(),
+            trim,
+            params: vec![value.to_string().into(), trim.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Dflt {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.value.to_string(), self.trim.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("dflt {} {};\n", self.value, self.trim).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let is_empty = if self.trim { input.trim().is_empty() } else { input.is_empty() };
+
+        if is_empty {
+            return Ok(self.value.clone());
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "dflt"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "dflt", "")?;
+
+        self.value = parse_args!(params, 0, String, "Value should be of string type");
+        self.trim = parse_args!(params, 1, Bool, "Trim should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x48
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.value.clone()),
+            AtpParamTypes::Bool(self.trim),
+        ])
+    }
+}
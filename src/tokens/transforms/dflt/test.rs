@@ -0,0 +1,124 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::dflt::Dflt };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_dflt() {
+        let t = Dflt::default();
+        assert_eq!(t.get_string_repr(), "dflt");
+    }
+
+    #[test]
+    fn transform_empty_input_uses_value() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Dflt::new("N/A", false);
+        assert_eq!(t.transform("", &mut ctx), Ok("N/A".to_string()));
+    }
+
+    #[test]
+    fn transform_whitespace_only_with_trim_uses_value() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Dflt::new("N/A", true);
+        assert_eq!(t.transform(" \t\n ", &mut ctx), Ok("N/A".to_string()));
+    }
+
+    #[test]
+    fn transform_whitespace_only_without_trim_passes_through() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Dflt::new("N/A", false);
+        assert_eq!(t.transform(" \t\n ", &mut ctx), Ok(" \t\n ".to_string()));
+    }
+
+    #[test]
+    fn transform_non_empty_input_passes_through() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Dflt::new("N/A", true);
+        assert_eq!(t.transform(" banana ", &mut ctx), Ok(" banana ".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Dflt::new("N/A", true);
+        assert_eq!(t.to_atp_line().as_ref(), "dflt N/A true;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_string_and_bool() {
+        let mut t = Dflt::default();
+        t.from_params(&vec![AtpParamTypes::String("-".to_string()), AtpParamTypes::Bool(true)]).unwrap();
+        assert_eq!(t.value, "-");
+        assert!(t.trim);
+    }
+
+    #[test]
+    fn from_params_rejects_non_bool_trim() {
+        let mut t = Dflt::default();
+        let err = t
+            .from_params(&vec![AtpParamTypes::String("-".to_string()), AtpParamTypes::Usize(1)])
+            .unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn text_line_round_trips_through_reader() {
+        use crate::text::reader::read_from_chunks;
+
+        let chunks = vec!["dflt".to_string(), "N/A".to_string(), "true".to_string()];
+        let token = read_from_chunks(&chunks).unwrap();
+
+        assert_eq!(token.to_text_line_unresolved().unwrap(), "dflt N/A true;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Dflt::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x48() {
+            let t = Dflt::default();
+            assert_eq!(t.get_opcode(), 0x48);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Dflt::new("N/A", true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x48);
+            assert_eq!(bc[12] as usize, 2);
+        }
+
+        #[test]
+        fn to_bytecode_encodes_trim_as_single_byte_bool() {
+            let t = Dflt::new("N/A", true);
+            let bc = t.to_bytecode();
+
+            // the last param is [u64 total][u32 type][u32 size][u8 payload]
+            let last = &bc[bc.len() - 17..];
+
+            assert_eq!(u64::from_be_bytes(last[0..8].try_into().unwrap()), 17);
+            assert_eq!(u32::from_be_bytes(last[8..12].try_into().unwrap()), 0x05);
+            assert_eq!(u32::from_be_bytes(last[12..16].try_into().unwrap()), 1);
+            assert_eq!(last[16], 1);
+        }
+    }
+}
@@ -5,15 +5,18 @@ use std::borrow::Cow;
 
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::params::AtpParamTypes;
-use crate::utils::validations::{ check_index_against_input, check_vec_len };
+use crate::utils::validations::{ IndexUnit, check_vec_len, resolve_token_index };
 use crate::{ tokens::InstructionMethods };
 
-use crate::utils::errors::{ AtpError, AtpErrorCode };
+use crate::utils::errors::AtpError;
 
 /// Dla - Delete After
 /// Delete all characters after `index` in the specified `input`
 ///
-/// It will throw an `AtpError` if index does not exists in the current `input`
+/// The char at `index` is kept, so the result is the chars `0..=index` of `input`.
+/// When `index` is the last char, `input` is returned unchanged.
+///
+/// It will throw an `AtpError` if index does not exists in the current `input` (including an empty `input`)
 ///
 /// # Example:
 ///
@@ -41,32 +44,28 @@ impl InstructionMethods for Dla {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("dla {};\n", self.index).into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        check_index_against_input(self.index, input)?;
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Char, context)? else {
+            return Ok(input.to_string());
+        };
 
-        let mut s = String::from(input);
-        if
-            let Some(byte_index) = s
-                .char_indices()
-                .nth(self.index + 1)
-                .map(|(i, _)| i)
-        {
-            s.drain(byte_index..);
-            return Ok(s);
+        // `index` is already known to exist, so when there is no char after it
+        // there is nothing to delete and the whole input is kept.
+        match input.char_indices().nth(index + 1) {
+            Some((byte_index, _)) => Ok(input[..byte_index].to_string()),
+            None => Ok(input.to_string()),
         }
-        Err(
-            AtpError::new(
-                AtpErrorCode::IndexOutOfRange(
-                    "Index is out of range for the desired string".into()
-                ),
-                self.to_atp_line(),
-                input.to_string()
-            )
-        )
     }
 
     fn get_string_repr(&self) -> &'static str {
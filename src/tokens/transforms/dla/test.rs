@@ -5,7 +5,7 @@ mod tests {
     use crate::context::execution_context::GlobalExecutionContext;
     use crate::tokens::InstructionMethods;
     use crate::tokens::transforms::dla::Dla;
-    use crate::utils::errors::{ AtpError, AtpErrorCode };
+    use crate::utils::errors::AtpErrorCode;
     use crate::utils::params::AtpParamTypes;
 
     #[test]
@@ -64,26 +64,31 @@ mod tests {
     }
 
     #[test]
-    fn transform_returns_index_out_of_range_when_index_is_last_char() {
-        // comportamento atual do código:
-        // se index aponta pro último char, nth(index+1) vira None -> cai no Err(IndexOutOfRange)
-        let input = "abc";
+    fn transform_keeps_whole_string_when_index_is_last_char() {
         let t = Dla::new(2); // último char
         let mut ctx = GlobalExecutionContext::new();
 
-        let got = t.transform(input, &mut ctx);
+        assert_eq!(t.transform("abc", &mut ctx), Ok("abc".to_string()));
+    }
 
-        let expected = Err(
-            AtpError::new(
-                AtpErrorCode::IndexOutOfRange(
-                    "Index is out of range for the desired string".into()
-                ),
-                t.to_atp_line(),
-                input.to_string()
-            )
-        );
+    #[test]
+    fn transform_errors_when_index_is_one_past_the_end() {
+        let t = Dla::new(3);
+        let mut ctx = GlobalExecutionContext::new();
 
-        assert_eq!(got, expected);
+        let err = t.transform("abc", &mut ctx).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn transform_errors_on_empty_input() {
+        let t = Dla::new(0);
+        let mut ctx = GlobalExecutionContext::new();
+
+        let err = t.transform("", &mut ctx).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
     }
 
     #[test]
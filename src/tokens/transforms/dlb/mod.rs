@@ -7,7 +7,7 @@ use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::errors::{ AtpError, AtpErrorCode };
 
 use crate::utils::params::AtpParamTypes;
-use crate::utils::validations::{ check_index_against_input, check_vec_len };
+use crate::utils::validations::{ IndexUnit, check_vec_len, resolve_token_index };
 use crate::{ tokens::InstructionMethods };
 
 /// Dlb - Delete Before
@@ -41,19 +41,28 @@ impl InstructionMethods for Dlb {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("dlb {};\n", self.index).into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
         let mut s = String::from(input);
 
-        check_index_against_input(self.index, input)?;
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Char, context)? else {
+            return Ok(input.to_string());
+        };
 
         if
             let Some(byte_index) = s
                 .char_indices()
-                .nth(self.index)
+                .nth(index)
                 .map(|(i, _)| i)
         {
             s.drain(0..byte_index);
@@ -66,7 +75,7 @@ impl InstructionMethods for Dlb {
                     format!(
                         "Supported indexes 0-{}, entered index {}",
                         input.chars().count().saturating_sub(1),
-                        self.index
+                        index
                     ).into()
                 ),
                 self.to_atp_line(),
@@ -46,6 +46,9 @@ impl InstructionMethods for Dlc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("dlc {} {};\n", self.start_index, self.end_index).into()
     }
@@ -102,6 +102,12 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+    #[test]
+    fn get_args_returns_indices_as_strings() {
+        let t = Dlc::new(1, 3).unwrap();
+        assert_eq!(t.get_args(), vec!["1".to_string(), "3".to_string()]);
+    }
+
     // ============================
     // Bytecode-only tests
     // ============================
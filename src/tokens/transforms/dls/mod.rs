@@ -6,7 +6,10 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, validations::{ check_index_against_input, check_vec_len } },
+    utils::{
+        errors::AtpError,
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -42,6 +45,9 @@ impl InstructionMethods for Dls {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "dls"
     }
@@ -49,14 +55,20 @@ impl InstructionMethods for Dls {
         format!("dls {};\n", self.index).into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        check_index_against_input(self.index, input)?;
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Char, context)? else {
+            return Ok(input.to_string());
+        };
         Ok(
             input
                 .chars()
                 .enumerate()
                 .filter_map(|(i, c)| {
-                    if self.index == i {
+                    if index == i {
                         return None;
                     } else {
                         return Some(c);
@@ -0,0 +1,94 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// ENSUREPFX - Ensure Prefix
+///
+/// Prepends `prefix` to `input`, unless `input` already starts with it, so applying it twice is the same as applying it once.
+///
+/// See Also:
+///
+/// - [`ATB` - Add to Beginning](crate::tokens::transforms::atb)
+/// - [`ENSURESFX` - Ensure Suffix](crate::tokens::transforms::ensuresfx)
+/// - [`STPPFX` - Strip Prefix](crate::tokens::transforms::stppfx)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::ensurepfx::Ensurepfx};
+///
+/// let token = Ensurepfx::new("banana ");
+///
+/// assert_eq!(token.transform("laranja"), Ok("banana laranja".to_string()));
+/// assert_eq!(token.transform("banana laranja"), Ok("banana laranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Ensurepfx {
+    pub prefix: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Ensurepfx {
+    pub fn new(prefix: &str) -> Self {
+        Ensurepfx {
+            prefix: prefix.to_string(),
+            params: vec![prefix.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Ensurepfx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.prefix.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("ensurepfx {};\n", self.prefix).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if input.starts_with(self.prefix.as_str()) {
+            return Ok(input.to_string());
+        }
+
+        Ok(format!("{}{}", self.prefix, input))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "ensurepfx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "ensurepfx", "")?;
+
+        self.prefix = parse_args!(params, 0, String, "Prefix should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x60
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.prefix.clone()),
+        ])
+    }
+}
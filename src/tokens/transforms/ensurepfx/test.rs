@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::ensurepfx::Ensurepfx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_ensurepfx() {
+        let t = Ensurepfx::default();
+        assert_eq!(t.get_string_repr(), "ensurepfx");
+    }
+
+    #[test]
+    fn transform_adds_missing_prefix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensurepfx::new(">> ");
+        assert_eq!(t.transform("banana", &mut ctx), Ok(">> banana".to_string()));
+    }
+
+    #[test]
+    fn transform_present_prefix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensurepfx::new(">> ");
+        assert_eq!(t.transform(">> banana", &mut ctx), Ok(">> banana".to_string()));
+    }
+
+    #[test]
+    fn transform_is_idempotent() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensurepfx::new("/");
+        let once = t.transform("banana", &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()));
+    }
+
+    #[test]
+    fn transform_partial_prefix_is_still_added() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensurepfx::new("ab");
+        assert_eq!(t.transform("ba", &mut ctx), Ok("abba".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_gets_prefix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensurepfx::new("x");
+        assert_eq!(t.transform("", &mut ctx), Ok("x".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Ensurepfx::new("/").to_atp_line().as_ref(), "ensurepfx /;\n");
+    }
+
+    #[test]
+    fn from_params_reads_prefix() {
+        let mut t = Ensurepfx::default();
+        t.from_params(&vec![AtpParamTypes::String("#".to_string())]).unwrap();
+        assert_eq!(t.prefix, "#");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Ensurepfx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x60() {
+            let t = Ensurepfx::default();
+            assert_eq!(t.get_opcode(), 0x60);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Ensurepfx::new("/");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x60);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
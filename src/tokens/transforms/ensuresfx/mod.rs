@@ -0,0 +1,94 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// ENSURESFX - Ensure Suffix
+///
+/// Appends `suffix` to `input`, unless `input` already ends with it, so applying it twice is the same as applying it once.
+///
+/// See Also:
+///
+/// - [`ATE` - Add to End](crate::tokens::transforms::ate)
+/// - [`ENSUREPFX` - Ensure Prefix](crate::tokens::transforms::ensurepfx)
+/// - [`STPSFX` - Strip Suffix](crate::tokens::transforms::stpsfx)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::ensuresfx::Ensuresfx};
+///
+/// let token = Ensuresfx::new(" laranja");
+///
+/// assert_eq!(token.transform("banana"), Ok("banana laranja".to_string()));
+/// assert_eq!(token.transform("banana laranja"), Ok("banana laranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Ensuresfx {
+    pub suffix: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Ensuresfx {
+    pub fn new(suffix: &str) -> Self {
+        Ensuresfx {
+            suffix: suffix.to_string(),
+            params: vec![suffix.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Ensuresfx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.suffix.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("ensuresfx {};\n", self.suffix).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if input.ends_with(self.suffix.as_str()) {
+            return Ok(input.to_string());
+        }
+
+        Ok(format!("{}{}", input, self.suffix))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "ensuresfx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "ensuresfx", "")?;
+
+        self.suffix = parse_args!(params, 0, String, "Suffix should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x61
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.suffix.clone()),
+        ])
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::ensuresfx::Ensuresfx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_ensuresfx() {
+        let t = Ensuresfx::default();
+        assert_eq!(t.get_string_repr(), "ensuresfx");
+    }
+
+    #[test]
+    fn transform_adds_missing_suffix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensuresfx::new(" <<");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana <<".to_string()));
+    }
+
+    #[test]
+    fn transform_present_suffix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensuresfx::new(" <<");
+        assert_eq!(t.transform("banana <<", &mut ctx), Ok("banana <<".to_string()));
+    }
+
+    #[test]
+    fn transform_is_idempotent() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensuresfx::new("/");
+        let once = t.transform("banana", &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()));
+    }
+
+    #[test]
+    fn transform_partial_suffix_is_still_added() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensuresfx::new("ab");
+        assert_eq!(t.transform("ba", &mut ctx), Ok("baab".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_gets_suffix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ensuresfx::new("x");
+        assert_eq!(t.transform("", &mut ctx), Ok("x".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Ensuresfx::new("/").to_atp_line().as_ref(), "ensuresfx /;\n");
+    }
+
+    #[test]
+    fn from_params_reads_suffix() {
+        let mut t = Ensuresfx::default();
+        t.from_params(&vec![AtpParamTypes::String("#".to_string())]).unwrap();
+        assert_eq!(t.suffix, "#");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Ensuresfx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x61() {
+            let t = Ensuresfx::default();
+            assert_eq!(t.get_opcode(), 0x61);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Ensuresfx::new("/");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x61);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
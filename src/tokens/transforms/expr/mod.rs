@@ -0,0 +1,112 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// EXPR - Expand Ranges
+///
+/// Replaces every `X-Y` in `input`, where `X` and `Y` are both ASCII lowercase letters, both ASCII uppercase
+/// letters or both digits, with the inclusive sequence from `X` to `Y` (`a-e` -> `abcde`, `1-5` -> `12345`).
+///
+/// Reverse ranges are expanded in descending order (`e-a` -> `edcba`). Any other hyphen is left as it is.
+///
+/// A range must stand on its own: if the char right before `X` or right after `Y` is alphanumeric
+/// or another hyphen, it is part of a word (`re-use`, `well-known`, `pre-a-c`) and is left as is.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::expr::Expr};
+///
+/// let token = Expr::default();
+///
+/// assert_eq!(token.transform("[a-e] [1-5]"), Ok("[abcde] [12345]".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Expr {
+    params: Vec<AtpParamTypes>,
+}
+
+/// Whether `start-end` is a range `Expr` expands, both ends must be of the same kind.
+fn is_range(start: char, end: char) -> bool {
+    (start.is_ascii_lowercase() && end.is_ascii_lowercase()) ||
+        (start.is_ascii_uppercase() && end.is_ascii_uppercase()) ||
+        (start.is_ascii_digit() && end.is_ascii_digit())
+}
+
+/// Whether a range bounded by `c` would be part of a longer word.
+fn touches_word(c: Option<&char>) -> bool {
+    matches!(c, Some(c) if c.is_alphanumeric() || *c == '-')
+}
+
+impl InstructionMethods for Expr {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "expr;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let chars = input.chars().collect::<Vec<_>>();
+        let mut result = String::with_capacity(input.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            let before = i.checked_sub(1).and_then(|b| chars.get(b));
+
+            if
+                let (Some(&start), Some('-'), Some(&end)) = (
+                    chars.get(i),
+                    chars.get(i + 1),
+                    chars.get(i + 2),
+                ) &&
+                is_range(start, end) &&
+                !touches_word(before) &&
+                !touches_word(chars.get(i + 3))
+            {
+                if start <= end {
+                    result.extend(start..=end);
+                } else {
+                    result.extend((end..=start).rev());
+                }
+                i += 3;
+                continue;
+            }
+
+            result.push(chars[i]);
+            i += 1;
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "expr"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "expr", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x70
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::expr::Expr };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_expr() {
+        let t = Expr::default();
+        assert_eq!(t.get_string_repr(), "expr");
+    }
+
+    #[test]
+    fn transform_expands_letter_ranges() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        assert_eq!(t.transform("a-e", &mut ctx), Ok("abcde".to_string()));
+        assert_eq!(t.transform("A-D X-Z", &mut ctx), Ok("ABCD XYZ".to_string()));
+    }
+
+    #[test]
+    fn transform_expands_digit_ranges() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        assert_eq!(t.transform("ids 1-5 e 7-7", &mut ctx), Ok("ids 12345 e 7".to_string()));
+    }
+
+    #[test]
+    fn transform_reverse_ranges_are_descending() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        assert_eq!(t.transform("e-a 9-6", &mut ctx), Ok("edcba 9876".to_string()));
+    }
+
+    #[test]
+    fn transform_leaves_non_range_hyphens_intact() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        assert_eq!(t.transform("a-Z 1-b - -a x- ab-", &mut ctx), Ok("a-Z 1-b - -a x- ab-".to_string()));
+        assert_eq!(t.transform("ã-é", &mut ctx), Ok("ã-é".to_string()));
+    }
+
+    #[test]
+    fn transform_leaves_hyphen_chains_intact() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        // "a-c-e" is ambiguous, so no range of the chain is expanded
+        assert_eq!(t.transform("a-c-e", &mut ctx), Ok("a-c-e".to_string()));
+    }
+
+    #[test]
+    fn transform_leaves_hyphenated_words_intact() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Expr::default();
+        assert_eq!(t.transform("re-use", &mut ctx), Ok("re-use".to_string()));
+        assert_eq!(t.transform("well-known", &mut ctx), Ok("well-known".to_string()));
+        assert_eq!(t.transform("pre-a-c", &mut ctx), Ok("pre-a-c".to_string()));
+        assert_eq!(t.transform("x-ray 2-4", &mut ctx), Ok("x-ray 234".to_string()));
+        assert_eq!(t.transform("(a-c), [1-3]", &mut ctx), Ok("(abc), [123]".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Expr::default().to_atp_line().as_ref(), "expr;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Expr::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x70() {
+            let t = Expr::default();
+            assert_eq!(t.get_opcode(), 0x70);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Expr::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x70);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
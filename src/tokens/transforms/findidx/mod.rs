@@ -0,0 +1,112 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use regex::Regex;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// FINDIDX - Find Index
+///
+/// Replaces `input` with the character index of the first match of `pattern`,
+/// or with `-1` if `pattern` is not found.
+///
+/// The index counts characters, not bytes, so it can be used directly with the index based tokens.
+///
+/// See Also:
+///
+/// - [`RFW` - Replace First With](crate::tokens::transforms::rfw)
+/// - [`SSLT` - Split Select](crate::tokens::transforms::sslt)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::findidx::Findidx};
+///
+/// let token = Findidx::new("ã").unwrap();
+///
+/// assert_eq!(token.transform("maçã"), Ok("3".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Findidx {
+    pub pattern: Regex,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Findidx {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let pattern = Regex::new(pattern).map_err(|x| x.to_string())?;
+        Ok(Findidx {
+            params: vec![pattern.to_string().into()],
+            pattern,
+        })
+    }
+}
+
+impl Default for Findidx {
+    fn default() -> Self {
+        Findidx {
+            pattern: Regex::new("").unwrap(),
+            params: vec!["".to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Findidx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("findidx {};\n", self.pattern).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        match self.pattern.find(input) {
+            Some(m) => Ok(input[..m.start()].chars().count().to_string()),
+            None => Ok("-1".to_string()),
+        }
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "findidx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "findidx", "")?;
+
+        let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
+
+        self.pattern = Regex::new(&pattern_payload).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to create regex".into()),
+                "findidx",
+                pattern_payload.clone()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x44
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [AtpParamTypes::String(self.pattern.to_string())])
+    }
+}
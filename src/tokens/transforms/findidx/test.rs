@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::findidx::Findidx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_findidx() {
+        let t = Findidx::default();
+        assert_eq!(t.get_string_repr(), "findidx");
+    }
+
+    #[test]
+    fn new_rejects_invalid_regex() {
+        let err = Findidx::new("(").unwrap_err();
+        assert!(!err.is_empty());
+    }
+
+    #[test]
+    fn transform_returns_index_of_first_match() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Findidx::new("an").unwrap();
+        assert_eq!(t.transform("banana", &mut ctx), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn transform_uses_char_index_not_byte_index() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Findidx::new("ã").unwrap();
+        // "maç" is 4 bytes long but only 3 characters
+        assert_eq!(t.transform("maçã", &mut ctx), Ok("3".to_string()));
+    }
+
+    #[test]
+    fn transform_not_found_returns_minus_one() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Findidx::new("x+").unwrap();
+        assert_eq!(t.transform("banana", &mut ctx), Ok("-1".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Findidx::new("a+").unwrap();
+        assert_eq!(t.to_atp_line().as_ref(), "findidx a+;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_invalid_regex() {
+        let mut t = Findidx::default();
+        let err = t.from_params(&vec![AtpParamTypes::String("(".to_string())]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Findidx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x44() {
+            let t = Findidx::default();
+            assert_eq!(t.get_opcode(), 0x44);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Findidx::new("a+").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x44);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
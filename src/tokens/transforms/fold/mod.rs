@@ -0,0 +1,112 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// FOLD - Case Fold
+///
+/// Applies Unicode full case folding to `input`, for case-insensitive comparisons. Besides lowercasing it
+/// expands characters that have no single-character lower case, e.g. "ß" becomes "ss" and "ﬁ" becomes
+/// "fi", and maps variant forms like final sigma ("ς") to their regular letter.
+///
+/// The result is meant for comparing or indexing text, not for display, and folding it again does not
+/// change it.
+///
+/// See Also:
+///
+/// - [`TLA` - To Lowercase All](crate::tokens::transforms::tla)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::fold::Fold};
+///
+/// let token = Fold::default();
+///
+/// assert_eq!(token.transform("Straße"), Ok("strasse".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Fold {
+    params: Vec<AtpParamTypes>,
+}
+
+/// Case folding rules that differ from `char::to_lowercase`.
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'ß' | 'ẞ' => "ss",
+        'ſ' => "s",
+        'ŉ' => "ʼn",
+        'İ' => "i\u{307}",
+        'ς' => "σ",
+        'ϐ' => "β",
+        'ϑ' => "θ",
+        'ϕ' => "φ",
+        'ϖ' => "π",
+        'ϰ' => "κ",
+        'ϱ' => "ρ",
+        'ϵ' => "ε",
+        'ẛ' => "ṡ",
+        'ﬀ' => "ff",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        'ﬃ' => "ffi",
+        'ﬄ' => "ffl",
+        'ﬅ' | 'ﬆ' => "st",
+        _ => {
+            return None;
+        }
+    };
+
+    Some(folded)
+}
+
+impl InstructionMethods for Fold {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "fold;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut result = String::with_capacity(input.len());
+
+        for c in input.chars() {
+            match fold_char(c) {
+                Some(folded) => result.push_str(folded),
+                None => result.extend(c.to_lowercase()),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "fold"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "fold", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x78
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
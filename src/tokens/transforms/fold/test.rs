@@ -0,0 +1,78 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::fold::Fold };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_fold() {
+        let t = Fold::default();
+        assert_eq!(t.get_string_repr(), "fold");
+    }
+
+    #[test]
+    fn transform_folds_sharp_s() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Fold::default();
+        assert_eq!(t.transform("straße", &mut ctx), Ok("strasse".to_string()));
+        assert_eq!(t.transform("STRAẞE", &mut ctx), Ok("strasse".to_string()));
+    }
+
+    #[test]
+    fn transform_makes_case_variants_equal() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Fold::default();
+
+        assert_eq!(t.transform("STRASSE", &mut ctx), t.transform("Straße", &mut ctx));
+        assert_eq!(t.transform("ΟΔΟΣ", &mut ctx), t.transform("οδος", &mut ctx));
+        assert_eq!(t.transform("ﬁle", &mut ctx), Ok("file".to_string()));
+    }
+
+    #[test]
+    fn transform_is_stable_under_repeated_application() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Fold::default();
+
+        for input in ["Straße", "ΣΊΣΥΦΟΣ ς", "İstanbul", "ﬃ ſ ŉ", "plain ascii"] {
+            let once = t.transform(input, &mut ctx).unwrap();
+            assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Fold::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x78() {
+            let t = Fold::default();
+            assert_eq!(t.get_opcode(), 0x78);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Fold::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x78);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
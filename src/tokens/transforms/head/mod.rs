@@ -0,0 +1,102 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// HEAD - Head
+///
+/// Keeps only the first `n` lines of `input`, like the Unix `head`. Lines are separated by `\n`.
+///
+/// If `input` has less than `n` lines it is returned whole, a trailing newline at the end of `input` is preserved.
+///
+/// See Also:
+///
+/// - [`TAIL` - Tail](crate::tokens::transforms::tail)
+/// - [`LSLICE` - Line Slice](crate::tokens::transforms::lslice)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::head::Head};
+///
+/// let token = Head::new(2);
+///
+/// assert_eq!(token.transform("banana\nlaranja\ncanja"), Ok("banana\nlaranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Head {
+    pub n: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Head {
+    pub fn new(n: usize) -> Self {
+        Head {
+            n,
+            params: vec![n.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Head {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.n.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("head {};\n", self.n).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let body = input.strip_suffix('\n');
+        let lines = body.unwrap_or(input).split('\n').collect::<Vec<_>>();
+
+        let mut result = lines.iter().take(self.n)
+            .copied()
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() && self.n > 0 {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "head"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "head", "")?;
+
+        self.n = parse_args!(params, 0, Usize, "Line count should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x69
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.n),
+        ])
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::head::Head };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_head() {
+        let t = Head::default();
+        assert_eq!(t.get_string_repr(), "head");
+    }
+
+    #[test]
+    fn transform_keeps_first_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Head::new(2);
+        assert_eq!(t.transform("l0\nl1\nl2\nl3", &mut ctx), Ok("l0\nl1".to_string()));
+    }
+
+    #[test]
+    fn transform_preserves_trailing_newline() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Head::new(1);
+        assert_eq!(t.transform("l0\nl1\n", &mut ctx), Ok("l0\n".to_string()));
+    }
+
+    #[test]
+    fn transform_n_larger_than_line_count_returns_all() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Head::new(10);
+        assert_eq!(t.transform("l0\nl1\nl2\n", &mut ctx), Ok("l0\nl1\nl2\n".to_string()));
+        assert_eq!(t.transform("l0", &mut ctx), Ok("l0".to_string()));
+    }
+
+    #[test]
+    fn transform_zero_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Head::new(0);
+        assert_eq!(t.transform("l0\nl1\n", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Head::new(5).to_atp_line().as_ref(), "head 5;\n");
+    }
+
+    #[test]
+    fn from_params_reads_n() {
+        let mut t = Head::default();
+        t.from_params(&vec![AtpParamTypes::Usize(3)]).unwrap();
+        assert_eq!(t.n, 3);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Head::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x69() {
+            let t = Head::default();
+            assert_eq!(t.get_opcode(), 0x69);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Head::new(5);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x69);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
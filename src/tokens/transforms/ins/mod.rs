@@ -43,6 +43,9 @@ impl InstructionMethods for Ins {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string(), self.text_to_insert.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "ins"
     }
@@ -120,6 +120,12 @@ mod tests {
     // ============================
     // Bytecode-only tests (separados)
     // ============================
+    #[test]
+    fn get_args_returns_index_and_text() {
+        let t = Ins::new(2, "xy");
+        assert_eq!(t.get_args(), vec!["2".to_string(), "xy".to_string()]);
+    }
+
     #[cfg(feature = "bytecode")]
     mod bytecode_tests {
         use super::*;
@@ -0,0 +1,101 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// INTERLEAVE - Interleave
+///
+/// Writes each character of `input` followed by the next character of `other`, repeating `other` when it is
+/// shorter than `input`. When `other` is longer, its unused characters are appended at the end.
+///
+/// An empty `other` returns `input` unchanged.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::interleave::Interleave};
+///
+/// let token = Interleave::new("-+");
+///
+/// assert_eq!(token.transform("banana"), Ok("b-a+n-a+n-a+".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Interleave {
+    pub other: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Interleave {
+    pub fn new(other: &str) -> Self {
+        Interleave {
+            other: other.to_string(),
+            params: vec![other.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Interleave {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.other.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("interleave {};\n", self.other).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.other.is_empty() {
+            return Ok(input.to_string());
+        }
+
+        let mut result = String::with_capacity(input.len() * 2);
+        let mut used = 0;
+
+        for (c, o) in input.chars().zip(self.other.chars().cycle()) {
+            result.push(c);
+            result.push(o);
+            used += 1;
+        }
+
+        result.extend(self.other.chars().skip(used));
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "interleave"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "interleave", "")?;
+
+        self.other = parse_args!(params, 0, String, "Other should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x65
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.other.clone()),
+        ])
+    }
+}
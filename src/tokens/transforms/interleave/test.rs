@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::interleave::Interleave };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_interleave() {
+        let t = Interleave::default();
+        assert_eq!(t.get_string_repr(), "interleave");
+    }
+
+    #[test]
+    fn transform_equal_length() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Interleave::new("xyz");
+        assert_eq!(t.transform("abc", &mut ctx), Ok("axbycz".to_string()));
+    }
+
+    #[test]
+    fn transform_longer_input_cycles_other() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Interleave::new("xy");
+        assert_eq!(t.transform("abcde", &mut ctx), Ok("axbycxdyex".to_string()));
+    }
+
+    #[test]
+    fn transform_longer_other_appends_leftovers() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Interleave::new("12345");
+        assert_eq!(t.transform("ab", &mut ctx), Ok("a1b2345".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("12345".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_other_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Interleave::new("");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_multibyte_chars() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Interleave::new("ç");
+        assert_eq!(t.transform("ãé", &mut ctx), Ok("ãçéç".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Interleave::new("-").to_atp_line().as_ref(), "interleave -;\n");
+    }
+
+    #[test]
+    fn from_params_reads_other() {
+        let mut t = Interleave::default();
+        t.from_params(&vec![AtpParamTypes::String("ab".to_string())]).unwrap();
+        assert_eq!(t.other, "ab");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Interleave::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x65() {
+            let t = Interleave::default();
+            assert_eq!(t.get_opcode(), 0x65);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Interleave::new("-");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x65);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, transforms::capitalize, validations::check_vec_len },
+    utils::{ errors::AtpError, transforms::capitalize_with_locale, validations::check_vec_len },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -42,13 +42,18 @@ impl InstructionMethods for Jcmc {
         "jcmc;\n".into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
         let v = input.split_whitespace().collect::<Vec<_>>();
 
         let processed = v
             .iter()
             .enumerate()
-            .map(|(i, w)| if i >= 1 { capitalize(w) } else { w.to_string() })
+            .map(|(i, w)| if i >= 1 { capitalize_with_locale(w, locale) } else { w.to_string() })
             .collect::<Vec<_>>()
             .join("");
 
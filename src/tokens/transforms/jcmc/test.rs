@@ -87,6 +87,7 @@ mod tests {
         assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
     }
 
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
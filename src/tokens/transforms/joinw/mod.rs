@@ -0,0 +1,151 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::utils::transforms::{
+    capitalize_with_locale,
+    to_lowercase_with_locale,
+    to_uppercase_with_locale,
+};
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// JOINW - Join Words
+///
+/// Splits `input` on whitespace, applies `word_case` to every word and joins them with `separator`.
+///
+/// `word_case` can be `none` (words are kept as they are), `lower`, `upper` or `capitalize` (first
+/// letter upper cased). `lower` and `upper` honor the locale set in the processor. Any other
+/// `word_case` returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`JSNC` - Join to Snake Case](crate::tokens::transforms::jsnc)
+/// - [`JKBC` - Join to Kebab Case](crate::tokens::transforms::jkbc)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::joinw::Joinw};
+///
+/// let token = Joinw::new("_", "lower").unwrap();
+///
+/// assert_eq!(token.transform("Banana Laranja  Cheia"), Ok("banana_laranja_cheia".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Joinw {
+    pub separator: String,
+    pub word_case: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Joinw {
+    pub fn new(separator: &str, word_case: &str) -> Result<Self, AtpError> {
+        check_word_case(word_case)?;
+
+        Ok(Joinw {
+            separator: separator.to_string(),
+            word_case: word_case.to_string(),
+            params: vec![separator.to_string().into(), word_case.to_string().into()],
+        })
+    }
+}
+
+fn check_word_case(word_case: &str) -> Result<(), AtpError> {
+    match word_case {
+        "none" | "lower" | "upper" | "capitalize" => Ok(()),
+        _ =>
+            Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters(
+                        "Word case should be one of 'none', 'lower', 'upper' or 'capitalize'".into()
+                    ),
+                    "joinw",
+                    word_case.to_string()
+                )
+            ),
+    }
+}
+
+impl Default for Joinw {
+    fn default() -> Self {
+        Joinw {
+            separator: " ".to_string(),
+            word_case: "none".to_string(),
+            params: vec![" ".to_string().into(), "none".to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Joinw {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.separator.to_string(), self.word_case.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("joinw {} {};\n", self.separator, self.word_case).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
+
+        Ok(
+            input
+                .split_whitespace()
+                .map(|word| {
+                    match self.word_case.as_str() {
+                        "lower" => to_lowercase_with_locale(word, locale),
+                        "upper" => to_uppercase_with_locale(word, locale),
+                        "capitalize" => capitalize_with_locale(word, locale),
+                        _ => word.to_string(),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(&self.separator)
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "joinw"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "joinw", "")?;
+
+        self.separator = parse_args!(params, 0, String, "Separator should be of string type");
+        self.word_case = parse_args!(params, 1, String, "Word case should be of string type");
+
+        check_word_case(&self.word_case)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x76
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.separator.clone()),
+            AtpParamTypes::String(self.word_case.clone()),
+        ])
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::joinw::Joinw };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_joinw() {
+        let t = Joinw::default();
+        assert_eq!(t.get_string_repr(), "joinw");
+    }
+
+    #[test]
+    fn transform_reproduces_snake_case() {
+        use crate::tokens::transforms::jsnc::Jsnc;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "Banana Laranja  cheia de CANJA";
+        let t = Joinw::new("_", "lower").unwrap();
+
+        assert_eq!(t.transform(input, &mut ctx), Ok("banana_laranja_cheia_de_canja".to_string()));
+        assert_eq!(t.transform(input, &mut ctx), Jsnc::default().transform(input, &mut ctx));
+    }
+
+    #[test]
+    fn transform_reproduces_kebab_case() {
+        use crate::tokens::transforms::jkbc::Jkbc;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "Banana Laranja cheia";
+        let t = Joinw::new("-", "lower").unwrap();
+
+        assert_eq!(t.transform(input, &mut ctx), Ok("banana-laranja-cheia".to_string()));
+        assert_eq!(t.transform(input, &mut ctx), Jkbc::default().transform(input, &mut ctx));
+    }
+
+    #[test]
+    fn transform_applies_each_word_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "banana LARANJA cHeia";
+
+        let joined = |separator: &str, case: &str, ctx: &mut GlobalExecutionContext| {
+            Joinw::new(separator, case).unwrap().transform(input, ctx).unwrap()
+        };
+
+        assert_eq!(joined(".", "none", &mut ctx), "banana.LARANJA.cHeia");
+        assert_eq!(joined("::", "upper", &mut ctx), "BANANA::LARANJA::CHEIA");
+        assert_eq!(joined("", "capitalize", &mut ctx), "BananaLARANJACHeia");
+    }
+
+    #[test]
+    fn transform_upper_honors_locale() {
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_locale(Some("tr"));
+
+        let t = Joinw::new("_", "upper").unwrap();
+        assert_eq!(t.transform("istanbul izmir", &mut ctx), Ok("İSTANBUL_İZMİR".to_string()));
+    }
+
+    #[test]
+    fn new_rejects_unknown_word_case() {
+        let err = Joinw::new("_", "snake").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_unknown_word_case() {
+        let mut t = Joinw::default();
+        let params = vec![
+            AtpParamTypes::String("-".to_string()),
+            AtpParamTypes::String("Upper".to_string())
+        ];
+
+        let err = t.from_params(&params).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(
+            Joinw::new("-", "capitalize").unwrap().to_atp_line().as_ref(),
+            "joinw - capitalize;\n"
+        );
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Joinw::default();
+        let params = vec![
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1)
+        ];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x76() {
+            let t = Joinw::default();
+            assert_eq!(t.get_opcode(), 0x76);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Joinw::new("-", "upper").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x76);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, transforms::capitalize, validations::check_vec_len },
+    utils::{ errors::AtpError, transforms::capitalize_with_locale, validations::check_vec_len },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -42,12 +42,17 @@ impl InstructionMethods for Jpsc {
         "jpsc;\n".into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
         let v = input.split_whitespace().collect::<Vec<_>>();
 
         let processed = v
             .iter()
-            .map(|w| capitalize(w))
+            .map(|w| capitalize_with_locale(w, locale))
             .collect::<Vec<_>>()
             .join("");
 
@@ -86,6 +86,7 @@ mod tests {
         assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
     }
 
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
@@ -0,0 +1,84 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// JSONM - Json Minify
+///
+/// Parses `input` as JSON and writes it back without any whitespace, using serde_json::to_string.
+///
+/// Object keys are written in alphabetical order, not in the order of `input`. Invalid JSON returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`JSONP` - Json Pretty](crate::tokens::transforms::jsonp)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::jsonm::Jsonm};
+///
+/// let token = Jsonm::default();
+///
+/// assert_eq!(token.transform("{\n  \"banana\": [1, 2]\n}"), Ok(r#"{"banana":[1,2]}"#.to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Jsonm {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Jsonm {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "jsonm;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let value = serde_json::from_str::<serde_json::Value>(input).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to parse JSON".into()),
+                "serde_json::from_str",
+                input.to_string()
+            )
+        })?;
+
+        serde_json::to_string(&value).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to serialize to JSON".into()),
+                "serde_json::to_string",
+                input.to_string()
+            )
+        })
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "jsonm"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "jsonm", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5e
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
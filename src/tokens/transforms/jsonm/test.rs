@@ -0,0 +1,91 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::jsonm::Jsonm };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_jsonm() {
+        let t = Jsonm::default();
+        assert_eq!(t.get_string_repr(), "jsonm");
+    }
+
+    #[test]
+    fn transform_minifies_pretty_json() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonm::default();
+        let pretty = "{\n  \"fruta\": \"banana split\",\n  \"qtd\": [\n    1,\n    2\n  ]\n}";
+        assert_eq!(t.transform(pretty, &mut ctx), Ok(r#"{"fruta":"banana split","qtd":[1,2]}"#.to_string()));
+    }
+
+    #[test]
+    fn transform_roundtrips_with_jsonp() {
+        use crate::tokens::transforms::jsonp::Jsonp;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let compact = r#"{"a":{"b":[true,null,"x y"]},"c":1.5}"#;
+        let pretty = Jsonp::default().transform(compact, &mut ctx).unwrap();
+
+        assert_eq!(Jsonm::default().transform(&pretty, &mut ctx), Ok(compact.to_string()));
+    }
+
+    #[test]
+    fn transform_sorts_object_keys() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonm::default();
+        assert_eq!(
+            t.transform(r#"{ "z": 1, "a": 2, "m": { "y": 3, "b": 4 } }"#, &mut ctx),
+            Ok(r#"{"a":2,"m":{"b":4,"y":3},"z":1}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn transform_invalid_json_is_text_parsing_error() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonm::default();
+        let err = t.transform("[1, 2", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Jsonm::default().to_atp_line().as_ref(), "jsonm;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Jsonm::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5e() {
+            let t = Jsonm::default();
+            assert_eq!(t.get_opcode(), 0x5e);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Jsonm::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5e);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// JSONP - Json Pretty
+///
+/// Parses `input` as JSON and pretty prints it with 2 space indentation, using serde_json::to_string_pretty.
+///
+/// Object keys are written in alphabetical order. Invalid JSON returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`JSONE` - Json Escape](crate::tokens::transforms::jsone)
+/// - [`JSONU` - Json Unescape](crate::tokens::transforms::jsonu)
+/// - [`JSONM` - Json Minify](crate::tokens::transforms::jsonm)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::jsonp::Jsonp};
+///
+/// let token = Jsonp::default();
+///
+/// assert_eq!(token.transform(r#"{"banana":[1,2]}"#), Ok("{\n  \"banana\": [\n    1,\n    2\n  ]\n}".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Jsonp {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Jsonp {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "jsonp;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let value = serde_json::from_str::<serde_json::Value>(input).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to parse JSON".into()),
+                "serde_json::from_str",
+                input.to_string()
+            )
+        })?;
+
+        serde_json::to_string_pretty(&value).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to serialize to JSON".into()),
+                "serde_json::to_string_pretty",
+                input.to_string()
+            )
+        })
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "jsonp"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "jsonp", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5d
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::jsonp::Jsonp };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_jsonp() {
+        let t = Jsonp::default();
+        assert_eq!(t.get_string_repr(), "jsonp");
+    }
+
+    #[test]
+    fn transform_pretty_prints_object() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonp::default();
+        assert_eq!(
+            t.transform(r#"{"fruta":"banana","qtd":3,"tags":{"cor":"amarela"}}"#, &mut ctx),
+            Ok(
+                "{\n  \"fruta\": \"banana\",\n  \"qtd\": 3,\n  \"tags\": {\n    \"cor\": \"amarela\"\n  }\n}".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn transform_pretty_prints_array() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonp::default();
+        assert_eq!(
+            t.transform("[1, \"a\", null, true]", &mut ctx),
+            Ok("[\n  1,\n  \"a\",\n  null,\n  true\n]".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_accepts_already_pretty_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonp::default();
+        let pretty = t.transform(r#"{"a":[]}"#, &mut ctx).unwrap();
+        assert_eq!(t.transform(&pretty, &mut ctx), Ok(pretty.clone()));
+    }
+
+    #[test]
+    fn transform_invalid_json_is_text_parsing_error() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Jsonp::default();
+        let err = t.transform("{banana: 1", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        assert_eq!(err.input, "{banana: 1");
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Jsonp::default().to_atp_line().as_ref(), "jsonp;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Jsonp::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5d() {
+            let t = Jsonp::default();
+            assert_eq!(t.get_opcode(), 0x5d);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Jsonp::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5d);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
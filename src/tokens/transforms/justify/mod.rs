@@ -0,0 +1,126 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// JUSTIFY - Justify Lines
+///
+/// Stretches every line of `input` to `width` characters by adding spaces between its words, the
+/// extra spaces that do not divide evenly go to the leftmost gaps.
+///
+/// The last line, lines with a single word and lines that are already wider than `width` are
+/// kept as they are.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::justify::Justify};
+///
+/// let token = Justify::new(10);
+///
+/// assert_eq!(token.transform("a bb cc\nlast line"), Ok("a   bb  cc\nlast line".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Justify {
+    pub width: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Justify {
+    pub fn new(width: usize) -> Self {
+        Justify {
+            width,
+            params: vec![width.into()],
+        }
+    }
+}
+
+/// Spreads the words of `line` so it is exactly `width` characters wide, if possible.
+fn justify_line(line: &str, width: usize) -> String {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let gaps = words.len().saturating_sub(1);
+    let letters: usize = words
+        .iter()
+        .map(|w| w.chars().count())
+        .sum();
+
+    if gaps == 0 || letters + gaps > width {
+        return line.to_string();
+    }
+
+    let spaces = width - letters;
+    let (base, extra) = (spaces / gaps, spaces % gaps);
+
+    let mut result = String::with_capacity(line.len() + spaces);
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let pad = if i <= extra { base + 1 } else { base };
+            result.extend(std::iter::repeat_n(' ', pad));
+        }
+        result.push_str(word);
+    }
+
+    result
+}
+
+impl InstructionMethods for Justify {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.width.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("justify {};\n", self.width).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        let last = lines.len() - 1;
+
+        let justified: Vec<String> = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == last { line.to_string() } else { justify_line(line, self.width) }
+            })
+            .collect();
+
+        Ok(justified.join("\n"))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "justify"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "justify", "")?;
+
+        self.width = parse_args!(params, 0, Usize, "Width should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x80
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.width),
+        ])
+    }
+}
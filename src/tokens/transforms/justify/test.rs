@@ -0,0 +1,98 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::justify::Justify };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_justify() {
+        let t = Justify::default();
+        assert_eq!(t.get_string_repr(), "justify");
+    }
+
+    #[test]
+    fn transform_distributes_remainder_from_the_left() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Justify::new(12);
+        // 4 letters and 3 gaps: 8 spaces, 2 each plus 2 extra for the leftmost gaps
+        assert_eq!(t.transform("a b c d\nend", &mut ctx), Ok("a   b   c  d\nend".to_string()));
+    }
+
+    #[test]
+    fn transform_evenly_divisible_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Justify::new(12);
+        assert_eq!(t.transform("ab  cd ef\nx", &mut ctx), Ok("ab   cd   ef\nx".to_string()));
+    }
+
+    #[test]
+    fn transform_single_word_lines_are_not_justified() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Justify::new(10);
+        assert_eq!(t.transform("banana\nab cd", &mut ctx), Ok("banana\nab cd".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_last_line_untouched() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Justify::new(9);
+        assert_eq!(t.transform("ab cd ef\nab cd", &mut ctx), Ok("ab  cd ef\nab cd".to_string()));
+        assert_eq!(t.transform("ab cd", &mut ctx), Ok("ab cd".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_lines_wider_than_width() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Justify::new(4);
+        assert_eq!(t.transform("ab cd\nx", &mut ctx), Ok("ab cd\nx".to_string()));
+    }
+
+    #[test]
+    fn from_params_sets_width() {
+        let mut t = Justify::default();
+        t.from_params(&vec![AtpParamTypes::Usize(30)]).unwrap();
+        assert_eq!(t.width, 30);
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Justify::new(40).to_atp_line().as_ref(), "justify 40;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Justify::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x80() {
+            let t = Justify::default();
+            assert_eq!(t.get_opcode(), 0x80);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Justify::new(40);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x80);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
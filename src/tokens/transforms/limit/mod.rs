@@ -0,0 +1,100 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// LIMIT - Limit Length
+///
+/// Passes `input` through unchanged, unless it has more than `max_chars` characters, in which case it fails with
+/// `InvalidParameters`.
+///
+/// Useful as the first token of a pipeline that may receive untrusted input.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::limit::Limit};
+///
+/// let token = Limit::new(6);
+///
+/// assert_eq!(token.transform("banana"), Ok("banana".to_string()));
+/// assert!(token.transform("bananas").is_err());
+/// ```
+#[derive(Clone, Default)]
+pub struct Limit {
+    pub max_chars: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Limit {
+    pub fn new(max_chars: usize) -> Self {
+        Limit {
+            max_chars,
+            params: vec![max_chars.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Limit {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.max_chars.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("limit {};\n", self.max_chars).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        // Stops counting right after the limit, so huge inputs are not fully scanned
+        if input.chars().take(self.max_chars + 1).count() > self.max_chars {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters(
+                        format!("Input exceeds the limit of {} characters", self.max_chars).into()
+                    ),
+                    self.to_atp_line(),
+                    ""
+                )
+            );
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "limit"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "limit", "")?;
+
+        self.max_chars = parse_args!(params, 0, Usize, "Max chars should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x57
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.max_chars),
+        ])
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::limit::Limit };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_limit() {
+        let t = Limit::default();
+        assert_eq!(t.get_string_repr(), "limit");
+    }
+
+    #[test]
+    fn transform_passes_input_at_the_limit() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Limit::new(6);
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_input_over_the_limit() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Limit::new(6);
+        let err = t.transform("bananas", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn transform_counts_chars_not_bytes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Limit::new(4);
+        assert_eq!(t.transform("maçã", &mut ctx), Ok("maçã".to_string()));
+    }
+
+    #[test]
+    fn transform_zero_limit_only_accepts_empty_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Limit::new(0);
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+        assert!(t.transform("a", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Limit::new(100);
+        assert_eq!(t.to_atp_line().as_ref(), "limit 100;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Limit::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x57() {
+            let t = Limit::default();
+            assert_eq!(t.get_opcode(), 0x57);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Limit::new(100);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x57);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
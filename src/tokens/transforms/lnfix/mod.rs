@@ -0,0 +1,118 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// LNFIX - Line Prefix/Suffix
+///
+/// Adds `prefix` to the start and `suffix` to the end of every `\n` separated line of `input`.
+///
+/// Blank lines are wrapped too, but a trailing newline does not create an extra line.
+/// For `\r\n` line endings the suffix is placed before the `\r`.
+///
+/// See Also:
+///
+/// - [`ATB` - Add to Beginning](crate::tokens::transforms::atb)
+/// - [`ATE` - Add to End](crate::tokens::transforms::ate)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::lnfix::Lnfix};
+///
+/// let token = Lnfix::new("> ", "");
+///
+/// assert_eq!(token.transform("hello\nworld"), Ok("> hello\n> world".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Lnfix {
+    pub prefix: String,
+    pub suffix: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Lnfix {
+    pub fn new(prefix: &str, suffix: &str) -> Self {
+        Lnfix {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+            params: vec![prefix.to_string().into(), suffix.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Lnfix {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.prefix.to_string(), self.suffix.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("lnfix {} {};\n", self.prefix, self.suffix).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if input.is_empty() {
+            return Ok(String::new());
+        }
+
+        let body = input.strip_suffix('\n');
+        let lines = body.unwrap_or(input);
+
+        let mut result = lines
+            .split('\n')
+            .map(|line| {
+                let (text, cr) = match line.strip_suffix('\r') {
+                    Some(text) => (text, "\r"),
+                    None => (line, ""),
+                };
+                format!("{}{}{}{}", self.prefix, text, self.suffix, cr)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "lnfix"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "lnfix", "")?;
+
+        self.prefix = parse_args!(params, 0, String, "Prefix should be of string type");
+        self.suffix = parse_args!(params, 1, String, "Suffix should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x54
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.prefix.clone()),
+            AtpParamTypes::String(self.suffix.clone()),
+        ])
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::lnfix::Lnfix };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_lnfix() {
+        let t = Lnfix::default();
+        assert_eq!(t.get_string_repr(), "lnfix");
+    }
+
+    #[test]
+    fn transform_prefix_only() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("> ", "");
+        assert_eq!(t.transform("hello\nworld", &mut ctx), Ok("> hello\n> world".to_string()));
+    }
+
+    #[test]
+    fn transform_suffix_only() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("", ";");
+        assert_eq!(t.transform("a = 1\nb = 2", &mut ctx), Ok("a = 1;\nb = 2;".to_string()));
+    }
+
+    #[test]
+    fn transform_wraps_blank_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("// ", "");
+        assert_eq!(t.transform("a\n\nb", &mut ctx), Ok("// a\n// \n// b".to_string()));
+    }
+
+    #[test]
+    fn transform_preserves_trailing_newline() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("[", "]");
+        assert_eq!(t.transform("a\nb\n", &mut ctx), Ok("[a]\n[b]\n".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_crlf_after_suffix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("<", ">");
+        assert_eq!(t.transform("a\r\nb\r\n", &mut ctx), Ok("<a>\r\n<b>\r\n".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lnfix::new("<", ">");
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Lnfix::new("<", ">");
+        assert_eq!(t.to_atp_line().as_ref(), "lnfix < >;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Lnfix::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x54() {
+            let t = Lnfix::default();
+            assert_eq!(t.get_opcode(), 0x54);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Lnfix::new("<", ">");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x54);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
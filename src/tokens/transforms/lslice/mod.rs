@@ -0,0 +1,151 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::{ check_chunk_bound_indexes, check_vec_len } },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// LSLICE - Line Slice
+///
+/// Keeps only the lines of `input` between `start` and `end` (inclusive), lines are separated by `\n`.
+///
+/// If `end` is bigger than the last line index, it is clamped to it. A trailing newline at the end of `input`
+/// is preserved. It will throw an `AtpError` if `start` is not smaller than `end`, or if `start` does not
+/// exist in `input`.
+///
+/// See Also:
+///
+/// - [`WSLICE` - Word Slice](crate::tokens::transforms::wslice)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::lslice::Lslice};
+///
+/// let token = Lslice::new(1, 2).unwrap();
+///
+/// assert_eq!(token.transform("banana\nlaranja\ncheia\ncanja"), Ok("laranja\ncheia".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Lslice {
+    pub start: usize,
+    pub end: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Lslice {
+    pub fn new(start: usize, end: usize) -> Result<Self, AtpError> {
+        check_chunk_bound_indexes(start, end, None)?;
+
+        Ok(Lslice {
+            start,
+            end,
+            params: vec![start.into(), end.into()],
+        })
+    }
+}
+
+// `start` must stay below `end`, so the default is the first line and not an empty range
+impl Default for Lslice {
+    fn default() -> Self {
+        Lslice {
+            start: 0,
+            end: 1,
+            params: vec![],
+        }
+    }
+}
+
+impl InstructionMethods for Lslice {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start.to_string(), self.end.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("lslice {} {};\n", self.start, self.end).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if input.is_empty() {
+            return Ok(String::new());
+        }
+
+        let body = input.strip_suffix('\n');
+        let lines = body.unwrap_or(input).split('\n').collect::<Vec<_>>();
+
+        if self.start >= lines.len() {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::IndexOutOfRange(
+                        format!(
+                            "Line index {} does not exist for input, only indexes between 0-{} are allowed!",
+                            self.start,
+                            lines.len() - 1
+                        ).into()
+                    ),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        let end = self.end.min(lines.len() - 1);
+        let mut result = lines[self.start..=end].join("\n");
+
+        if body.is_some() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "lslice"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "lslice", "")?;
+
+        let start = parse_args!(params, 0, Usize, "Index should be of usize type");
+        let end = parse_args!(params, 1, Usize, "Index should be of usize type");
+
+        // Same rule as `new`, so a parsed line can't build a token `new` would reject
+        check_chunk_bound_indexes(start, end, None).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Start index must be smaller than end index".into()),
+                "lslice",
+                e.input
+            )
+        })?;
+
+        self.start = start;
+        self.end = end;
+        self.params = params.clone();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x68
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.start),
+            AtpParamTypes::Usize(self.end),
+        ])
+    }
+}
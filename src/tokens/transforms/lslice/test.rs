@@ -0,0 +1,128 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::lslice::Lslice };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_lslice() {
+        let t = Lslice::default();
+        assert_eq!(t.get_string_repr(), "lslice");
+    }
+
+    #[test]
+    fn new_rejects_start_not_smaller_than_end() {
+        let err = Lslice::new(2, 1).err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidIndex(_)));
+    }
+
+    #[test]
+    fn transform_keeps_range() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lslice::new(1, 3).unwrap();
+        assert_eq!(
+            t.transform("l0\nl1\nl2\nl3\nl4\n", &mut ctx),
+            Ok("l1\nl2\nl3\n".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_single_line_range() {
+        let mut ctx = GlobalExecutionContext::new();
+        // end é limitado à última linha, sobrando só ela
+        let t = Lslice::new(2, 10).unwrap();
+        assert_eq!(t.transform("l0\nl1\nl2", &mut ctx), Ok("l2".to_string()));
+    }
+
+    #[test]
+    fn transform_clamps_out_of_bounds_end() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lslice::new(0, 99).unwrap();
+        assert_eq!(t.transform("l0\n\nl2", &mut ctx), Ok("l0\n\nl2".to_string()));
+    }
+
+    #[test]
+    fn transform_start_out_of_range_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lslice::new(3, 5).unwrap();
+        let err = t.transform("l0\nl1\n", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Lslice::new(0, 1).unwrap().transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Lslice::new(1, 4).unwrap().to_atp_line().as_ref(), "lslice 1 4;\n");
+    }
+
+    #[test]
+    fn from_params_reads_indexes() {
+        let mut t = Lslice::default();
+        t.from_params(&vec![AtpParamTypes::Usize(2), AtpParamTypes::Usize(5)]).unwrap();
+        assert_eq!((t.start, t.end), (2, 5));
+    }
+
+    #[test]
+    fn from_params_rejects_start_not_smaller_than_end() {
+        let mut t = Lslice::default();
+
+        for (start, end) in [(3, 1), (2, 2)] {
+            let params = vec![AtpParamTypes::Usize(start), AtpParamTypes::Usize(end)];
+            let err = t.from_params(&params).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        }
+    }
+
+    #[test]
+    fn parsed_line_with_reversed_bounds_is_an_error() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let token = read_from_text("lslice 3 1;").unwrap();
+
+        let err = token.apply_token("a\nb\nc\nd\ne", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Lslice::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x68() {
+            let t = Lslice::default();
+            assert_eq!(t.get_opcode(), 0x68);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Lslice::new(1, 4).unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x68);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
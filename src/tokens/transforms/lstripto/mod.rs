@@ -0,0 +1,93 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// LSTRIPTO - Left Strip To
+///
+/// Removes everything before the first occurrence of `ch` in `input`, including `ch` itself.
+///
+/// If `ch` is not found, `input` is returned unchanged.
+///
+/// See Also:
+///
+/// - [`RSTRIPTO` - Right Strip To](crate::tokens::transforms::rstripto)
+/// - [`DLB` - Delete Before](crate::tokens::transforms::dlb)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::lstripto::Lstripto};
+///
+/// let token = Lstripto::new("/");
+///
+/// assert_eq!(token.transform("home/user/file.txt"), Ok("user/file.txt".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Lstripto {
+    pub ch: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Lstripto {
+    pub fn new(ch: &str) -> Self {
+        Lstripto {
+            ch: ch.to_string(),
+            params: vec![ch.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Lstripto {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.ch.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("lstripto {};\n", self.ch).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        match input.find(&self.ch) {
+            Some(i) if !self.ch.is_empty() => Ok(input[i + self.ch.len()..].to_string()),
+            _ => Ok(input.to_string()),
+        }
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "lstripto"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "lstripto", "")?;
+
+        self.ch = parse_args!(params, 0, String, "Character should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x38
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.ch.clone()),
+        ])
+    }
+}
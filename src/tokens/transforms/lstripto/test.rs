@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::lstripto::Lstripto };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_lstripto() {
+        let t = Lstripto::default();
+        assert_eq!(t.get_string_repr(), "lstripto");
+    }
+
+    #[test]
+    fn transform_drops_up_to_first_occurrence() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lstripto::new("/");
+        assert_eq!(t.transform("home/user/file.txt", &mut ctx), Ok("user/file.txt".to_string()));
+    }
+
+    #[test]
+    fn transform_absent_character_keeps_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lstripto::new("#");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_character_at_the_end_yields_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lstripto::new(":");
+        assert_eq!(t.transform("key:", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_handles_multibyte_characters() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Lstripto::new("ç");
+        assert_eq!(t.transform("maçã", &mut ctx), Ok("ã".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Lstripto::new("/");
+        assert_eq!(t.to_atp_line().as_ref(), "lstripto /;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_single_string() {
+        let mut t = Lstripto::default();
+        t.from_params(&vec![AtpParamTypes::String("-".to_string())]).unwrap();
+        assert_eq!(t.ch, "-");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Lstripto::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x38() {
+            let t = Lstripto::default();
+            assert_eq!(t.get_opcode(), 0x38);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Lstripto::new("/");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x38);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
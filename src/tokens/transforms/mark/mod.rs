@@ -0,0 +1,101 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// MARK - Debug Mark
+///
+/// Returns `input` unchanged. When the pipeline runs in debug mode (e.g. `process_all_with_debug`)
+/// it also writes `[mark: {label}] {snippet}` to the debug output, where `snippet` is the start of
+/// `input`, so the stages of long pipelines are easy to find in the logs.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::mark::Mark};
+///
+/// let token = Mark::new("after trim");
+///
+/// assert_eq!(token.transform(" banana "), Ok(" banana ".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Mark {
+    pub label: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Mark {
+    pub fn new(label: &str) -> Self {
+        Mark {
+            label: label.to_string(),
+            params: vec![label.to_string().into()],
+        }
+    }
+}
+
+/// Characters of `input` shown after the mark label.
+const SNIPPET_LEN: usize = 40;
+
+fn snippet(input: &str) -> String {
+    match input.char_indices().nth(SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &input[..end]),
+        None => input.to_string(),
+    }
+}
+
+impl InstructionMethods for Mark {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.label.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("mark {};\n", self.label).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        context.debug_log(format!("[mark: {}] {}", self.label, snippet(input)));
+
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "mark"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "mark", "")?;
+
+        self.label = parse_args!(params, 0, String, "Label should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x77
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.label.clone()),
+        ])
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::mark::Mark };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_mark() {
+        let t = Mark::default();
+        assert_eq!(t.get_string_repr(), "mark");
+    }
+
+    #[test]
+    fn transform_passes_input_through_exactly() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Mark::new("stage 1");
+
+        for input in ["", "  banana  ", "maçã\r\n\tlaranja\n"] {
+            assert_eq!(t.transform(input, &mut ctx), Ok(input.to_string()));
+        }
+    }
+
+    #[test]
+    fn transform_logs_only_in_debug_mode() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Mark::new("stage 1");
+
+        t.transform("banana", &mut ctx).unwrap();
+        assert!(ctx.take_debug_messages().is_empty());
+
+        ctx.set_debug(true);
+        t.transform("banana", &mut ctx).unwrap();
+        assert_eq!(ctx.take_debug_messages(), vec!["[mark: stage 1] banana".to_string()]);
+    }
+
+    #[test]
+    fn transform_truncates_long_snippets() {
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_debug(true);
+
+        Mark::new("x").transform(&"ç".repeat(50), &mut ctx).unwrap();
+
+        assert_eq!(ctx.take_debug_messages(), vec![format!("[mark: x] {}...", "ç".repeat(40))]);
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Mark::new("stage_1").to_atp_line().as_ref(), "mark stage_1;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Mark::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x77() {
+            let t = Mark::default();
+            assert_eq!(t.get_opcode(), 0x77);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Mark::new("stage");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x77);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// MIRROR - Mirror
+///
+/// Appends the reverse of `input` to itself, reversing by grapheme so accents and emojis stay intact.
+///
+/// When `include_pivot` is true the last grapheme appears twice at the seam (`abc` -> `abccba`),
+/// otherwise it appears once (`abc` -> `abcba`).
+///
+/// See Also:
+///
+/// - [`REV` - Reverse](crate::tokens::transforms::rev)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::mirror::Mirror};
+///
+/// assert_eq!(Mirror::new(true).transform("abc"), Ok("abccba".to_string()));
+/// assert_eq!(Mirror::new(false).transform("abc"), Ok("abcba".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Mirror {
+    pub include_pivot: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Mirror {
+    pub fn new(include_pivot: bool) -> Self {
+        Mirror {
+            include_pivot,
+            params: vec![include_pivot.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Mirror {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.include_pivot.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("mirror {};\n", self.include_pivot).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut reversed = input.graphemes(true).rev();
+
+        if !self.include_pivot {
+            reversed.next();
+        }
+
+        Ok(format!("{}{}", input, reversed.collect::<String>()))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "mirror"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "mirror", "")?;
+
+        self.include_pivot = parse_args!(params, 0, Bool, "Include pivot flag should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x64
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Bool(self.include_pivot),
+        ])
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::mirror::Mirror };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_mirror() {
+        let t = Mirror::default();
+        assert_eq!(t.get_string_repr(), "mirror");
+    }
+
+    #[test]
+    fn transform_with_pivot_duplicates_last_char() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Mirror::new(true);
+        assert_eq!(t.transform("abc", &mut ctx), Ok("abccba".to_string()));
+    }
+
+    #[test]
+    fn transform_without_pivot_is_a_palindrome_with_single_center() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Mirror::new(false);
+        assert_eq!(t.transform("abc", &mut ctx), Ok("abcba".to_string()));
+        assert_eq!(t.transform("a", &mut ctx), Ok("a".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_graphemes_intact() {
+        let mut ctx = GlobalExecutionContext::new();
+        // "e\u{301}" é um único grafema (e + acento combinante)
+        assert_eq!(
+            Mirror::new(true).transform("mãe\u{301}", &mut ctx),
+            Ok("mãe\u{301}e\u{301}ãm".to_string())
+        );
+        assert_eq!(Mirror::new(false).transform("🍌🍊", &mut ctx), Ok("🍌🍊🍌".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Mirror::new(true).transform("", &mut ctx), Ok("".to_string()));
+        assert_eq!(Mirror::new(false).transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Mirror::new(true).to_atp_line().as_ref(), "mirror true;\n");
+        assert_eq!(Mirror::new(false).to_atp_line().as_ref(), "mirror false;\n");
+    }
+
+    #[test]
+    fn from_params_reads_flag() {
+        let mut t = Mirror::default();
+        t.from_params(&vec![AtpParamTypes::Bool(true)]).unwrap();
+        assert!(t.include_pivot);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Mirror::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x64() {
+            let t = Mirror::default();
+            assert_eq!(t.get_opcode(), 0x64);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Mirror::new(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x64);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -1,50 +1,124 @@
+pub mod abbr;
+pub mod asciionly;
 pub mod atb;
 pub mod ate;
+pub mod bind;
+pub mod bine;
 pub mod cfw;
+pub mod chunkevery;
 pub mod clw;
+pub mod cmt;
+pub mod cntm;
+pub mod colalign;
+pub mod colsel;
+pub mod contains;
+pub mod crc32;
+pub mod csve;
 pub mod ctc;
 pub mod ctr;
 pub mod cts;
+pub mod ddpw;
+pub mod dflt;
 pub mod dla;
 pub mod dlb;
 pub mod dlc;
 pub mod dlf;
 pub mod dll;
 pub mod dls;
+pub mod ensurepfx;
+pub mod ensuresfx;
+pub mod expr;
+pub mod findidx;
+pub mod fold;
+pub mod head;
 pub mod htmle;
 pub mod htmlu;
 pub mod ins;
+pub mod interleave;
 pub mod jcmc;
 pub mod jkbc;
+pub mod joinw;
 pub mod jpsc;
 pub mod jsnc;
 pub mod jsone;
+pub mod jsonm;
+pub mod jsonp;
 pub mod jsonu;
+pub mod justify;
+pub mod limit;
+pub mod lnfix;
+pub mod lslice;
+pub mod lstripto;
+pub mod mark;
+pub mod mirror;
+pub mod morsed;
+pub mod morsee;
+pub mod nop;
+pub mod nrmnl;
+pub mod numinc;
+pub mod ovw;
 pub mod padl;
+pub mod padmul;
 pub mod padr;
+pub mod printable;
+pub mod punctsp;
+pub mod qpd;
+pub mod qpe;
 pub mod raw;
 pub mod rcw;
+pub mod redact;
+pub mod require;
 pub mod rev;
+pub mod revew;
+pub mod revl;
 pub mod rfw;
 pub mod rlw;
+pub mod rmel;
 pub mod rmws;
 pub mod rnw;
+pub mod rotn;
 pub mod rpt;
+pub mod rstripto;
 pub mod rtl;
 pub mod rtr;
+pub mod rww;
+pub mod sample;
+pub mod sha256;
+pub mod shesc;
+pub mod shunesc;
 pub mod slt;
+pub mod slug;
 pub mod splc;
+pub mod splitcase;
 pub mod sslt;
+pub mod stppfx;
+pub mod stpsfx;
+pub mod stripansi;
+pub mod submap;
+pub mod swpw;
+pub mod tabs;
+pub mod tail;
 pub mod tbs;
+pub mod tbsa;
+pub mod tgl;
 pub mod tla;
 pub mod tlcc;
 pub mod tlcs;
 pub mod tlcw;
 pub mod tls;
+pub mod tlsa;
+pub mod transp;
+pub mod trln;
 pub mod trs;
+pub mod trsa;
+pub mod ttlx;
 pub mod tua;
 pub mod tucc;
 pub mod tucs;
 pub mod tucw;
 pub mod urld;
 pub mod urle;
+pub mod wfreq;
+pub mod wrap;
+pub mod wsel;
+pub mod wslice;
@@ -0,0 +1,100 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::utils::transforms::MORSE_TABLE;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// MORSED - Morse Decode
+///
+/// Decodes international Morse code `input`, where dot-dash groups are separated by whitespace
+/// and words by `/`. Letters are decoded as upper case.
+///
+/// It will throw an `AtpError` if a group is not a known Morse code.
+///
+/// See Also:
+///
+/// - [`MORSEE` - Morse Encode](crate::tokens::transforms::morsee)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::morsed::Morsed};
+///
+/// let token = Morsed::default();
+///
+/// assert_eq!(token.transform("... --- ... / .... . .-.. .--."), Ok("SOS HELP".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Morsed {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Morsed {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "morsed;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut words = Vec::new();
+
+        for word in input.split('/') {
+            let mut decoded = String::new();
+
+            for group in word.split_whitespace() {
+                match MORSE_TABLE.iter().find(|(_, code)| *code == group) {
+                    Some((symbol, _)) => decoded.push(*symbol),
+                    None => {
+                        return Err(
+                            AtpError::new(
+                                AtpErrorCode::TextParsingError(
+                                    format!("Unknown Morse code group '{}'", group).into()
+                                ),
+                                "morsed",
+                                input.to_string()
+                            )
+                        );
+                    }
+                }
+            }
+
+            if !decoded.is_empty() {
+                words.push(decoded);
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "morsed"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "morsed", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x73
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
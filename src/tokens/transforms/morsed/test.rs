@@ -0,0 +1,84 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::morsed::Morsed };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_morsed() {
+        let t = Morsed::default();
+        assert_eq!(t.get_string_repr(), "morsed");
+    }
+
+    #[test]
+    fn transform_decodes_words() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Morsed::default();
+        assert_eq!(
+            t.transform("... --- ... / .... . .-.. .--.", &mut ctx),
+            Ok("SOS HELP".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_accepts_extra_whitespace() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Morsed::default();
+        assert_eq!(t.transform("  ...   ---  ...\n/ ..  ", &mut ctx), Ok("SOS I".to_string()));
+    }
+
+    #[test]
+    fn transform_round_trips_through_morsee() {
+        use crate::tokens::transforms::morsee::Morsee;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let code = "... --- ... / .... . .-.. .--.";
+        let decoded = Morsed::default().transform(code, &mut ctx).unwrap();
+
+        assert_eq!(Morsee::default().transform(&decoded, &mut ctx), Ok(code.to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_unknown_code_group() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Morsed::default().transform("... ------- ...", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Morsed::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x73() {
+            let t = Morsed::default();
+            assert_eq!(t.get_opcode(), 0x73);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Morsed::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x73);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
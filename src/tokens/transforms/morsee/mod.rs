@@ -0,0 +1,99 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::utils::transforms::MORSE_TABLE;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// MORSEE - Morse Encode
+///
+/// Encodes `input` as international Morse code. Each letter, digit or punctuation mark becomes its
+/// dot-dash group, groups are separated by a space and words by ` / `. Letters are case insensitive.
+///
+/// It will throw an `AtpError` if `input` has a character without a Morse code.
+///
+/// See Also:
+///
+/// - [`MORSED` - Morse Decode](crate::tokens::transforms::morsed)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::morsee::Morsee};
+///
+/// let token = Morsee::default();
+///
+/// assert_eq!(token.transform("SOS help"), Ok("... --- ... / .... . .-.. .--.".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Morsee {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Morsee {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "morsee;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut words = Vec::new();
+
+        for word in input.split_whitespace() {
+            let mut groups = Vec::new();
+
+            for c in word.chars() {
+                let upper = c.to_ascii_uppercase();
+                match MORSE_TABLE.iter().find(|(symbol, _)| *symbol == upper) {
+                    Some((_, code)) => groups.push(*code),
+                    None => {
+                        return Err(
+                            AtpError::new(
+                                AtpErrorCode::TextParsingError(
+                                    format!("Character '{}' has no Morse code", c).into()
+                                ),
+                                "morsee",
+                                input.to_string()
+                            )
+                        );
+                    }
+                }
+            }
+
+            words.push(groups.join(" "));
+        }
+
+        Ok(words.join(" / "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "morsee"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "morsee", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x72
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::morsee::Morsee };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_morsee() {
+        let t = Morsee::default();
+        assert_eq!(t.get_string_repr(), "morsee");
+    }
+
+    #[test]
+    fn transform_encodes_words_with_slash_separator() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Morsee::default();
+        assert_eq!(
+            t.transform("SOS HELP", &mut ctx),
+            Ok("... --- ... / .... . .-.. .--.".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_is_case_insensitive_and_handles_digits() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Morsee::default();
+        assert_eq!(t.transform("sos 42", &mut ctx), Ok("... --- ... / ....- ..---".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_round_trips_through_morsed() {
+        use crate::tokens::transforms::morsed::Morsed;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let encoded = Morsee::default().transform("SOS HELP", &mut ctx).unwrap();
+
+        assert_eq!(Morsed::default().transform(&encoded, &mut ctx), Ok("SOS HELP".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_characters_without_code() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Morsee::default().transform("maçã", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Morsee::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x72() {
+            let t = Morsee::default();
+            assert_eq!(t.get_opcode(), 0x72);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Morsee::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x72);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
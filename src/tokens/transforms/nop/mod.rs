@@ -0,0 +1,66 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// NOP - No Operation
+///
+/// Returns `input` unchanged.
+///
+/// Useful as an explicit placeholder step in pipelines generated from templates.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::nop::Nop};
+///
+/// let token = Nop::default();
+///
+/// assert_eq!(token.transform("banana"), Ok("banana".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Nop {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Nop {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "nop;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "nop"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "nop", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4c
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
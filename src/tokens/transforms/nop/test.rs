@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::nop::Nop };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_nop() {
+        let t = Nop::default();
+        assert_eq!(t.get_string_repr(), "nop");
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Nop::default();
+        assert_eq!(t.to_atp_line().as_ref(), "nop;\n");
+    }
+
+    #[test]
+    fn transform_is_identity() {
+        let t = Nop::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        for input in ["", "banana", "  a\tb\n ", "çãõ 🍌 e\u{301}"] {
+            assert_eq!(t.transform(input, &mut ctx), Ok(input.to_string()));
+        }
+    }
+
+    #[test]
+    fn text_line_round_trips_through_reader() {
+        use crate::text::reader::read_from_text;
+
+        let token = read_from_text("nop;").unwrap();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(token.get_string_repr(), "nop");
+        assert_eq!(token.to_text_line_unresolved().unwrap(), "nop;\n");
+        assert_eq!(token.apply_token("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Nop::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x4c() {
+            let t = Nop::default();
+            assert_eq!(t.get_opcode(), 0x4c);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Nop::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x4c);
+            assert_eq!(bc[12] as usize, 0);
+        }
+
+        #[test]
+        fn bytecode_round_trips_through_file_reader() {
+            use crate::bytecode::reader::read_bytecode_from_file;
+
+            let mut bytes: Vec<u8> = vec![38, 235, 245, 8, 244, 137, 1, 179];
+            bytes.extend_from_slice(&1u64.to_be_bytes());
+            bytes.extend_from_slice(&1u32.to_be_bytes());
+            bytes.extend_from_slice(&Nop::default().to_bytecode());
+
+            let mut path = std::env::temp_dir();
+            path.push(format!("atp_nop_roundtrip_{}.atpbc", std::process::id()));
+            std::fs::write(&path, bytes).unwrap();
+
+            let tokens = read_bytecode_from_file(&path).unwrap();
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(tokens.len(), 1);
+            assert_eq!(tokens[0].get_string_repr(), "nop");
+        }
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// NRMNL - Normalize Newlines
+///
+/// Converts every line ending of `input` (`\r\n`, `\r` or `\n`) to the one given by `style`, which can be
+/// `lf`, `crlf` or `cr`. Any other `style` returns an `AtpError`.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::nrmnl::Nrmnl};
+///
+/// let token = Nrmnl::new("lf").unwrap();
+///
+/// assert_eq!(token.transform("banana\r\nlaranja\rcanja\n"), Ok("banana\nlaranja\ncanja\n".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Nrmnl {
+    pub style: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Nrmnl {
+    pub fn new(style: &str) -> Result<Self, AtpError> {
+        newline_for_style(style)?;
+
+        Ok(Nrmnl {
+            style: style.to_string(),
+            params: vec![style.to_string().into()],
+        })
+    }
+}
+
+/// Maps a newline `style` to the line ending it stands for.
+fn newline_for_style(style: &str) -> Result<&'static str, AtpError> {
+    match style {
+        "lf" => Ok("\n"),
+        "crlf" => Ok("\r\n"),
+        "cr" => Ok("\r"),
+        _ =>
+            Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters(
+                        "Newline style should be one of 'lf', 'crlf' or 'cr'".into()
+                    ),
+                    "nrmnl",
+                    style.to_string()
+                )
+            ),
+    }
+}
+
+impl Default for Nrmnl {
+    fn default() -> Self {
+        Nrmnl { style: "lf".to_string(), params: vec!["lf".to_string().into()] }
+    }
+}
+
+impl InstructionMethods for Nrmnl {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.style.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("nrmnl {};\n", self.style).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let newline = newline_for_style(&self.style)?;
+
+        let mut result = String::with_capacity(input.len());
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\r' => {
+                    chars.next_if_eq(&'\n');
+                    result.push_str(newline);
+                }
+                '\n' => result.push_str(newline),
+                _ => result.push(c),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "nrmnl"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "nrmnl", "")?;
+
+        self.style = parse_args!(params, 0, String, "Style should be of string type");
+
+        newline_for_style(&self.style)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x6c
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.style.clone()),
+        ])
+    }
+}
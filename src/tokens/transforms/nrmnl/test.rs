@@ -0,0 +1,101 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::nrmnl::Nrmnl };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_nrmnl() {
+        let t = Nrmnl::default();
+        assert_eq!(t.get_string_repr(), "nrmnl");
+    }
+
+    const MIXED: &str = "a\r\nb\rc\nd\r\n\re";
+
+    #[test]
+    fn transform_mixed_to_lf() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Nrmnl::new("lf").unwrap();
+        assert_eq!(t.transform(MIXED, &mut ctx), Ok("a\nb\nc\nd\n\ne".to_string()));
+    }
+
+    #[test]
+    fn transform_mixed_to_crlf() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Nrmnl::new("crlf").unwrap();
+        assert_eq!(t.transform(MIXED, &mut ctx), Ok("a\r\nb\r\nc\r\nd\r\n\r\ne".to_string()));
+    }
+
+    #[test]
+    fn transform_mixed_to_cr() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Nrmnl::new("cr").unwrap();
+        assert_eq!(t.transform(MIXED, &mut ctx), Ok("a\rb\rc\rd\r\re".to_string()));
+    }
+
+    #[test]
+    fn transform_is_idempotent() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Nrmnl::new("crlf").unwrap();
+        let once = t.transform(MIXED, &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()));
+    }
+
+    #[test]
+    fn new_rejects_unknown_style() {
+        let err = Nrmnl::new("LF").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_unknown_style() {
+        let mut t = Nrmnl::default();
+        let err = t.from_params(&vec![AtpParamTypes::String("unix".to_string())]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+
+        t.from_params(&vec![AtpParamTypes::String("cr".to_string())]).unwrap();
+        assert_eq!(t.style, "cr");
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Nrmnl::new("crlf").unwrap().to_atp_line().as_ref(), "nrmnl crlf;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Nrmnl::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x6c() {
+            let t = Nrmnl::default();
+            assert_eq!(t.get_opcode(), 0x6c);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Nrmnl::new("lf").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x6c);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use regex::Captures;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::AtpError,
+        regex_cache::get_or_compile,
+        validations::check_vec_len,
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// NUMINC - Numeric Increment
+///
+/// Adds `delta` to every integer found in `input`, `delta` may be negative.
+///
+/// Results below zero saturate at 0 and numbers too large to be parsed are kept unchanged.
+///
+/// See Also:
+///
+/// - [`RPT` - Repeat](crate::tokens::transforms::rpt)
+/// - [`RAW` - Replace All With](crate::tokens::transforms::raw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::numinc::Numinc};
+///
+/// let token = Numinc::new(1);
+///
+/// assert_eq!(token.transform("page 9 of 10"), Ok("page 10 of 11".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Numinc {
+    pub delta: i64,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Numinc {
+    pub fn new(delta: i64) -> Self {
+        Numinc {
+            delta,
+            params: vec![AtpParamTypes::I64(delta)],
+        }
+    }
+}
+
+impl InstructionMethods for Numinc {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.delta.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("numinc {};\n", self.delta).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let numbers = get_or_compile(r"\d+")?;
+
+        Ok(
+            numbers
+                .replace_all(input, |caps: &Captures| {
+                    match caps[0].parse::<i64>() {
+                        Ok(n) => n.saturating_add(self.delta).max(0).to_string(),
+                        Err(_) => caps[0].to_string(),
+                    }
+                })
+                .to_string()
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "numinc"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "numinc", "")?;
+
+        self.delta = parse_args!(params, 0, I64, "Delta should be of i64 type");
+        self.params = params.clone();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x42
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::I64(self.delta),
+        ])
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::numinc::Numinc };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_numinc() {
+        let t = Numinc::default();
+        assert_eq!(t.get_string_repr(), "numinc");
+    }
+
+    #[test]
+    fn transform_increments_every_number() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Numinc::new(1);
+        assert_eq!(t.transform("page 9 of 10, item 099", &mut ctx), Ok("page 10 of 11, item 100".to_string()));
+    }
+
+    #[test]
+    fn transform_negative_delta_saturates_at_zero() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Numinc::new(-5);
+        assert_eq!(t.transform("12 apples and 3 pears", &mut ctx), Ok("7 apples and 0 pears".to_string()));
+    }
+
+    #[test]
+    fn transform_text_without_numbers_is_unchanged() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Numinc::new(3);
+        assert_eq!(t.transform("banana laranja", &mut ctx), Ok("banana laranja".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Numinc::new(-2);
+        assert_eq!(t.to_atp_line().as_ref(), "numinc -2;\n");
+    }
+
+    #[test]
+    fn from_params_parses_signed_delta() {
+        let mut t = Numinc::default();
+        t.from_params(&vec![AtpParamTypes::I64(-42)]).unwrap();
+        assert_eq!(t.delta, -42);
+    }
+
+    #[test]
+    fn from_params_rejects_non_integer_delta() {
+        let mut t = Numinc::default();
+        let err = t.from_params(&vec![AtpParamTypes::String("-42".to_string())]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Numinc::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+        use crate::utils::test_helpers::bytecode_roundtrip;
+
+        #[test]
+        fn get_opcode_is_0x42() {
+            let t = Numinc::default();
+            assert_eq!(t.get_opcode(), 0x42);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Numinc::new(-2);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x42);
+            assert_eq!(bc[12] as usize, 1);
+        }
+
+        #[test]
+        fn negative_delta_survives_bytecode_roundtrip() {
+            let bc = Numinc::new(-3).to_bytecode();
+
+            // param record starts after total(8) + opcode(4) + count(1) + param total(8)
+            let decoded = AtpParamTypes::from_bytecode(bc[21..].to_vec()).unwrap();
+            assert!(matches!(decoded, AtpParamTypes::I64(-3)));
+
+            let mut t = Numinc::default();
+            t.from_params(&vec![decoded]).unwrap();
+            assert_eq!(t.delta, -3);
+            assert_eq!(bytecode_roundtrip(&t), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::{ check_chunk_bound_indexes, check_vec_len } },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// OVW - Overwrite
+///
+/// Replaces the characters of `input` between `start_index` and `end_index` (inclusive) with `text`.
+///
+/// Unlike an insertion, the range is removed; `text` may be shorter or longer than the range it replaces.
+///
+/// See Also:
+///
+/// - [`DLC` - Delete Chunk](crate::tokens::transforms::dlc)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::ovw::Ovw};
+///
+/// let token = Ovw::new(0, 5, "maçã").unwrap();
+///
+/// assert_eq!(token.transform("banana laranja"), Ok("maçã laranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Ovw {
+    pub start_index: usize,
+    pub end_index: usize,
+    pub text: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Ovw {
+    pub fn new(start_index: usize, end_index: usize, text: &str) -> Result<Self, AtpError> {
+        check_chunk_bound_indexes(start_index, end_index, None)?;
+
+        Ok(Ovw {
+            start_index,
+            end_index,
+            text: text.to_string(),
+            params: vec![start_index.into(), end_index.into(), text.to_string().into()],
+        })
+    }
+}
+
+impl InstructionMethods for Ovw {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string(), self.text.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("ovw {} {} {};\n", self.start_index, self.end_index, self.text).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        check_chunk_bound_indexes(self.start_index, self.end_index, Some(input))?;
+
+        let mut result = String::with_capacity(input.len() + self.text.len());
+
+        for (i, c) in input.chars().enumerate() {
+            if i == self.start_index {
+                result.push_str(&self.text);
+            }
+            if i < self.start_index || i > self.end_index {
+                result.push(c);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "ovw"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 3, "ovw", "")?;
+
+        self.start_index = parse_args!(params, 0, Usize, "Index should be of usize type");
+        self.end_index = parse_args!(params, 1, Usize, "Index should be of usize type");
+        self.text = parse_args!(params, 2, String, "Text should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x56
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.start_index),
+            AtpParamTypes::Usize(self.end_index),
+            AtpParamTypes::String(self.text.clone()),
+        ])
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::ovw::Ovw };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_ovw() {
+        let t = Ovw::default();
+        assert_eq!(t.get_string_repr(), "ovw");
+    }
+
+    #[test]
+    fn new_rejects_start_not_smaller_than_end() {
+        let err = Ovw::new(3, 3, "x").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidIndex(_)));
+    }
+
+    #[test]
+    fn transform_with_shorter_replacement() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ovw::new(1, 4, "X").unwrap();
+        assert_eq!(t.transform("abcdefg", &mut ctx), Ok("aXfg".to_string()));
+    }
+
+    #[test]
+    fn transform_with_longer_replacement() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ovw::new(1, 2, "12345").unwrap();
+        assert_eq!(t.transform("abcdefg", &mut ctx), Ok("a12345defg".to_string()));
+    }
+
+    #[test]
+    fn transform_with_empty_replacement_deletes_the_range() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ovw::new(2, 3, "").unwrap();
+        assert_eq!(t.transform("abcdefg", &mut ctx), Ok("abefg".to_string()));
+    }
+
+    #[test]
+    fn transform_up_to_last_char_and_unicode() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ovw::new(4, 6, "ção").unwrap();
+        assert_eq!(t.transform("maçã😀🍌!", &mut ctx), Ok("maçãção".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_out_of_range_end() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ovw::new(1, 10, "x").unwrap();
+        let err = t.transform("abc", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Ovw::new(1, 3, "abc").unwrap();
+        assert_eq!(t.to_atp_line().as_ref(), "ovw 1 3 abc;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Ovw::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x56() {
+            let t = Ovw::default();
+            assert_eq!(t.get_opcode(), 0x56);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Ovw::new(1, 3, "abc").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x56);
+            assert_eq!(bc[12] as usize, 3);
+        }
+    }
+}
@@ -15,7 +15,10 @@ use crate::utils::params::AtpParamTypes;
 
 /// PADL - Pad Left
 ///
-/// Repeats `text` characters until `max_len` is reached, and then insert the result at the beggining of `input`
+/// Repeats `text` until `input` plus the padding has exactly `max_len` characters, cutting the last
+/// repetition of `text` short when needed, and then inserts the padding at the beginning of `input`.
+///
+/// If `input` already has `max_len` characters or more, it is returned unchanged.
 ///
 /// See Also:
 ///
@@ -51,6 +54,9 @@ impl InstructionMethods for Padl {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string(), self.max_len.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "padl"
     }
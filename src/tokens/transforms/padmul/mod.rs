@@ -0,0 +1,119 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// PADMUL - Pad To Multiple
+///
+/// Right-pads `input` with the first character of `fill` until its length (in characters)
+/// is a multiple of `multiple`.
+///
+/// If `multiple` is 0 an `AtpError` is returned, if `fill` is empty `input` is returned unchanged.
+///
+/// See Also:
+///
+/// - [`PADR` - Pad Right](crate::tokens::transforms::padr)
+/// - [`PADL` - Pad Left](crate::tokens::transforms::padl)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::padmul::Padmul};
+///
+/// let token = Padmul::new("0", 4);
+///
+/// assert_eq!(token.transform("banana"), Ok("banana00".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Padmul {
+    pub fill: String,
+    pub multiple: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Padmul {
+    pub fn new(fill: &str, multiple: usize) -> Self {
+        Padmul {
+            fill: fill.to_string(),
+            multiple,
+            params: vec![fill.to_string().into(), multiple.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Padmul {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.fill.to_string(), self.multiple.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("padmul {} {};\n", self.fill, self.multiple).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.multiple == 0 {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::ZeroDivisionError("Multiple should be greater than zero".into()),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        let fill = match self.fill.chars().next() {
+            Some(c) => c,
+            None => {
+                return Ok(input.to_string());
+            }
+        };
+
+        let len = input.chars().count();
+        let missing = (self.multiple - (len % self.multiple)) % self.multiple;
+
+        let mut result = String::with_capacity(input.len() + missing * fill.len_utf8());
+        result.push_str(input);
+        result.extend(std::iter::repeat_n(fill, missing));
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "padmul"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "padmul", "")?;
+
+        self.fill = parse_args!(params, 0, String, "Fill should be of string type");
+        self.multiple = parse_args!(params, 1, Usize, "Multiple should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3c
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.fill.clone()),
+            AtpParamTypes::Usize(self.multiple),
+        ])
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::padmul::Padmul };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_padmul() {
+        let t = Padmul::default();
+        assert_eq!(t.get_string_repr(), "padmul");
+    }
+
+    #[test]
+    fn transform_pads_until_multiple() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Padmul::new("0", 4);
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana00".to_string()));
+    }
+
+    #[test]
+    fn transform_already_aligned_input_is_unchanged() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Padmul::new("-", 3);
+        assert_eq!(t.transform("abcdef", &mut ctx), Ok("abcdef".to_string()));
+    }
+
+    #[test]
+    fn transform_zero_multiple_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Padmul::new("-", 0);
+        let err = t.transform("abc", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::ZeroDivisionError(_)));
+    }
+
+    #[test]
+    fn transform_uses_first_fill_character_and_counts_chars() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Padmul::new("çx", 4);
+        assert_eq!(t.transform("maçã", &mut ctx), Ok("maçã".to_string()));
+        assert_eq!(t.transform("maçãs", &mut ctx), Ok("maçãsççç".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Padmul::new("0", 8);
+        assert_eq!(t.to_atp_line().as_ref(), "padmul 0 8;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_string_and_usize() {
+        let mut t = Padmul::default();
+        t.from_params(&vec![AtpParamTypes::String("*".to_string()), AtpParamTypes::Usize(5)]).unwrap();
+        assert_eq!(t.fill, "*");
+        assert_eq!(t.multiple, 5);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Padmul::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3c() {
+            let t = Padmul::default();
+            assert_eq!(t.get_opcode(), 0x3c);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Padmul::new("0", 4);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3c);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
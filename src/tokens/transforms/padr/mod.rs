@@ -12,7 +12,10 @@ use crate::{
 use crate::utils::params::AtpParamTypes;
 /// PADR - Pad Right
 ///
-/// Repeats `text` characters until `max_len` is reached, and then insert the result at the end of `input`
+/// Repeats `text` until `input` plus the padding has exactly `max_len` characters, cutting the last
+/// repetition of `text` short when needed, and then inserts the padding at the end of `input`.
+///
+/// If `input` already has `max_len` characters or more, it is returned unchanged.
 ///
 /// See Also:
 ///
@@ -48,6 +51,9 @@ impl InstructionMethods for Padr {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.text.to_string(), self.max_len.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "padr"
     }
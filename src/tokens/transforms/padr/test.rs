@@ -48,6 +48,31 @@ mod tests {
         assert_eq!(t.transform("banana", &mut ctx), Ok("bananaxyxy".to_string()));
     }
 
+    #[test]
+    fn transform_multi_char_text_fills_exactly_max_len() {
+        let mut ctx = GlobalExecutionContext::new();
+
+        // "banana" tem 6 chars, as larguras restantes ímpares cortam a última repetição
+        for max_len in [7, 9, 11, 13] {
+            let out = Padr::new("xy", max_len).transform("banana", &mut ctx).unwrap();
+            assert_eq!(out.chars().count(), max_len);
+        }
+
+        assert_eq!(Padr::new("xy", 9).transform("banana", &mut ctx), Ok("bananaxyx".to_string()));
+        assert_eq!(Padr::new("abc", 11).transform("banana", &mut ctx), Ok("bananaabcab".to_string()));
+    }
+
+    #[test]
+    fn transform_counts_multibyte_chars() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Padr::new("áβ", 8);
+
+        let out = t.transform("maçã", &mut ctx).unwrap();
+
+        assert_eq!(out, "maçãáβáβ");
+        assert_eq!(out.chars().count(), 8);
+    }
+
     #[test]
     fn from_params_accepts_text_then_max_len() {
         let mut t = Padr::default();
@@ -0,0 +1,97 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// PRINTABLE - Printable Only
+///
+/// Removes every control character (`char::is_control`) of `input`, such as `\0` or the `ESC`
+/// that starts ANSI escape sequences. The rest of an escape sequence (e.g. `[31m`) is printable
+/// and is kept.
+///
+/// If `keep_newlines` is true, `\n` and `\t` are preserved.
+///
+/// See Also:
+///
+/// - [`ASCIIONLY` - ASCII Only](crate::tokens::transforms::asciionly)
+/// - [`STRIPANSI` - Strip ANSI Escape Codes](crate::tokens::transforms::stripansi)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::printable::Printable};
+///
+/// let token = Printable::new(true);
+///
+/// assert_eq!(token.transform("a\0b\nc"), Ok("ab\nc".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Printable {
+    pub keep_newlines: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Printable {
+    pub fn new(keep_newlines: bool) -> Self {
+        Printable {
+            keep_newlines,
+            params: vec![keep_newlines.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Printable {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.keep_newlines.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("printable {};\n", self.keep_newlines).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(
+            input
+                .chars()
+                .filter(|c| !c.is_control() || (self.keep_newlines && (*c == '\n' || *c == '\t')))
+                .collect()
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "printable"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "printable", "")?;
+
+        self.keep_newlines = parse_args!(params, 0, Bool, "Keep newlines flag should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x52
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Bool(self.keep_newlines),
+        ])
+    }
+}
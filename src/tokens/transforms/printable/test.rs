@@ -0,0 +1,86 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::printable::Printable };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_printable() {
+        let t = Printable::default();
+        assert_eq!(t.get_string_repr(), "printable");
+    }
+
+    #[test]
+    fn transform_strips_null_and_escape_bytes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Printable::new(false);
+        assert_eq!(
+            t.transform("\u{1b}[31mred\u{1b}[0m\0!", &mut ctx),
+            Ok("[31mred[0m!".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_without_keep_newlines_removes_them() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Printable::new(false);
+        assert_eq!(t.transform("a\nb\tc\r", &mut ctx), Ok("abc".to_string()));
+    }
+
+    #[test]
+    fn transform_with_keep_newlines_preserves_newline_and_tab() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Printable::new(true);
+        assert_eq!(t.transform("a\nb\tc\r\u{7}\0", &mut ctx), Ok("a\nb\tc".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_unicode_text() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Printable::new(false);
+        assert_eq!(t.transform("olá 🌍", &mut ctx), Ok("olá 🌍".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Printable::new(true);
+        assert_eq!(t.to_atp_line().as_ref(), "printable true;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Printable::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x52() {
+            let t = Printable::default();
+            assert_eq!(t.get_opcode(), 0x52);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Printable::new(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x52);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use regex::Captures;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, regex_cache::get_or_compile, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// PUNCTSP - Fix Punctuation Spacing
+///
+/// Fixes the spacing around `, . ; : ! ?` in `input`: spaces and tabs before them are removed, and
+/// exactly one space is left between them and the next word. Runs of punctuation like `?!` or `...`
+/// are kept together, line breaks are not touched and numbers like `3.14` or `10:30` are left as
+/// they are.
+///
+/// Punctuation inside URLs (`http://x.y`, `www.x.y`) and dotted abbreviations (`e.g.`, `U.S.A`) is
+/// left alone, so no space is added there.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::punctsp::Punctsp};
+///
+/// let token = Punctsp::default();
+///
+/// assert_eq!(token.transform("hello ,world !Bye"), Ok("hello, world! Bye".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Punctsp {
+    params: Vec<AtpParamTypes>,
+}
+
+/// URLs and dotted abbreviations, the punctuation inside them is left alone.
+const PROTECTED_PATTERN: &str = concat!(
+    r"(?:[a-zA-Z][a-zA-Z0-9+.-]*://|www\.)\S*",
+    "|",
+    r"\b(?:\p{L}\.){2,}"
+);
+
+impl InstructionMethods for Punctsp {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "punctsp;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let before = get_or_compile(r"[ \t]+([,.;:!?])")?;
+        let after = get_or_compile(r"(\d?)([,.;:!?])([ \t]*)([^\s,.;:!?])")?;
+
+        let protected_spans = get_or_compile(PROTECTED_PATTERN)?
+            .find_iter(input)
+            .map(|m| m.range())
+            .collect::<Vec<_>>();
+
+        // Spacing after punctuation runs first, so the spans still match the positions in `input`
+        let spaced = after.replace_all(input, |caps: &Captures| {
+            let inside_number =
+                !caps[1].is_empty() &&
+                caps[3].is_empty() &&
+                caps[4].starts_with(|c: char| c.is_ascii_digit());
+
+            let punctuation = caps.get(2).map_or(0, |m| m.start());
+            let inside_protected =
+                caps[3].is_empty() && protected_spans.iter().any(|r| r.contains(&punctuation));
+
+            if inside_number || inside_protected {
+                caps[0].to_string()
+            } else {
+                format!("{}{} {}", &caps[1], &caps[2], &caps[4])
+            }
+        });
+
+        Ok(before.replace_all(&spaced, "$1").to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "punctsp"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "punctsp", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7c
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::punctsp::Punctsp };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_punctsp() {
+        let t = Punctsp::default();
+        assert_eq!(t.get_string_repr(), "punctsp");
+    }
+
+    #[test]
+    fn transform_removes_spaces_before_punctuation() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        assert_eq!(t.transform("hello ,world", &mut ctx), Ok("hello, world".to_string()));
+        assert_eq!(t.transform("sim \t! e não ?", &mut ctx), Ok("sim! e não?".to_string()));
+    }
+
+    #[test]
+    fn transform_adds_single_space_after_punctuation() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        assert_eq!(t.transform("a,b;c:d", &mut ctx), Ok("a, b; c: d".to_string()));
+        assert_eq!(t.transform("fim.   Começo", &mut ctx), Ok("fim. Começo".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_consecutive_punctuation_together() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        assert_eq!(t.transform("what ?!really", &mut ctx), Ok("what?! really".to_string()));
+        assert_eq!(t.transform("wait ...ok", &mut ctx), Ok("wait... ok".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_numbers_and_line_breaks() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        assert_eq!(
+            t.transform("pi is 3.14 at 10:30,ok", &mut ctx),
+            Ok("pi is 3.14 at 10:30, ok".to_string())
+        );
+        assert_eq!(t.transform("um,\ndois.", &mut ctx), Ok("um,\ndois.".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_urls_and_abbreviations() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        assert_eq!(t.transform("see http://x.y ,ok", &mut ctx), Ok("see http://x.y, ok".into()));
+        assert_eq!(t.transform("go to www.x.y", &mut ctx), Ok("go to www.x.y".to_string()));
+        assert_eq!(t.transform("fruits,e.g. apples", &mut ctx), Ok("fruits, e.g. apples".into()));
+        assert_eq!(t.transform("(e.g.,pears)", &mut ctx), Ok("(e.g., pears)".to_string()));
+        assert_eq!(t.transform("the U.S.A.", &mut ctx), Ok("the U.S.A.".to_string()));
+    }
+
+    #[test]
+    fn transform_is_idempotent() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Punctsp::default();
+        let once = t.transform("hello ,world !How are you ?Fine...thanks", &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(once.clone()));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Punctsp::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7c() {
+            let t = Punctsp::default();
+            assert_eq!(t.get_opcode(), 0x7c);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Punctsp::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7c);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
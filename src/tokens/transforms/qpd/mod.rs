@@ -0,0 +1,122 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// QPD - Quoted Printable Decode
+///
+/// Decodes quoted-printable (RFC 2045) `input`, turning `=XX` back into bytes and removing soft line breaks
+/// (`=` at the end of a line). Hex digits may be upper or lower case.
+///
+/// It will throw an `AtpError` if an `=` is not followed by two hex digits or a line break, or if the decoded
+/// bytes are not valid UTF-8.
+///
+/// See Also:
+///
+/// - [`QPE` - Quoted Printable Encode](crate::tokens::transforms::qpe)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::qpd::Qpd};
+///
+/// let token = Qpd::default();
+///
+/// assert_eq!(token.transform("ma=C3=A7=C3=A3 =3D fr=\r\nuta"), Ok("maçã = fruta".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Qpd {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Qpd {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "qpd;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let malformed = |message: &'static str| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError(message.into()),
+                self.to_atp_line(),
+                input.to_string()
+            )
+        };
+
+        let bytes = input.as_bytes();
+        let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != b'=' {
+                decoded.push(bytes[i]);
+                i += 1;
+                continue;
+            }
+
+            // Soft line break, transport padding between "=" and the line break is allowed
+            let mut j = i + 1;
+            while j < bytes.len() && (bytes[j] == b' ' || bytes[j] == b'\t') {
+                j += 1;
+            }
+            match (bytes.get(j), bytes.get(j + 1)) {
+                (None, _) => {
+                    i = j;
+                    continue;
+                }
+                (Some(b'\n'), _) => {
+                    i = j + 1;
+                    continue;
+                }
+                (Some(b'\r'), Some(b'\n')) => {
+                    i = j + 2;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or_else(|| malformed("Malformed quoted-printable escape sequence"))?;
+
+            decoded.push(hex);
+            i += 3;
+        }
+
+        String::from_utf8(decoded).map_err(|_| malformed("Decoded quoted-printable is not valid UTF-8"))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "qpd"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "qpd", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x6e
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::qpd::Qpd };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_qpd() {
+        let t = Qpd::default();
+        assert_eq!(t.get_string_repr(), "qpd");
+    }
+
+    #[test]
+    fn transform_decodes_escapes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpd::default();
+        assert_eq!(t.transform("caf=C3=A9 =3D =e2=82=ac", &mut ctx), Ok("café = €".to_string()));
+    }
+
+    #[test]
+    fn transform_removes_soft_line_breaks() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpd::default();
+        assert_eq!(t.transform("ban=\r\nana lar=\nanja=  \r\n!=", &mut ctx), Ok("banana laranja!".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_hard_line_breaks() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpd::default();
+        assert_eq!(t.transform("a=20\r\nb\nc", &mut ctx), Ok("a \r\nb\nc".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_malformed_escape() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpd::default();
+
+        for input in ["=G1", "abc=4", "=\r", "a=Z"] {
+            let err = t.transform(input, &mut ctx).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)), "{}", input);
+        }
+    }
+
+    #[test]
+    fn transform_rejects_invalid_utf8() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Qpd::default().transform("=FF=FE", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Qpd::default().to_atp_line().as_ref(), "qpd;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Qpd::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x6e() {
+            let t = Qpd::default();
+            assert_eq!(t.get_opcode(), 0x6e);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Qpd::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x6e);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
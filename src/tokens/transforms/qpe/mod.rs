@@ -0,0 +1,100 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// QPE - Quoted Printable Encode
+///
+/// Encodes `input` as quoted-printable (RFC 2045). Bytes outside printable ASCII and `=` become `=XX`,
+/// spaces and tabs right before a line break are encoded too, and lines longer than 76 characters are split
+/// with soft line breaks (`=\r\n`). The line breaks of `input` are kept as they are.
+///
+/// See Also:
+///
+/// - [`QPD` - Quoted Printable Decode](crate::tokens::transforms::qpd)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::qpe::Qpe};
+///
+/// let token = Qpe::default();
+///
+/// assert_eq!(token.transform("maçã = fruta"), Ok("ma=C3=A7=C3=A3 =3D fruta".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Qpe {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Qpe {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "qpe;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut result = String::with_capacity(input.len());
+        let mut line_len = 0;
+        let bytes = input.as_bytes();
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            if byte == b'\n' || (byte == b'\r' && bytes.get(i + 1) == Some(&b'\n')) {
+                result.push(byte as char);
+                line_len = 0;
+                continue;
+            }
+
+            let at_line_end = matches!(bytes.get(i + 1), None | Some(b'\n') | Some(b'\r'));
+            let literal = match byte {
+                b' ' | b'\t' => !at_line_end,
+                b'=' => false,
+                33..=126 => true,
+                _ => false,
+            };
+            let encoded = if literal { (byte as char).to_string() } else { format!("={:02X}", byte) };
+
+            // 75 leaves room for the "=" of the soft line break
+            if line_len + encoded.len() > 75 {
+                result.push_str("=\r\n");
+                line_len = 0;
+            }
+
+            line_len += encoded.len();
+            result.push_str(&encoded);
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "qpe"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "qpe", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x6d
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::qpe::Qpe };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_qpe() {
+        let t = Qpe::default();
+        assert_eq!(t.get_string_repr(), "qpe");
+    }
+
+    #[test]
+    fn transform_keeps_printable_ascii() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpe::default();
+        assert_eq!(t.transform("Banana, laranja & canja!", &mut ctx), Ok("Banana, laranja & canja!".to_string()));
+    }
+
+    #[test]
+    fn transform_encodes_non_ascii_bytes_and_equals() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpe::default();
+        assert_eq!(t.transform("café=€", &mut ctx), Ok("caf=C3=A9=3D=E2=82=AC".to_string()));
+    }
+
+    #[test]
+    fn transform_encodes_whitespace_before_line_breaks() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpe::default();
+        assert_eq!(t.transform("a b \nc\t\r\nd ", &mut ctx), Ok("a b=20\nc=09\r\nd=20".to_string()));
+    }
+
+    #[test]
+    fn transform_inserts_soft_line_breaks() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpe::default();
+        let out = t.transform(&"a".repeat(100), &mut ctx).unwrap();
+
+        assert_eq!(out, format!("{}=\r\n{}", "a".repeat(75), "a".repeat(25)));
+        assert!(out.split("\r\n").all(|line| line.len() <= 76));
+    }
+
+    #[test]
+    fn transform_does_not_split_escape_sequences() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Qpe::default();
+        let out = t.transform(&format!("{}é", "a".repeat(73)), &mut ctx).unwrap();
+
+        assert_eq!(out, format!("{}=\r\n=C3=A9", "a".repeat(73)));
+    }
+
+    #[test]
+    fn transform_roundtrips_with_qpd() {
+        use crate::tokens::transforms::qpd::Qpd;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let input = format!("Olá, mundo! {}\n ação = reação \r\nfim\t", "ç".repeat(40));
+        let encoded = Qpe::default().transform(&input, &mut ctx).unwrap();
+
+        assert_eq!(Qpd::default().transform(&encoded, &mut ctx), Ok(input));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Qpe::default().to_atp_line().as_ref(), "qpe;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Qpe::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x6d() {
+            let t = Qpe::default();
+            assert_eq!(t.get_opcode(), 0x6d);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Qpe::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x6d);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
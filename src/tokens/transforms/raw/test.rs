@@ -127,6 +127,12 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+    #[test]
+    fn get_args_returns_pattern_and_replacement() {
+        let t = Raw::new("a+", "b").unwrap();
+        assert_eq!(t.get_args(), vec!["a+".to_string(), "b".to_string()]);
+    }
+
     // ============================
     // Bytecode-only tests (separados)
     // ============================
@@ -1,7 +1,7 @@
 #[cfg(feature = "test_access")]
 pub mod test;
 
-use std::borrow::Cow;
+use std::{ borrow::Cow, sync::Arc };
 
 use regex::Regex;
 
@@ -9,6 +9,7 @@ use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::errors::{ AtpError, AtpErrorCode };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::regex_cache::get_or_compile;
 use crate::utils::validations::check_vec_len;
 use crate::{ tokens::InstructionMethods };
 
@@ -35,7 +36,7 @@ use crate::{ tokens::InstructionMethods };
 ///
 #[derive(Clone, Debug)]
 pub struct Rcw {
-    pub pattern: Regex,
+    pub pattern: Arc<Regex>,
     pub count: usize,
     pub text_to_replace: String,
     params: Vec<AtpParamTypes>,
@@ -43,7 +44,7 @@ pub struct Rcw {
 
 impl Rcw {
     pub fn new(pattern: &str, text_to_replace: &str, count: usize) -> Result<Self, String> {
-        let pattern = Regex::new(&pattern).map_err(|x| x.to_string())?;
+        let pattern = get_or_compile(pattern).map_err(|e| e.error_code.message().to_string())?;
         Ok(Rcw {
             text_to_replace: text_to_replace.to_string(),
             params: vec![
@@ -60,7 +61,7 @@ impl Rcw {
 impl Default for Rcw {
     fn default() -> Self {
         Rcw {
-            pattern: Regex::new("").unwrap(),
+            pattern: Arc::new(Regex::new("").unwrap()),
             text_to_replace: "_".to_string(),
             count: 0 as usize,
             params: vec!["".to_string().into(), "".to_string().into(), (0).into()],
@@ -72,6 +73,9 @@ impl InstructionMethods for Rcw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string(), self.text_to_replace.to_string(), self.count.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("rcw {} {} {};\n", self.pattern, self.text_to_replace, self.count).into()
     }
@@ -93,7 +97,7 @@ impl InstructionMethods for Rcw {
 
         let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
 
-        self.pattern = Regex::new(&pattern_payload.clone()).map_err(|_| {
+        self.pattern = get_or_compile(&pattern_payload).map_err(|_| {
             AtpError::new(
                 AtpErrorCode::TextParsingError("Failed to create regex".into()),
                 "sslt",
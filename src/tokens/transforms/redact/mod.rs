@@ -0,0 +1,139 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::{ borrow::Cow, sync::Arc };
+
+use regex::Regex;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::{ AtpError, AtpErrorCode },
+        regex_cache::get_or_compile,
+        validations::check_vec_len,
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// REDACT - Redact Matches
+///
+/// Replaces every match of `pattern` in `input` with `mask_char` repeated once for each character
+/// of the match, so the redacted text keeps its visible length. A `mask_char` that isn't exactly one
+/// character returns an `AtpError`.
+///
+/// See Also:
+///
+/// - [`RAW` - Replace All With](crate::tokens::transforms::raw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::redact::Redact};
+///
+/// let token = Redact::new(r"\d", "#").unwrap();
+///
+/// assert_eq!(token.transform("card 4321, cvv 987"), Ok("card ####, cvv ###".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Redact {
+    pub pattern: Arc<Regex>,
+    pub mask_char: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Redact {
+    pub fn new(pattern: &str, mask_char: &str) -> Result<Self, AtpError> {
+        check_mask_char(mask_char)?;
+
+        Ok(Redact {
+            pattern: get_or_compile(pattern)?,
+            mask_char: mask_char.to_string(),
+            params: vec![pattern.to_string().into(), mask_char.to_string().into()],
+        })
+    }
+}
+
+fn check_mask_char(mask_char: &str) -> Result<(), AtpError> {
+    if mask_char.chars().count() != 1 {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Mask char must be a single character".into()),
+                "redact",
+                mask_char.to_string()
+            )
+        );
+    }
+    Ok(())
+}
+
+impl Default for Redact {
+    fn default() -> Self {
+        Redact {
+            pattern: Arc::new(Regex::new("").unwrap()),
+            mask_char: "*".to_string(),
+            params: vec!["".to_string().into(), "*".to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Redact {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string(), self.mask_char.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("redact {} {};\n", self.pattern, self.mask_char).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(
+            self.pattern
+                .replace_all(input, |caps: &regex::Captures| {
+                    self.mask_char.repeat(caps[0].chars().count())
+                })
+                .to_string()
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "redact"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "redact", "")?;
+
+        let pattern: String = parse_args!(params, 0, String, "Pattern should be of string type");
+        let mask_char: String = parse_args!(
+            params,
+            1,
+            String,
+            "Mask char should be of string type"
+        );
+        check_mask_char(&mask_char)?;
+
+        self.pattern = get_or_compile(&pattern)?;
+        self.mask_char = mask_char;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7a
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.pattern.to_string()),
+            AtpParamTypes::String(self.mask_char.clone()),
+        ])
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::redact::Redact };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_redact() {
+        let t = Redact::default();
+        assert_eq!(t.get_string_repr(), "redact");
+    }
+
+    #[test]
+    fn transform_redacts_email_like_matches() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Redact::new(r"[\w.+-]+@[\w-]+\.[\w.]+", "*").unwrap();
+
+        assert_eq!(
+            t.transform("contato: ana.silva@example.com, ou bob@mail.org", &mut ctx),
+            Ok("contato: *********************, ou ************".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_preserves_length_of_multibyte_matches() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Redact::new(r"São Paulo|José", "█").unwrap();
+        let input = "José mora em São Paulo";
+
+        let output = t.transform(input, &mut ctx).unwrap();
+
+        assert_eq!(output, "████ mora em █████████");
+        assert_eq!(output.chars().count(), input.chars().count());
+    }
+
+    #[test]
+    fn new_rejects_invalid_pattern_and_empty_mask() {
+        let err = Redact::new("(", "*").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+
+        let err = Redact::new(r"\d", "").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn multi_char_mask_is_rejected() {
+        let err = Redact::new(r"\d", "xy").err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+
+        let mut t = Redact::default();
+        let err = t
+            .from_params(
+                &vec![AtpParamTypes::String(r"\d".to_string()), AtpParamTypes::String("xy".to_string())]
+            )
+            .unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_sets_pattern_and_mask() {
+        let mut ctx = GlobalExecutionContext::new();
+        let mut t = Redact::default();
+
+        t.from_params(
+            &vec![AtpParamTypes::String(r"\d".to_string()), AtpParamTypes::String("#".to_string())]
+        ).unwrap();
+
+        assert_eq!(t.transform("a1b22", &mut ctx), Ok("a#b##".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Redact::new(r"\d+", "#").unwrap();
+        assert_eq!(t.to_atp_line().as_ref(), "redact \\d+ #;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Redact::default();
+        let params = vec![
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1)
+        ];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7a() {
+            let t = Redact::default();
+            assert_eq!(t.get_opcode(), 0x7a);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Redact::new(r"\d+", "#").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7a);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
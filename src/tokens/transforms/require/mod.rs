@@ -0,0 +1,121 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use regex::Regex;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// REQUIRE - Require Match
+///
+/// Passes `input` through unchanged if it matches `pattern`, otherwise fails with `InvalidParameters`.
+///
+/// Useful to assert an invariant in the middle of a pipeline, before the tokens that depend on it.
+///
+/// See Also:
+///
+/// - [`FINDIDX` - Find Index](crate::tokens::transforms::findidx)
+/// - [`LIMIT` - Limit Length](crate::tokens::transforms::limit)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::require::Require};
+///
+/// let token = Require::new(r"^\d+$").unwrap();
+///
+/// assert_eq!(token.transform("2024"), Ok("2024".to_string()));
+/// assert!(token.transform("banana").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Require {
+    pub pattern: Regex,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Require {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let pattern = Regex::new(pattern).map_err(|x| x.to_string())?;
+        Ok(Require {
+            params: vec![pattern.to_string().into()],
+            pattern,
+        })
+    }
+}
+
+impl Default for Require {
+    fn default() -> Self {
+        Require {
+            pattern: Regex::new("").unwrap(),
+            params: vec!["".to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Require {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("require {};\n", self.pattern).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if !self.pattern.is_match(input) {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters(
+                        format!("Input does not match the required pattern `{}`", self.pattern).into()
+                    ),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "require"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "require", "")?;
+
+        let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
+
+        self.pattern = Regex::new(&pattern_payload).map_err(|_| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError("Failed to create regex".into()),
+                "require",
+                pattern_payload.clone()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x58
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [AtpParamTypes::String(self.pattern.to_string())])
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::require::Require };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_require() {
+        let t = Require::default();
+        assert_eq!(t.get_string_repr(), "require");
+    }
+
+    #[test]
+    fn new_rejects_invalid_regex() {
+        let err = Require::new("(").unwrap_err();
+        assert!(!err.is_empty());
+    }
+
+    #[test]
+    fn transform_passes_matching_input_unchanged() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Require::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+        assert_eq!(t.transform("2024-01-31", &mut ctx), Ok("2024-01-31".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_non_matching_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Require::new(r"^\d+$").unwrap();
+
+        let err = t.transform("banana", &mut ctx).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        assert!(err.error_code.message().contains(r"^\d+$"));
+        assert_eq!(err.input, "banana");
+    }
+
+    #[test]
+    fn transform_matches_anywhere_without_anchors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Require::new("nan").unwrap();
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Require::new("a+").unwrap();
+        assert_eq!(t.to_atp_line().as_ref(), "require a+;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_invalid_regex() {
+        let mut t = Require::default();
+        let err = t.from_params(&vec![AtpParamTypes::String("(".to_string())]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Require::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x58() {
+            let t = Require::default();
+            assert_eq!(t.get_opcode(), 0x58);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Require::new("a+").unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x58);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -3,6 +3,8 @@ pub mod test;
 
 use std::borrow::Cow;
 
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::params::AtpParamTypes;
 
@@ -13,6 +15,9 @@ use crate::{ tokens::InstructionMethods, utils::errors::{ AtpError } };
 ///
 /// Reverses `input` character order
 ///
+/// Grapheme clusters (e.g. a letter followed by combining accents, or ZWJ emoji sequences)
+/// are kept intact, only their order is reversed.
+///
 /// # Example:
 ///
 /// ```rust
@@ -38,7 +43,7 @@ impl InstructionMethods for Rev {
     }
 
     fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        Ok(input.chars().rev().collect())
+        Ok(input.graphemes(true).rev().collect())
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
         check_vec_len(&params, 0, "rev", "")?;
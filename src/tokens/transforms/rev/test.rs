@@ -45,13 +45,33 @@ mod tests {
 
     #[test]
     fn transform_unicode_safe() {
-        // chars() => reversão por scalar values (não por byte)
+        // graphemes => reversão por cluster (não por byte)
         let t = Rev::default();
         let mut ctx = GlobalExecutionContext::new();
 
         assert_eq!(t.transform("áβç", &mut ctx).unwrap(), "çβá");
     }
 
+    #[test]
+    fn transform_keeps_combining_accents_with_their_base() {
+        let t = Rev::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        // "e" + U+0301 COMBINING ACUTE ACCENT must stay a single "é" cluster
+        assert_eq!(t.transform("abe\u{0301}", &mut ctx).unwrap(), "e\u{0301}ba");
+    }
+
+    #[test]
+    fn transform_keeps_zwj_emoji_sequences_intact() {
+        let t = Rev::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let input = format!("a{}b", family);
+
+        assert_eq!(t.transform(&input, &mut ctx).unwrap(), format!("b{}a", family));
+    }
+
     #[test]
     fn from_params_accepts_empty() {
         let mut t = Rev::default();
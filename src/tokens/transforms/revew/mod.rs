@@ -0,0 +1,77 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// REVEW - Reverse Each Word
+///
+/// Reverses the characters of every word in `input` while keeping the word order.
+///
+/// Words are joined back with a single space, so leading, trailing and repeated whitespace is collapsed.
+///
+/// See Also:
+///
+/// - [`REV` - Reverse](crate::tokens::transforms::rev)
+/// - [`SWPW` - Swap Words](crate::tokens::transforms::swpw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::revew::Revew};
+///
+/// let token = Revew::default();
+///
+/// assert_eq!(token.transform("abc def"), Ok("cba fed".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Revew {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Revew {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "revew;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(
+            input
+                .split_whitespace()
+                .map(|w| w.chars().rev().collect::<String>())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "revew"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "revew", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3e
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
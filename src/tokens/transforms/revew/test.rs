@@ -0,0 +1,76 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::revew::Revew };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_revew() {
+        let t = Revew::default();
+        assert_eq!(t.get_string_repr(), "revew");
+    }
+
+    #[test]
+    fn transform_reverses_each_word_in_place() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revew::default();
+        assert_eq!(t.transform("abc def", &mut ctx), Ok("cba fed".to_string()));
+    }
+
+    #[test]
+    fn transform_handles_multibyte_words() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revew::default();
+        assert_eq!(t.transform("maçã coração", &mut ctx), Ok("ãçam oãçaroc".to_string()));
+    }
+
+    #[test]
+    fn transform_collapses_surrounding_whitespace() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revew::default();
+        assert_eq!(t.transform("  abc   def \n", &mut ctx), Ok("cba fed".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Revew::default();
+        assert_eq!(t.to_atp_line().as_ref(), "revew;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Revew::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3e() {
+            let t = Revew::default();
+            assert_eq!(t.get_opcode(), 0x3e);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Revew::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3e);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// REVL - Reverse Lines
+///
+/// Reverses the order of the lines of `input`. A trailing newline stays at the end instead of
+/// becoming an empty first line.
+///
+/// See Also:
+///
+/// - [`head` - Head](crate::tokens::transforms::head)
+/// - [`tail` - Tail](crate::tokens::transforms::tail)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::revl::Revl};
+///
+/// let token = Revl::default();
+///
+/// assert_eq!(token.transform("banana\nlaranja\n"), Ok("laranja\nbanana\n".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Revl {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Revl {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "revl;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let (body, trailing) = match input.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (input, ""),
+        };
+
+        let mut lines: Vec<&str> = body.split('\n').collect();
+        lines.reverse();
+
+        Ok(format!("{}{}", lines.join("\n"), trailing))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "revl"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "revl", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x82
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
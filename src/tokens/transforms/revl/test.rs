@@ -0,0 +1,87 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::revl::Revl };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_revl() {
+        let t = Revl::default();
+        assert_eq!(t.get_string_repr(), "revl");
+    }
+
+    #[test]
+    fn transform_reverses_two_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revl::default();
+        assert_eq!(t.transform("banana\nlaranja", &mut ctx), Ok("laranja\nbanana".to_string()));
+    }
+
+    #[test]
+    fn transform_preserves_trailing_newline() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revl::default();
+        assert_eq!(t.transform("a\nb\nc\n", &mut ctx), Ok("c\nb\na\n".to_string()));
+        assert_eq!(t.transform("a\n\nb\n\n", &mut ctx), Ok("\nb\n\na\n".to_string()));
+    }
+
+    #[test]
+    fn transform_single_line_and_empty_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revl::default();
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+        assert_eq!(t.transform("\n", &mut ctx), Ok("\n".to_string()));
+    }
+
+    #[test]
+    fn transform_twice_is_identity() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Revl::default();
+        let input = "a\nb\n\nc\n";
+        let once = t.transform(input, &mut ctx).unwrap();
+        assert_eq!(t.transform(&once, &mut ctx), Ok(input.to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Revl::default().to_atp_line().as_ref(), "revl;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Revl::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x82() {
+            let t = Revl::default();
+            assert_eq!(t.get_opcode(), 0x82);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Revl::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x82);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -1,14 +1,18 @@
 #[cfg(feature = "test_access")]
 pub mod test;
 
-use std::borrow::Cow;
+use std::{ borrow::Cow, sync::Arc };
 
 use regex::Regex;
 
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+    utils::{
+        errors::{ AtpError, AtpErrorCode },
+        regex_cache::get_or_compile,
+        validations::check_vec_len,
+    },
 };
 
 use crate::utils::params::AtpParamTypes;
@@ -35,14 +39,14 @@ use crate::utils::params::AtpParamTypes;
 ///
 #[derive(Clone, Debug)]
 pub struct Rlw {
-    pub pattern: Regex,
+    pub pattern: Arc<Regex>,
     pub text_to_replace: String,
     params: Vec<AtpParamTypes>,
 }
 
 impl Rlw {
     pub fn new(pattern: &str, text_to_replace: &str) -> Result<Self, String> {
-        let pattern = Regex::new(&pattern).map_err(|x| x.to_string())?;
+        let pattern = get_or_compile(pattern).map_err(|e| e.error_code.message().to_string())?;
         Ok(Rlw {
             text_to_replace: text_to_replace.to_string(),
             params: vec![pattern.to_string().into(), text_to_replace.to_string().into()],
@@ -54,7 +58,7 @@ impl Rlw {
 impl Default for Rlw {
     fn default() -> Self {
         Rlw {
-            pattern: Regex::new("").unwrap(),
+            pattern: Arc::new(Regex::new("").unwrap()),
             text_to_replace: "_".to_string(),
             params: vec!["".to_string().into(), "_".to_string().into()],
         }
@@ -65,6 +69,9 @@ impl InstructionMethods for Rlw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string(), self.text_to_replace.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("rlw {} {};\n", self.pattern, self.text_to_replace).into()
     }
@@ -96,7 +103,7 @@ impl InstructionMethods for Rlw {
 
         let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
 
-        self.pattern = Regex::new(&pattern_payload.clone()).map_err(|_| {
+        self.pattern = get_or_compile(&pattern_payload).map_err(|_| {
             AtpError::new(
                 AtpErrorCode::TextParsingError("Failed to create regex".into()),
                 "sslt",
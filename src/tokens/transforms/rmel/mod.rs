@@ -0,0 +1,106 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// RMEL - Remove Empty Lines
+///
+/// Removes the empty lines of `input`, lines are separated by `\n` and a trailing `\r` is ignored.
+///
+/// Lines with only whitespace are also removed, unless `keep_whitespace_lines` is true.
+/// A trailing newline at the end of `input` is preserved when some line is left.
+///
+/// See Also:
+///
+/// - [`LNFIX` - Line Prefix/Suffix](crate::tokens::transforms::lnfix)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::rmel::Rmel};
+///
+/// let token = Rmel::new(false);
+///
+/// assert_eq!(token.transform("banana\n\n   \nlaranja"), Ok("banana\nlaranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rmel {
+    pub keep_whitespace_lines: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Rmel {
+    pub fn new(keep_whitespace_lines: bool) -> Self {
+        Rmel {
+            keep_whitespace_lines,
+            params: vec![keep_whitespace_lines.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Rmel {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.keep_whitespace_lines.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("rmel {};\n", self.keep_whitespace_lines).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let body = input.strip_suffix('\n');
+
+        let mut result = body
+            .unwrap_or(input)
+            .split('\n')
+            .filter(|line| {
+                let text = line.strip_suffix('\r').unwrap_or(line);
+                if self.keep_whitespace_lines { !text.is_empty() } else { !text.trim().is_empty() }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() && !result.is_empty() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "rmel"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "rmel", "")?;
+
+        self.keep_whitespace_lines = parse_args!(params, 0, Bool, "Keep whitespace lines flag should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5a
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Bool(self.keep_whitespace_lines),
+        ])
+    }
+}
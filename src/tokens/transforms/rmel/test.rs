@@ -0,0 +1,115 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::rmel::Rmel };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_rmel() {
+        let t = Rmel::default();
+        assert_eq!(t.get_string_repr(), "rmel");
+    }
+
+    #[test]
+    fn transform_removes_empty_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rmel::new(false);
+        assert_eq!(t.transform("\nbanana\n\n\nlaranja\n\n", &mut ctx), Ok("banana\nlaranja\n".to_string()));
+    }
+
+    #[test]
+    fn transform_removes_whitespace_lines_by_default() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rmel::new(false);
+        assert_eq!(t.transform("banana\n  \t \nlaranja", &mut ctx), Ok("banana\nlaranja".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_whitespace_lines_when_asked() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rmel::new(true);
+        assert_eq!(
+            t.transform("banana\n  \t \n\nlaranja", &mut ctx),
+            Ok("banana\n  \t \nlaranja".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_preserves_content_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rmel::new(false);
+        let input = "  banana\nlaranja  \n cheia de canja";
+        assert_eq!(t.transform(input, &mut ctx), Ok(input.to_string()));
+    }
+
+    #[test]
+    fn transform_handles_crlf() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(
+            Rmel::new(true).transform("banana\r\n\r\nlaranja\r\n", &mut ctx),
+            Ok("banana\r\nlaranja\r\n".to_string())
+        );
+        assert_eq!(
+            Rmel::new(false).transform("banana\r\n \r\nlaranja", &mut ctx),
+            Ok("banana\r\nlaranja".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_only_blank_lines_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rmel::new(false);
+        assert_eq!(t.transform("\n \n\n", &mut ctx), Ok("".to_string()));
+        assert_eq!(t.transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Rmel::new(false).to_atp_line().as_ref(), "rmel false;\n");
+        assert_eq!(Rmel::new(true).to_atp_line().as_ref(), "rmel true;\n");
+    }
+
+    #[test]
+    fn from_params_reads_flag() {
+        let mut t = Rmel::default();
+        t.from_params(&vec![AtpParamTypes::Bool(true)]).unwrap();
+        assert!(t.keep_whitespace_lines);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Rmel::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5a() {
+            let t = Rmel::default();
+            assert_eq!(t.get_opcode(), 0x5a);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Rmel::new(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5a);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(feature = "test_access")]
 pub mod test;
 
-use std::borrow::Cow;
+use std::{ borrow::Cow, sync::Arc };
 
 use crate::{
     context::execution_context::GlobalExecutionContext,
@@ -11,13 +11,20 @@ use crate::{
 
 use regex::Regex;
 
-use crate::{ tokens::InstructionMethods, utils::{ errors::{ AtpError, AtpErrorCode } } };
+use crate::{
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, regex_cache::get_or_compile },
+};
 
 use crate::utils::params::AtpParamTypes;
 /// RLW - Replace Last With
 ///
 /// Replace the `nth`` ocurrency of `pattern` in `input` with `text_to_replace`
 ///
+/// `index` counts matches rather than chars or words, so the processor's
+/// [`IndexPolicy`](crate::utils::validations::IndexPolicy) does not apply: with fewer matches than
+/// `index`, `input` is returned unchanged.
+///
 /// See Also:
 ///
 /// - [`RAW` - Replace All With](crate::tokens::transforms::raw)
@@ -37,7 +44,7 @@ use crate::utils::params::AtpParamTypes;
 ///
 #[derive(Clone, Debug)]
 pub struct Rnw {
-    pub pattern: Regex,
+    pub pattern: Arc<Regex>,
     pub text_to_replace: String,
     pub index: usize,
     params: Vec<AtpParamTypes>,
@@ -45,7 +52,7 @@ pub struct Rnw {
 
 impl Rnw {
     pub fn new(pattern: &str, text_to_replace: &str, index: usize) -> Result<Self, String> {
-        let pattern = Regex::new(&pattern).map_err(|x| x.to_string())?;
+        let pattern = get_or_compile(pattern).map_err(|e| e.error_code.message().to_string())?;
         Ok(Rnw {
             text_to_replace: text_to_replace.to_string(),
             params: vec![
@@ -62,7 +69,7 @@ impl Rnw {
 impl Default for Rnw {
     fn default() -> Self {
         Rnw {
-            pattern: Regex::new("").unwrap(),
+            pattern: Arc::new(Regex::new("").unwrap()),
             text_to_replace: "_".to_string(),
             index: 0,
             params: vec!["".to_string().into(), "_".to_string().into(), (0).into()],
@@ -74,6 +81,9 @@ impl InstructionMethods for Rnw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string(), self.text_to_replace.to_string(), self.index.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("rnw {} {} {};\n", self.pattern, self.text_to_replace, self.index).into()
     }
@@ -110,7 +120,7 @@ impl InstructionMethods for Rnw {
 
         let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
 
-        self.pattern = Regex::new(&pattern_payload.clone()).map_err(|_| {
+        self.pattern = get_or_compile(&pattern_payload).map_err(|_| {
             AtpError::new(
                 AtpErrorCode::TextParsingError("Failed to create regex".into()),
                 "sslt",
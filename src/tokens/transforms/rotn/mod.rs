@@ -0,0 +1,96 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// ROTN - Rotate Digits
+///
+/// Rotates every ASCII digit of `input` by `shift`, wrapping around (`d` becomes `(d + shift) % 10`).
+/// Every other character is left unchanged. A `shift` of 5 is its own inverse.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::rotn::Rotn};
+///
+/// let token = Rotn::new(5);
+///
+/// assert_eq!(token.transform("pedido 1298"), Ok("pedido 6743".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rotn {
+    pub shift: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Rotn {
+    pub fn new(shift: usize) -> Self {
+        Rotn {
+            shift,
+            params: vec![shift.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Rotn {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.shift.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("rotn {};\n", self.shift).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let shift = (self.shift % 10) as u32;
+
+        Ok(
+            input
+                .chars()
+                .map(|c| {
+                    match c.to_digit(10) {
+                        Some(d) => char::from_digit((d + shift) % 10, 10).unwrap_or(c),
+                        _ => c,
+                    }
+                })
+                .collect()
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "rotn"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "rotn", "")?;
+
+        self.shift = parse_args!(params, 0, Usize, "Shift should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x6f
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.shift),
+        ])
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::rotn::Rotn };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_rotn() {
+        let t = Rotn::default();
+        assert_eq!(t.get_string_repr(), "rotn");
+    }
+
+    #[test]
+    fn transform_shift_5_is_its_own_inverse() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rotn::new(5);
+        let once = t.transform("0123456789", &mut ctx).unwrap();
+
+        assert_eq!(once, "5678901234");
+        assert_eq!(t.transform(&once, &mut ctx), Ok("0123456789".to_string()));
+    }
+
+    #[test]
+    fn transform_leaves_non_digits_alone() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rotn::new(3);
+        assert_eq!(t.transform("abc-7x9 ½ ٣", &mut ctx), Ok("abc-0x2 ½ ٣".to_string()));
+    }
+
+    #[test]
+    fn transform_wraps_large_shifts() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Rotn::new(13).transform("789", &mut ctx), Ok("012".to_string()));
+        assert_eq!(Rotn::new(10).transform("42", &mut ctx), Ok("42".to_string()));
+        assert_eq!(Rotn::new(usize::MAX).transform("0", &mut ctx), Ok("5".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Rotn::new(5).to_atp_line().as_ref(), "rotn 5;\n");
+    }
+
+    #[test]
+    fn from_params_reads_shift() {
+        let mut t = Rotn::default();
+        t.from_params(&vec![AtpParamTypes::Usize(7)]).unwrap();
+        assert_eq!(t.shift, 7);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Rotn::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x6f() {
+            let t = Rotn::default();
+            assert_eq!(t.get_opcode(), 0x6f);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Rotn::new(5);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x6f);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
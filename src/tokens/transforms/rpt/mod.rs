@@ -41,6 +41,9 @@ impl InstructionMethods for Rpt {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.times.to_string()]
+    }
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("rpt {};\n", self.times).into()
     }
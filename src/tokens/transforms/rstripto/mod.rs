@@ -0,0 +1,93 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// RSTRIPTO - Right Strip To
+///
+/// Removes everything from the last occurrence of `ch` in `input` onward, including `ch` itself.
+///
+/// If `ch` is not found, `input` is returned unchanged.
+///
+/// See Also:
+///
+/// - [`LSTRIPTO` - Left Strip To](crate::tokens::transforms::lstripto)
+/// - [`DLA` - Delete After](crate::tokens::transforms::dla)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::rstripto::Rstripto};
+///
+/// let token = Rstripto::new(".");
+///
+/// assert_eq!(token.transform("archive.tar.gz"), Ok("archive.tar".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rstripto {
+    pub ch: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Rstripto {
+    pub fn new(ch: &str) -> Self {
+        Rstripto {
+            ch: ch.to_string(),
+            params: vec![ch.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Rstripto {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.ch.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("rstripto {};\n", self.ch).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        match input.rfind(&self.ch) {
+            Some(i) if !self.ch.is_empty() => Ok(input[..i].to_string()),
+            _ => Ok(input.to_string()),
+        }
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "rstripto"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "rstripto", "")?;
+
+        self.ch = parse_args!(params, 0, String, "Character should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x39
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.ch.clone()),
+        ])
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::rstripto::Rstripto };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_rstripto() {
+        let t = Rstripto::default();
+        assert_eq!(t.get_string_repr(), "rstripto");
+    }
+
+    #[test]
+    fn transform_drops_from_last_occurrence() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rstripto::new(".");
+        assert_eq!(t.transform("archive.tar.gz", &mut ctx), Ok("archive.tar".to_string()));
+    }
+
+    #[test]
+    fn transform_absent_character_keeps_input() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rstripto::new("#");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_character_at_the_start_yields_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rstripto::new("/");
+        assert_eq!(t.transform("/banana", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_handles_multibyte_characters() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rstripto::new("ç");
+        assert_eq!(t.transform("açaíçã", &mut ctx), Ok("açaí".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Rstripto::new(".");
+        assert_eq!(t.to_atp_line().as_ref(), "rstripto .;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_single_string() {
+        let mut t = Rstripto::default();
+        t.from_params(&vec![AtpParamTypes::String("-".to_string())]).unwrap();
+        assert_eq!(t.ch, "-");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Rstripto::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x39() {
+            let t = Rstripto::default();
+            assert_eq!(t.get_opcode(), 0x39);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Rstripto::new(".");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x39);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -40,6 +40,9 @@ impl InstructionMethods for Rtl {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.times.to_string()]
+    }
     fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
         if input.is_empty() {
             return Err(
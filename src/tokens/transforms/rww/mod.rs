@@ -0,0 +1,93 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use regex::NoExpand;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, regex_cache::get_or_compile, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// RWW - Replace Whitespace With
+///
+/// Replaces every run of whitespace in `input` (spaces, tabs, newlines...) with a single `replacement`.
+///
+/// `replacement` is inserted literally, `$` has no special meaning.
+///
+/// See Also:
+///
+/// - [`RMWS` - Remove Whitespace](crate::tokens::transforms::rmws)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::rww::Rww};
+///
+/// let token = Rww::new("_");
+///
+/// assert_eq!(token.transform("banana  laranja\tcheia\nde canja"), Ok("banana_laranja_cheia_de_canja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rww {
+    pub replacement: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Rww {
+    pub fn new(replacement: &str) -> Self {
+        Rww {
+            replacement: replacement.to_string(),
+            params: vec![replacement.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Rww {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.replacement.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("rww {};\n", self.replacement).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let whitespace = get_or_compile(r"\s+")?;
+
+        Ok(whitespace.replace_all(input, NoExpand(&self.replacement)).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "rww"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "rww", "")?;
+
+        self.replacement = parse_args!(params, 0, String, "Replacement should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5f
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.replacement.clone()),
+        ])
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::rww::Rww };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_rww() {
+        let t = Rww::default();
+        assert_eq!(t.get_string_repr(), "rww");
+    }
+
+    #[test]
+    fn transform_collapses_multiple_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rww::new("_");
+        assert_eq!(t.transform("banana     laranja", &mut ctx), Ok("banana_laranja".to_string()));
+    }
+
+    #[test]
+    fn transform_replaces_tabs_and_newlines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rww::new("%20");
+        assert_eq!(
+            t.transform("banana\tlaranja\ncheia \r\n\t de", &mut ctx),
+            Ok("banana%20laranja%20cheia%20de".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_keeps_leading_and_trailing_runs() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rww::new("-");
+        assert_eq!(t.transform("  a b \n", &mut ctx), Ok("-a-b-".to_string()));
+    }
+
+    #[test]
+    fn transform_replacement_is_literal() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rww::new("$0");
+        assert_eq!(t.transform("a b", &mut ctx), Ok("a$0b".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_replacement_removes_whitespace() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Rww::new("");
+        assert_eq!(t.transform(" a \t b ", &mut ctx), Ok("ab".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Rww::new("_").to_atp_line().as_ref(), "rww _;\n");
+    }
+
+    #[test]
+    fn from_params_reads_replacement() {
+        let mut t = Rww::default();
+        t.from_params(&vec![AtpParamTypes::String("+".to_string())]).unwrap();
+        assert_eq!(t.replacement, "+");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Rww::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5f() {
+            let t = Rww::default();
+            assert_eq!(t.get_opcode(), 0x5f);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Rww::new("_");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5f);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
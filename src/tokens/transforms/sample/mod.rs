@@ -0,0 +1,119 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SAMPLE - Sample Lines
+///
+/// Keeps every `step`th line of `input`, starting at the line of index `offset` (0-based), so the kept lines are
+/// the ones where `(index - offset) % step == 0`. Lines before `offset` are dropped.
+///
+/// A trailing newline at the end of `input` is preserved when some line is kept. If `step` is 0 an `AtpError` is returned.
+///
+/// See Also:
+///
+/// - [`HEAD` - Head](crate::tokens::transforms::head)
+/// - [`TAIL` - Tail](crate::tokens::transforms::tail)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::sample::Sample};
+///
+/// let token = Sample::new(2, 0);
+///
+/// assert_eq!(token.transform("l0\nl1\nl2\nl3\nl4"), Ok("l0\nl2\nl4".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Sample {
+    pub step: usize,
+    pub offset: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Sample {
+    pub fn new(step: usize, offset: usize) -> Self {
+        Sample {
+            step,
+            offset,
+            params: vec![step.into(), offset.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Sample {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.step.to_string(), self.offset.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("sample {} {};\n", self.step, self.offset).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.step == 0 {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters("Step should be greater than zero".into()),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        let body = input.strip_suffix('\n');
+
+        let mut result = body
+            .unwrap_or(input)
+            .split('\n')
+            .skip(self.offset)
+            .step_by(self.step)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() && !result.is_empty() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "sample"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "sample", "")?;
+
+        self.step = parse_args!(params, 0, Usize, "Step should be of usize type");
+        self.offset = parse_args!(params, 1, Usize, "Offset should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x6b
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.step),
+            AtpParamTypes::Usize(self.offset),
+        ])
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::sample::Sample };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_sample() {
+        let t = Sample::default();
+        assert_eq!(t.get_string_repr(), "sample");
+    }
+
+    #[test]
+    fn transform_step_2_offset_0() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sample::new(2, 0);
+        assert_eq!(t.transform("l0\nl1\nl2\nl3\nl4\n", &mut ctx), Ok("l0\nl2\nl4\n".to_string()));
+    }
+
+    #[test]
+    fn transform_step_3_offset_1() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sample::new(3, 1);
+        assert_eq!(
+            t.transform("l0\nl1\nl2\nl3\nl4\nl5\nl6\nl7", &mut ctx),
+            Ok("l1\nl4\nl7".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_step_1_keeps_everything_from_offset() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sample::new(1, 2);
+        assert_eq!(t.transform("l0\nl1\nl2\nl3", &mut ctx), Ok("l2\nl3".to_string()));
+    }
+
+    #[test]
+    fn transform_offset_past_the_end_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sample::new(2, 5);
+        assert_eq!(t.transform("l0\nl1\n", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_zero_step_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Sample::new(0, 0).transform("l0\nl1", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Sample::new(3, 1).to_atp_line().as_ref(), "sample 3 1;\n");
+    }
+
+    #[test]
+    fn from_params_reads_step_and_offset() {
+        let mut t = Sample::default();
+        t.from_params(&vec![AtpParamTypes::Usize(4), AtpParamTypes::Usize(2)]).unwrap();
+        assert_eq!((t.step, t.offset), (4, 2));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Sample::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x6b() {
+            let t = Sample::default();
+            assert_eq!(t.get_opcode(), 0x6b);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Sample::new(3, 1);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x6b);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
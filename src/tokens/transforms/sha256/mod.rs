@@ -0,0 +1,73 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use sha2::{ Digest, Sha256 };
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SHA256 - SHA-256 Digest
+///
+/// Replaces `input` with the lowercase hex SHA-256 digest of its UTF-8 bytes.
+///
+/// See Also:
+///
+/// - [`URLE` - URL Encode](crate::tokens::transforms::urle)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::sha256::Sha256Tok};
+///
+/// let token = Sha256Tok::default();
+///
+/// assert_eq!(
+///     token.transform("abc"),
+///     Ok("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string())
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct Sha256Tok {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Sha256Tok {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "sha256;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(format!("{:x}", Sha256::digest(input.as_bytes())))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "sha256"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "sha256", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x46
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::sha256::Sha256Tok };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_sha256() {
+        let t = Sha256Tok::default();
+        assert_eq!(t.get_string_repr(), "sha256");
+    }
+
+    #[test]
+    fn transform_empty_string_vector() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sha256Tok::default();
+        assert_eq!(
+            t.transform("", &mut ctx),
+            Ok("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_abc_vector() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sha256Tok::default();
+        assert_eq!(
+            t.transform("abc", &mut ctx),
+            Ok("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_output_is_lowercase_hex() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Sha256Tok::default();
+        let out = t.transform("banana", &mut ctx).unwrap();
+        assert_eq!(out.len(), 64);
+        assert!(out.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Sha256Tok::default();
+        assert_eq!(t.to_atp_line().as_ref(), "sha256;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Sha256Tok::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x46() {
+            let t = Sha256Tok::default();
+            assert_eq!(t.get_opcode(), 0x46);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Sha256Tok::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x46);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SHESC - Shell Escape
+///
+/// Quotes `input` so it can be used as a single POSIX shell argument.
+///
+/// The whole text is wrapped in single quotes and every single quote inside it is written as `'\''`.
+///
+/// See Also:
+///
+/// - [`SHUNESC` - Shell Unescape](crate::tokens::transforms::shunesc)
+/// - [`HTMLE` - HTML Escape](crate::tokens::transforms::htmle)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::shesc::Shesc};
+///
+/// let token = Shesc::default();
+///
+/// assert_eq!(token.transform("it's $HOME"), Ok("'it'\\''s $HOME'".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Shesc {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Shesc {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "shesc;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(format!("'{}'", input.replace('\'', "'\\''")))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "shesc"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "shesc", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3f
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
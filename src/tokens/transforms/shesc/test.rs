@@ -0,0 +1,83 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::shesc::Shesc };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_shesc() {
+        let t = Shesc::default();
+        assert_eq!(t.get_string_repr(), "shesc");
+    }
+
+    #[test]
+    fn transform_wraps_input_with_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shesc::default();
+        assert_eq!(t.transform("hello world", &mut ctx), Ok("'hello world'".to_string()));
+    }
+
+    #[test]
+    fn transform_escapes_single_quotes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shesc::default();
+        assert_eq!(t.transform("it's", &mut ctx), Ok("'it'\\''s'".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_dollar_sign_literal() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shesc::default();
+        assert_eq!(t.transform("$HOME", &mut ctx), Ok("'$HOME'".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty_argument() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shesc::default();
+        assert_eq!(t.transform("", &mut ctx), Ok("''".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Shesc::default();
+        assert_eq!(t.to_atp_line().as_ref(), "shesc;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Shesc::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3f() {
+            let t = Shesc::default();
+            assert_eq!(t.get_opcode(), 0x3f);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Shesc::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3f);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SHUNESC - Shell Unescape
+///
+/// Reverts a shell quoted argument back to its plain text.
+///
+/// Single quoted parts are copied as they are and a backslash outside quotes escapes the next
+/// character, so the output of [`SHESC`](crate::tokens::transforms::shesc) is always accepted.
+/// If a single quote is left open an `AtpError` is returned.
+///
+/// See Also:
+///
+/// - [`SHESC` - Shell Escape](crate::tokens::transforms::shesc)
+/// - [`HTMLU` - HTML Unescape](crate::tokens::transforms::htmlu)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::shunesc::Shunesc};
+///
+/// let token = Shunesc::default();
+///
+/// assert_eq!(token.transform("'it'\\''s $HOME'"), Ok("it's $HOME".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Shunesc {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Shunesc {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "shunesc;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut result = String::with_capacity(input.len());
+        let mut in_quotes = false;
+        let mut chars = input.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' => {
+                    in_quotes = !in_quotes;
+                }
+                '\\' if !in_quotes => {
+                    if let Some(escaped) = chars.next() {
+                        result.push(escaped);
+                    }
+                }
+                _ => result.push(c),
+            }
+        }
+
+        if in_quotes {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::TextParsingError("Unterminated single quote".into()),
+                    "shunesc",
+                    input.to_string()
+                )
+            );
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "shunesc"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "shunesc", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x40
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
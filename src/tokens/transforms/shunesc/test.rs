@@ -0,0 +1,95 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::shunesc::Shunesc };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_shunesc() {
+        let t = Shunesc::default();
+        assert_eq!(t.get_string_repr(), "shunesc");
+    }
+
+    #[test]
+    fn transform_unwraps_input_with_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shunesc::default();
+        assert_eq!(t.transform("'hello world'", &mut ctx), Ok("hello world".to_string()));
+    }
+
+    #[test]
+    fn transform_restores_single_quotes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shunesc::default();
+        assert_eq!(t.transform("'it'\\''s'", &mut ctx), Ok("it's".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_dollar_sign() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shunesc::default();
+        assert_eq!(t.transform("'$HOME'", &mut ctx), Ok("$HOME".to_string()));
+    }
+
+    #[test]
+    fn transform_round_trips_shesc_output() {
+        use crate::tokens::transforms::shesc::Shesc;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "rm -rf '$dir' \\ \"x\"";
+        let escaped = Shesc::default().transform(input, &mut ctx).unwrap();
+
+        assert_eq!(Shunesc::default().transform(&escaped, &mut ctx), Ok(input.to_string()));
+    }
+
+    #[test]
+    fn transform_unterminated_quote_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Shunesc::default();
+        let err = t.transform("'banana", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Shunesc::default();
+        assert_eq!(t.to_atp_line().as_ref(), "shunesc;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Shunesc::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x40() {
+            let t = Shunesc::default();
+            assert_eq!(t.get_opcode(), 0x40);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Shunesc::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x40);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
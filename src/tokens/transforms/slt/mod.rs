@@ -48,6 +48,9 @@ impl InstructionMethods for Slt {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "slt"
     }
@@ -0,0 +1,79 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::AtpError,
+        regex_cache::get_or_compile,
+        transforms::ascii_fold,
+        validations::check_vec_len,
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SLUG - Slugify
+///
+/// Turns `input` into an URL slug: the text is lowercased, accented characters are folded to ASCII,
+/// every run of non alphanumeric characters becomes a single `-` and leading/trailing hyphens are removed.
+///
+/// See Also:
+///
+/// - [`TLA` - To Lowercase All](crate::tokens::transforms::tla)
+/// - [`URLE` - URL Encode](crate::tokens::transforms::urle)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::slug::Slug};
+///
+/// let token = Slug::default();
+///
+/// assert_eq!(token.transform("Héllo, World!"), Ok("hello-world".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Slug {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Slug {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "slug;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let folded = ascii_fold(&input.to_lowercase());
+
+        let separators = get_or_compile(r"[^a-z0-9]+")?;
+
+        Ok(separators.replace_all(&folded, "-").trim_matches('-').to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "slug"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "slug", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3a
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
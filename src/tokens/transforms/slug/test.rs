@@ -0,0 +1,90 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::slug::Slug };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_slug() {
+        let t = Slug::default();
+        assert_eq!(t.get_string_repr(), "slug");
+    }
+
+    #[test]
+    fn transform_builds_slug_from_accented_text() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Slug::default();
+        assert_eq!(t.transform("Héllo, World!", &mut ctx), Ok("hello-world".to_string()));
+    }
+
+    #[test]
+    fn transform_collapses_consecutive_separators() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Slug::default();
+        assert_eq!(t.transform("banana  --  laranja__cheia", &mut ctx), Ok("banana-laranja-cheia".to_string()));
+    }
+
+    #[test]
+    fn transform_strips_leading_and_trailing_punctuation() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Slug::default();
+        assert_eq!(t.transform("...¿Qué pasa?!", &mut ctx), Ok("que-pasa".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_digits() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Slug::default();
+        assert_eq!(t.transform("Release 2.0 Notes", &mut ctx), Ok("release-2-0-notes".to_string()));
+    }
+
+    #[test]
+    fn transform_only_punctuation_yields_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Slug::default();
+        assert_eq!(t.transform("!!! ---", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Slug::default();
+        assert_eq!(t.to_atp_line().as_ref(), "slug;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Slug::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3a() {
+            let t = Slug::default();
+            assert_eq!(t.get_opcode(), 0x3a);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Slug::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3a);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
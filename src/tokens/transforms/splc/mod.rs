@@ -3,6 +3,8 @@ pub mod test;
 
 use std::borrow::Cow;
 
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::tokens::InstructionMethods;
 
@@ -12,7 +14,9 @@ use crate::utils::validations::check_vec_len;
 
 /// SPLC - Split Characters
 ///
-/// Split `input` characters in a result whose chars are separed by spaces
+/// Split `input` characters in a result whose chars are separed by `separator` (a single space by default)
+///
+/// Characters are grapheme clusters, so a letter and its combining accents stay together.
 ///
 /// # Example
 ///
@@ -20,37 +24,76 @@ use crate::utils::validations::check_vec_len;
 /// use atp::tokens::{InstructionMethods, transforms::splc::Splc};
 ///
 /// let token = Splc::default();
-///
 /// assert_eq!(token.transform("banana"), Ok("b a n a n a".to_string()));
+///
+/// let token = Splc::new(",");
+/// assert_eq!(token.transform("banana"), Ok("b,a,n,a,n,a".to_string()));
 /// ```
 ///
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct Splc {
+    pub separator: String,
     params: Vec<AtpParamTypes>,
 }
 
+impl Splc {
+    pub fn new(separator: &str) -> Self {
+        Splc {
+            separator: separator.to_string(),
+            params: vec![separator.to_string().into()],
+        }
+    }
+}
+
+impl Default for Splc {
+    fn default() -> Self {
+        Splc {
+            separator: " ".to_string(),
+            params: vec![],
+        }
+    }
+}
+
 impl InstructionMethods for Splc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        if self.separator == " " { vec![] } else { vec![self.separator.clone()] }
+    }
     fn get_string_repr(&self) -> &'static str {
         "splc"
     }
     fn to_atp_line(&self) -> Cow<'static, str> {
-        "splc;\n".into()
+        // The default separator is omitted, so older `splc;` lines keep the same meaning.
+        if self.separator == " " {
+            "splc;\n".into()
+        } else {
+            // Quoted so empty or space padded separators read back the same
+            format!("splc {};\n", shell_words::quote(&self.separator)).into()
+        }
     }
 
     fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
         Ok(
             input
-                .chars()
-                .map(|c| c.to_string())
+                .graphemes(true)
                 .collect::<Vec<_>>()
-                .join(" ")
+                .join(&self.separator)
         )
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
-        check_vec_len(&params, 0, "rmws", "")?;
+        use crate::parse_args;
+
+        if params.is_empty() {
+            self.separator = " ".to_string();
+            return Ok(());
+        }
+
+        check_vec_len(&params, 1, "splc", "")?;
+
+        self.separator = parse_args!(params, 0, String, "Separator should be of string type");
+
         Ok(())
     }
     #[cfg(feature = "bytecode")]
@@ -60,7 +103,9 @@ impl InstructionMethods for Splc {
     #[cfg(feature = "bytecode")]
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
-        let result: Vec<u8> = to_bytecode!(self.get_opcode(), []);
+        let result: Vec<u8> = to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.separator.clone()),
+        ]);
         result
     }
 }
@@ -44,6 +44,15 @@ mod tests {
         assert_eq!(t.transform("áβ🍌", &mut ctx).unwrap(), "á β 🍌");
     }
 
+    #[test]
+    fn transform_keeps_combining_sequences_together() {
+        let t = Splc::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        // "e" + U+0301 COMBINING ACUTE ACCENT is a single cluster
+        assert_eq!(t.transform("e\u{0301}f", &mut ctx).unwrap(), "e\u{0301} f");
+    }
+
     #[test]
     fn transform_empty_is_empty() {
         let t = Splc::default();
@@ -60,15 +69,71 @@ mod tests {
     }
 
     #[test]
-    fn from_params_rejects_any_params() {
+    fn from_params_rejects_more_than_one_param() {
         let mut t = Splc::default();
-        let params = vec![AtpParamTypes::Usize(1)];
+        let params = vec![AtpParamTypes::String(",".to_string()), AtpParamTypes::Usize(1)];
 
         let err = t.from_params(&params).unwrap_err();
 
         assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
     }
 
+    #[test]
+    fn from_params_accepts_separator() {
+        let mut t = Splc::default();
+        t.from_params(&vec![AtpParamTypes::String(",".to_string())]).unwrap();
+        assert_eq!(t.separator, ",");
+    }
+
+    #[test]
+    fn transform_with_comma_separator() {
+        let t = Splc::new(",");
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("banana", &mut ctx).unwrap(), "b,a,n,a,n,a");
+    }
+
+    #[test]
+    fn transform_with_multi_character_separator() {
+        let t = Splc::new("->");
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("abc", &mut ctx).unwrap(), "a->b->c");
+    }
+
+    #[test]
+    fn to_atp_line_includes_custom_separator() {
+        let t = Splc::new(",");
+        assert_eq!(t.to_atp_line().as_ref(), "splc ,;\n");
+    }
+
+    #[test]
+    fn text_line_without_separator_still_parses() {
+        use crate::text::reader::read_from_text;
+
+        let token = read_from_text("splc;").unwrap();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(token.apply_token("abc", &mut ctx).unwrap(), "a b c");
+
+        let token = read_from_text("splc ,;").unwrap();
+        assert_eq!(token.apply_token("abc", &mut ctx).unwrap(), "a,b,c");
+    }
+
+    #[test]
+    fn text_round_trip_keeps_empty_and_padded_separators() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+
+        for (separator, expected) in [("", "abc"), (" , ", "a , b , c")] {
+            let line = Splc::new(separator).to_atp_line();
+            let token = read_from_text(&line).unwrap();
+
+            assert_eq!(token.apply_token("abc", &mut ctx).unwrap(), expected, "{}", line);
+        }
+    }
+
     // ============================
     // Bytecode tests
     // ============================
@@ -83,7 +148,7 @@ mod tests {
         }
 
         #[test]
-        fn to_bytecode_contains_opcode_and_zero_params() {
+        fn to_bytecode_contains_opcode_and_separator_param() {
             let t = Splc::default();
             let bc = t.to_bytecode();
 
@@ -97,7 +162,7 @@ mod tests {
             assert_eq!(opcode, 0x23);
 
             let param_count = bc[12] as usize;
-            assert_eq!(param_count, 0);
+            assert_eq!(param_count, 1);
         }
     }
 }
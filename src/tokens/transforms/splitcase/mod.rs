@@ -0,0 +1,102 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SPLITCASE - Split Case to Words
+///
+/// Splits a camelCase, PascalCase, snake_case or kebab-case `input` back into lowercase words separated by a single space.
+///
+/// Word boundaries are `_`, `-`, whitespace, a lowercase letter or digit followed by an uppercase one, and the last
+/// letter of an uppercase run followed by a lowercase one (`"HTTPServer"` becomes `"http server"`).
+///
+/// See Also:
+///
+/// - [`JCMC` - Join to Camel Case](crate::tokens::transforms::jcmc)
+/// - [`JKBC` - Join to Kebab Case](crate::tokens::transforms::jkbc)
+/// - [`JPSC` - Join to Pascal Case](crate::tokens::transforms::jpsc)
+/// - [`JSNC` - Join to Snake Case](crate::tokens::transforms::jsnc)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::splitcase::Splitcase};
+///
+/// let token = Splitcase::default();
+///
+/// assert_eq!(token.transform("bananaLaranjaCheiaDeCanja"), Ok("banana laranja cheia de canja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Splitcase {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Splitcase {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "splitcase;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut words: Vec<String> = Vec::new();
+        let mut current = String::new();
+
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '_' || c == '-' || c.is_whitespace() {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+
+            if c.is_uppercase() && !current.is_empty() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+
+                if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+
+            current.extend(c.to_lowercase());
+        }
+
+        if !current.is_empty() {
+            words.push(current);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "splitcase"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "splitcase", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x55
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
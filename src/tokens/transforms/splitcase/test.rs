@@ -0,0 +1,123 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::splitcase::Splitcase };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+    use crate::tokens::transforms::{ jcmc::Jcmc, jkbc::Jkbc, jpsc::Jpsc, jsnc::Jsnc };
+
+    #[test]
+    fn get_string_repr_is_splitcase() {
+        let t = Splitcase::default();
+        assert_eq!(t.get_string_repr(), "splitcase");
+    }
+
+    #[test]
+    fn transform_camel_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Splitcase::default();
+        assert_eq!(t.transform("bananaLaranjaCheiaDeCanja", &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn transform_snake_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Splitcase::default();
+        assert_eq!(t.transform("banana_laranja_cheia_de_canja", &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn transform_kebab_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Splitcase::default();
+        assert_eq!(t.transform("banana-laranja-cheia-de-canja", &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn round_trips_camel_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let joined = Jcmc::default().transform("banana laranja cheia de canja", &mut ctx).unwrap();
+        let t = Splitcase::default();
+        assert_eq!(t.transform(&joined, &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn round_trips_snake_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let joined = Jsnc::default().transform("banana laranja cheia de canja", &mut ctx).unwrap();
+        let t = Splitcase::default();
+        assert_eq!(t.transform(&joined, &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn round_trips_kebab_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let joined = Jkbc::default().transform("banana laranja cheia de canja", &mut ctx).unwrap();
+        let t = Splitcase::default();
+        assert_eq!(t.transform(&joined, &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn round_trips_pascal_case() {
+        let mut ctx = GlobalExecutionContext::new();
+        let joined = Jpsc::default().transform("banana laranja cheia de canja", &mut ctx).unwrap();
+        let t = Splitcase::default();
+        assert_eq!(t.transform(&joined, &mut ctx), Ok("banana laranja cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_acronyms_together() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Splitcase::default();
+        assert_eq!(t.transform("parseHTTPServerV2", &mut ctx), Ok("parse http server v2".to_string()));
+    }
+
+    #[test]
+    fn transform_collapses_repeated_separators() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Splitcase::default();
+        assert_eq!(t.transform("__banana--laranja__", &mut ctx), Ok("banana laranja".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Splitcase::default();
+        assert_eq!(t.to_atp_line().as_ref(), "splitcase;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Splitcase::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x55() {
+            let t = Splitcase::default();
+            assert_eq!(t.get_opcode(), 0x55);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Splitcase::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x55);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
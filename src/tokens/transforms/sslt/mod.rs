@@ -1,12 +1,13 @@
 #[cfg(feature = "test_access")]
 pub mod test;
 
-use std::borrow::Cow;
+use std::{ borrow::Cow, sync::Arc };
 
 use regex::Regex;
 
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::params::AtpParamTypes;
+use crate::utils::regex_cache::get_or_compile;
 use crate::utils::validations::check_vec_len;
 use crate::{ tokens::InstructionMethods };
 
@@ -29,15 +30,19 @@ use crate::utils::errors::{ AtpError, AtpErrorCode };
 /// ```
 #[derive(Clone)]
 pub struct Sslt {
-    pub pattern: Regex,
+    pub pattern: Arc<Regex>,
     pub index: usize,
     params: Vec<AtpParamTypes>,
 }
 
 impl Sslt {
     pub fn new(pattern: &str, index: usize) -> Result<Self, AtpError> {
-        let pattern = Regex::new(&pattern).map_err(|e| {
-            AtpError::new(AtpErrorCode::BytecodeParsingError(e.to_string().into()), "", "")
+        let pattern = get_or_compile(pattern).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::BytecodeParsingError(e.error_code.message().clone()),
+                "",
+                ""
+            )
         })?;
         Ok(Sslt { index, params: vec![pattern.to_string().into(), index.into()], pattern })
     }
@@ -46,7 +51,7 @@ impl Sslt {
 impl Default for Sslt {
     fn default() -> Self {
         Sslt {
-            pattern: Regex::new("").unwrap(),
+            pattern: Arc::new(Regex::new("").unwrap()),
             index: 0,
             params: vec!["".to_string().into(), (0).into()],
         }
@@ -57,6 +62,9 @@ impl InstructionMethods for Sslt {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.pattern.to_string(), self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "sslt"
     }
@@ -85,11 +93,9 @@ impl InstructionMethods for Sslt {
 
         check_vec_len(&params, 2, "sslt", "")?;
 
-        self.index = parse_args!(params, 0, Usize, "Index should be of type Usize");
-
-        let pattern_payload = parse_args!(params, 1, String, "Pattern should be of string type");
+        let pattern_payload = parse_args!(params, 0, String, "Pattern should be of string type");
 
-        self.pattern = Regex::new(&pattern_payload.clone()).map_err(|_| {
+        self.pattern = get_or_compile(&pattern_payload).map_err(|_| {
             AtpError::new(
                 AtpErrorCode::TextParsingError("Failed to create regex".into()),
                 "sslt",
@@ -97,6 +103,8 @@ impl InstructionMethods for Sslt {
             )
         })?;
 
+        self.index = parse_args!(params, 1, Usize, "Index should be of type Usize");
+
         return Ok(());
     }
     #[cfg(feature = "bytecode")]
@@ -107,8 +115,8 @@ impl InstructionMethods for Sslt {
     fn to_bytecode(&self) -> Vec<u8> {
         use crate::to_bytecode;
         let result: Vec<u8> = to_bytecode!(self.get_opcode(), [
-            AtpParamTypes::Usize(self.index),
             AtpParamTypes::String(self.pattern.to_string()),
+            AtpParamTypes::Usize(self.index),
         ]);
         result
     }
@@ -57,7 +57,7 @@ mod tests {
     #[test]
     fn from_params_accepts_two_params() {
         let mut t = Sslt::default();
-        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::String("_".to_string())];
+        let params = vec![AtpParamTypes::String("_".to_string()), AtpParamTypes::Usize(1)];
 
         assert_eq!(t.from_params(&params), Ok(()));
         assert_eq!(t.index, 1);
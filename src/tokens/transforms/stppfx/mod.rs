@@ -0,0 +1,89 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// STPPFX - Strip Prefix
+///
+/// Removes `prefix` from the beginning of `input` once, if it is there, otherwise `input` is returned unchanged.
+///
+/// See Also:
+///
+/// - [`ENSUREPFX` - Ensure Prefix](crate::tokens::transforms::ensurepfx)
+/// - [`STPSFX` - Strip Suffix](crate::tokens::transforms::stpsfx)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::stppfx::Stppfx};
+///
+/// let token = Stppfx::new("banana ");
+///
+/// assert_eq!(token.transform("banana laranja"), Ok("laranja".to_string()));
+/// assert_eq!(token.transform("canja"), Ok("canja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Stppfx {
+    pub prefix: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Stppfx {
+    pub fn new(prefix: &str) -> Self {
+        Stppfx {
+            prefix: prefix.to_string(),
+            params: vec![prefix.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Stppfx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.prefix.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("stppfx {};\n", self.prefix).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.strip_prefix(self.prefix.as_str()).unwrap_or(input).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "stppfx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "stppfx", "")?;
+
+        self.prefix = parse_args!(params, 0, String, "Prefix should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x62
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.prefix.clone()),
+        ])
+    }
+}
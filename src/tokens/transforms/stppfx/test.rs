@@ -0,0 +1,99 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::stppfx::Stppfx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_stppfx() {
+        let t = Stppfx::default();
+        assert_eq!(t.get_string_repr(), "stppfx");
+    }
+
+    #[test]
+    fn transform_removes_present_prefix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stppfx::new(">> ");
+        assert_eq!(t.transform(">> banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_absent_prefix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stppfx::new(">> ");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+        assert_eq!(t.transform("banana >> ", &mut ctx), Ok("banana >> ".to_string()));
+    }
+
+    #[test]
+    fn transform_removes_only_one_occurrence() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stppfx::new(">> ");
+        assert_eq!(t.transform(">> >> banana", &mut ctx), Ok(">> banana".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_prefix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stppfx::new("");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_undoes_ensure() {
+        use crate::tokens::transforms::ensurepfx::Ensurepfx;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let ensured = Ensurepfx::new("#").transform("banana", &mut ctx).unwrap();
+        assert_eq!(Stppfx::new("#").transform(&ensured, &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Stppfx::new("/").to_atp_line().as_ref(), "stppfx /;\n");
+    }
+
+    #[test]
+    fn from_params_reads_prefix() {
+        let mut t = Stppfx::default();
+        t.from_params(&vec![AtpParamTypes::String("#".to_string())]).unwrap();
+        assert_eq!(t.prefix, "#");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Stppfx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x62() {
+            let t = Stppfx::default();
+            assert_eq!(t.get_opcode(), 0x62);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Stppfx::new("/");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x62);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// STPSFX - Strip Suffix
+///
+/// Removes `suffix` from the end of `input` once, if it is there, otherwise `input` is returned unchanged.
+///
+/// See Also:
+///
+/// - [`ENSURESFX` - Ensure Suffix](crate::tokens::transforms::ensuresfx)
+/// - [`STPPFX` - Strip Prefix](crate::tokens::transforms::stppfx)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::stpsfx::Stpsfx};
+///
+/// let token = Stpsfx::new(" laranja");
+///
+/// assert_eq!(token.transform("banana laranja"), Ok("banana".to_string()));
+/// assert_eq!(token.transform("canja"), Ok("canja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Stpsfx {
+    pub suffix: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Stpsfx {
+    pub fn new(suffix: &str) -> Self {
+        Stpsfx {
+            suffix: suffix.to_string(),
+            params: vec![suffix.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Stpsfx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.suffix.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("stpsfx {};\n", self.suffix).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.strip_suffix(self.suffix.as_str()).unwrap_or(input).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "stpsfx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "stpsfx", "")?;
+
+        self.suffix = parse_args!(params, 0, String, "Suffix should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x63
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.suffix.clone()),
+        ])
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::stpsfx::Stpsfx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_stpsfx() {
+        let t = Stpsfx::default();
+        assert_eq!(t.get_string_repr(), "stpsfx");
+    }
+
+    #[test]
+    fn transform_removes_present_suffix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stpsfx::new(" <<");
+        assert_eq!(t.transform("banana <<", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_absent_suffix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stpsfx::new(" <<");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+        assert_eq!(t.transform(" << banana", &mut ctx), Ok(" << banana".to_string()));
+    }
+
+    #[test]
+    fn transform_removes_only_one_occurrence() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stpsfx::new(" <<");
+        assert_eq!(t.transform("banana << <<", &mut ctx), Ok("banana <<".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_suffix_is_noop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stpsfx::new("");
+        assert_eq!(t.transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_undoes_ensure() {
+        use crate::tokens::transforms::ensuresfx::Ensuresfx;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let ensured = Ensuresfx::new("#").transform("banana", &mut ctx).unwrap();
+        assert_eq!(Stpsfx::new("#").transform(&ensured, &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Stpsfx::new("/").to_atp_line().as_ref(), "stpsfx /;\n");
+    }
+
+    #[test]
+    fn from_params_reads_suffix() {
+        let mut t = Stpsfx::default();
+        t.from_params(&vec![AtpParamTypes::String("#".to_string())]).unwrap();
+        assert_eq!(t.suffix, "#");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Stpsfx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x63() {
+            let t = Stpsfx::default();
+            assert_eq!(t.get_opcode(), 0x63);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Stpsfx::new("/");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x63);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, regex_cache::get_or_compile, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// STRIPANSI - Strip ANSI Escape Codes
+///
+/// Removes ANSI escape sequences (`ESC [` followed by digits/`;` and a final letter, e.g. colors
+/// and cursor movement) from `input`, keeping the visible text.
+///
+/// A stray `ESC` that does not start a valid sequence is kept as is, use
+/// [`PRINTABLE`](crate::tokens::transforms::printable) afterwards to drop it.
+///
+/// See Also:
+///
+/// - [`PRINTABLE` - Printable Only](crate::tokens::transforms::printable)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::stripansi::Stripansi};
+///
+/// let token = Stripansi::default();
+///
+/// assert_eq!(token.transform("\x1b[1;31merror\x1b[0m: boom"), Ok("error: boom".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Stripansi {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Stripansi {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "stripansi;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let ansi = get_or_compile(r"\x1b\[[0-9;]*[A-Za-z]")?;
+
+        Ok(ansi.replace_all(input, "").to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "stripansi"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "stripansi", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x53
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
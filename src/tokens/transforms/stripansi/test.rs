@@ -0,0 +1,86 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::stripansi::Stripansi };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_stripansi() {
+        let t = Stripansi::default();
+        assert_eq!(t.get_string_repr(), "stripansi");
+    }
+
+    #[test]
+    fn transform_removes_color_codes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stripansi::default();
+        assert_eq!(
+            t.transform("\x1b[32mok\x1b[0m and \x1b[1;31mfail\x1b[m", &mut ctx),
+            Ok("ok and fail".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_removes_cursor_sequences() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stripansi::default();
+        assert_eq!(t.transform("\x1b[2K\x1b[1Gprogress 50%", &mut ctx), Ok("progress 50%".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_stray_escape() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stripansi::default();
+        assert_eq!(t.transform("a\x1bb \x1b[", &mut ctx), Ok("a\x1bb \x1b[".to_string()));
+    }
+
+    #[test]
+    fn transform_plain_text_is_unchanged() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Stripansi::default();
+        assert_eq!(t.transform("[31m not an escape", &mut ctx), Ok("[31m not an escape".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Stripansi::default();
+        assert_eq!(t.to_atp_line().as_ref(), "stripansi;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Stripansi::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x53() {
+            let t = Stripansi::default();
+            assert_eq!(t.get_opcode(), 0x53);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Stripansi::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x53);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use aho_corasick::{ AhoCorasick, MatchKind };
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SUBMAP - Substitute Map
+///
+/// Applies every `key => value` replacement of `pairs` in a single left-to-right pass over `input`.
+/// When keys overlap the longest match wins, and replaced text is never scanned again, so
+/// replacements do not cascade (with `a=>b;b=>c`, "ab" becomes "bc").
+///
+/// The pairs are written as `key=>value` separated by `;`, e.g. `a=>x;b=>y`. Keys can't be empty or
+/// contain `=>`, and neither keys nor values can contain `;`; these return an `AtpError`.
+///
+/// See Also:
+///
+/// - [`RAW` - Replace All With](crate::tokens::transforms::raw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::submap::Submap};
+///
+/// let token = Submap::new(&[("a", "e"), ("e", "a")]).unwrap();
+///
+/// assert_eq!(token.transform("banane"), Ok("benena".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Submap {
+    pub pairs: Vec<(String, String)>,
+    automaton: Option<AhoCorasick>,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Submap {
+    pub fn new(pairs: &[(&str, &str)]) -> Result<Self, AtpError> {
+        let pairs: Vec<(String, String)> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        for (key, value) in pairs.iter() {
+            check_pair(key, value)?;
+        }
+
+        let spec = format_pairs(&pairs);
+
+        Ok(Submap { automaton: build_automaton(&pairs)?, pairs, params: vec![spec.into()] })
+    }
+}
+
+fn invalid_pairs(message: &'static str, spec: String) -> AtpError {
+    AtpError::new(AtpErrorCode::InvalidParameters(message.into()), "submap", spec)
+}
+
+fn check_pair(key: &str, value: &str) -> Result<(), AtpError> {
+    if key.is_empty() {
+        return Err(
+            invalid_pairs("Substitution keys can't be empty", format!("{}=>{}", key, value))
+        );
+    }
+    if key.contains("=>") || key.contains(';') || value.contains(';') {
+        return Err(
+            invalid_pairs(
+                "Substitution keys can't contain '=>', and keys or values can't contain ';'",
+                format!("{}=>{}", key, value)
+            )
+        );
+    }
+    Ok(())
+}
+
+fn format_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}=>{}", key, value))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Parses a `key=>value;key=>value` spec, an empty spec has no pairs.
+fn parse_pairs(spec: &str) -> Result<Vec<(String, String)>, AtpError> {
+    if spec.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    spec.split(';')
+        .map(|pair| {
+            let (key, value) = pair
+                .split_once("=>")
+                .ok_or_else(|| {
+                    invalid_pairs(
+                        "Substitution pairs should be written as 'key=>value'",
+                        pair.to_string()
+                    )
+                })?;
+            check_pair(key, value)?;
+            Ok((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn build_automaton(pairs: &[(String, String)]) -> Result<Option<AhoCorasick>, AtpError> {
+    if pairs.is_empty() {
+        return Ok(None);
+    }
+
+    AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(pairs.iter().map(|(key, _)| key))
+        .map(Some)
+        .map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Failed building the substitution map".into()),
+                "submap",
+                e.to_string()
+            )
+        })
+}
+
+impl InstructionMethods for Submap {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![format_pairs(&self.pairs)]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("submap {};\n", format_pairs(&self.pairs)).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        match &self.automaton {
+            Some(automaton) => {
+                let values: Vec<&str> = self.pairs
+                    .iter()
+                    .map(|(_, value)| value.as_str())
+                    .collect();
+                Ok(automaton.replace_all(input, &values))
+            }
+            None => Ok(input.to_string()),
+        }
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "submap"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "submap", "")?;
+
+        let spec: String = parse_args!(params, 0, String, "Pairs should be of string type");
+        let pairs = parse_pairs(&spec)?;
+
+        self.automaton = build_automaton(&pairs)?;
+        self.pairs = pairs;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x79
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [AtpParamTypes::String(format_pairs(&self.pairs))])
+    }
+}
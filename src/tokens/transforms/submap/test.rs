@@ -0,0 +1,121 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::submap::Submap };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_submap() {
+        let t = Submap::default();
+        assert_eq!(t.get_string_repr(), "submap");
+    }
+
+    #[test]
+    fn transform_prefers_the_longest_overlapping_key() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Submap::new(&[("a", "1"), ("ab", "2"), ("abc", "3")]).unwrap();
+
+        assert_eq!(t.transform("abcaba", &mut ctx), Ok("321".to_string()));
+    }
+
+    #[test]
+    fn transform_does_not_cascade_replacements() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Submap::new(&[("a", "b"), ("b", "c")]).unwrap();
+
+        assert_eq!(t.transform("ab", &mut ctx), Ok("bc".to_string()));
+    }
+
+    #[test]
+    fn transform_swaps_values_in_one_pass() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Submap::new(&[("sim", "não"), ("não", "sim")]).unwrap();
+
+        assert_eq!(t.transform("sim ou não?", &mut ctx), Ok("não ou sim?".to_string()));
+    }
+
+    #[test]
+    fn transform_without_pairs_is_identity() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Submap::default().transform("banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn from_params_parses_serialized_pairs() {
+        let mut ctx = GlobalExecutionContext::new();
+        let mut t = Submap::default();
+
+        t.from_params(&vec![AtpParamTypes::String("a=>x;b=>=>y;c=>".to_string())]).unwrap();
+
+        assert_eq!(
+            t.pairs,
+            vec![
+                ("a".to_string(), "x".to_string()),
+                ("b".to_string(), "=>y".to_string()),
+                ("c".to_string(), "".to_string())
+            ]
+        );
+        assert_eq!(t.transform("abc", &mut ctx), Ok("x=>y".to_string()));
+    }
+
+    #[test]
+    fn from_params_rejects_malformed_pairs() {
+        let mut t = Submap::default();
+
+        for spec in ["a", "a=>x;", "=>x", "a=>x;;b=>y"] {
+            let err = t.from_params(&vec![AtpParamTypes::String(spec.to_string())]).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)), "{}", spec);
+        }
+    }
+
+    #[test]
+    fn new_rejects_pairs_that_can_not_be_serialized() {
+        for pairs in [[("", "x")], [("a=>b", "x")], [("a", "x;y")]] {
+            let err = Submap::new(&pairs).err().unwrap();
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        }
+    }
+
+    #[test]
+    fn to_atp_line_serializes_pairs() {
+        let t = Submap::new(&[("a", "x"), ("b", "y")]).unwrap();
+        assert_eq!(t.to_atp_line().as_ref(), "submap a=>x;b=>y;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Submap::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x79() {
+            let t = Submap::default();
+            assert_eq!(t.get_opcode(), 0x79);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Submap::new(&[("a", "x"), ("b", "y")]).unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x79);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
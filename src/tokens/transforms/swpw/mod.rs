@@ -0,0 +1,111 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::AtpError,
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// SWPW - Swap Words
+///
+/// Swaps the words at indexes `a` and `b` in `input`, words are joined back with a single space.
+///
+/// If either index is out of range an `AtpError` is returned.
+///
+/// See Also:
+///
+/// - [`RTL` - Rotate Left](crate::tokens::transforms::rtl)
+/// - [`TUCW` - To Uppercase Word](crate::tokens::transforms::tucw)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::swpw::Swpw};
+///
+/// let token = Swpw::new(0, 2);
+///
+/// assert_eq!(token.transform("banana laranja cheia"), Ok("cheia laranja banana".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Swpw {
+    pub a: usize,
+    pub b: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Swpw {
+    pub fn new(a: usize, b: usize) -> Self {
+        Swpw {
+            a,
+            b,
+            params: vec![a.into(), b.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Swpw {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.a.to_string(), self.b.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("swpw {} {};\n", self.a, self.b).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let (Some(a), Some(b)) = (
+            resolve_token_index(self.a, input, IndexUnit::Word, context)?,
+            resolve_token_index(self.b, input, IndexUnit::Word, context)?,
+        ) else {
+            return Ok(input.to_string());
+        };
+
+        let mut words = input.split_whitespace().collect::<Vec<_>>();
+        words.swap(a, b);
+
+        Ok(words.join(" "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "swpw"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "swpw", "")?;
+
+        self.a = parse_args!(params, 0, Usize, "Index should be of usize type");
+        self.b = parse_args!(params, 1, Usize, "Index should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x3d
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.a),
+            AtpParamTypes::Usize(self.b),
+        ])
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::swpw::Swpw };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_swpw() {
+        let t = Swpw::default();
+        assert_eq!(t.get_string_repr(), "swpw");
+    }
+
+    #[test]
+    fn transform_swaps_two_words() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Swpw::new(0, 2);
+        assert_eq!(t.transform("banana laranja cheia", &mut ctx), Ok("cheia laranja banana".to_string()));
+    }
+
+    #[test]
+    fn transform_same_index_is_identity() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Swpw::new(1, 1);
+        assert_eq!(t.transform("banana laranja cheia", &mut ctx), Ok("banana laranja cheia".to_string()));
+    }
+
+    #[test]
+    fn transform_out_of_range_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Swpw::new(0, 3);
+        let err = t.transform("banana laranja cheia", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Swpw::new(0, 2);
+        assert_eq!(t.to_atp_line().as_ref(), "swpw 0 2;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_two_usizes() {
+        let mut t = Swpw::default();
+        t.from_params(&vec![AtpParamTypes::Usize(3), AtpParamTypes::Usize(1)]).unwrap();
+        assert_eq!((t.a, t.b), (3, 1));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Swpw::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x3d() {
+            let t = Swpw::default();
+            assert_eq!(t.get_opcode(), 0x3d);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Swpw::new(0, 2);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x3d);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TABS - Expand Tabs
+///
+/// Replaces every tab of `input` with spaces. By default each tab becomes `width` spaces.
+///
+/// When `columnar` is set, tabs advance to the next tab stop instead: the column is tracked per
+/// line and each tab is padded up to the next multiple of `width`, like a terminal would render
+/// it. A `width` of 0 returns an `AtpError`.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::tabs::Tabs};
+///
+/// let naive = Tabs::new(4).unwrap();
+/// let columnar = Tabs::new(4).unwrap().with_columnar(true);
+///
+/// assert_eq!(naive.transform("ab\tc"), Ok("ab    c".to_string()));
+/// assert_eq!(columnar.transform("ab\tc"), Ok("ab  c".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Tabs {
+    pub width: usize,
+    pub columnar: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Tabs {
+    pub fn new(width: usize) -> Result<Self, AtpError> {
+        check_width(width)?;
+
+        Ok(Tabs { width, columnar: false, params: vec![width.into()] })
+    }
+
+    /// Opts into column-aware expansion, where tabs pad up to the next tab stop.
+    pub fn with_columnar(mut self, columnar: bool) -> Self {
+        self.params.truncate(1);
+        if columnar {
+            self.params.push(columnar.into());
+        }
+        self.columnar = columnar;
+        self
+    }
+}
+
+fn check_width(width: usize) -> Result<(), AtpError> {
+    if width == 0 {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Tab width should be greater than 0".into()),
+                "tabs",
+                width.to_string()
+            )
+        );
+    }
+    Ok(())
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Tabs { width: 4, columnar: false, params: vec![4usize.into()] }
+    }
+}
+
+impl InstructionMethods for Tabs {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        if self.columnar {
+            vec![self.width.to_string(), self.columnar.to_string()]
+        } else {
+            vec![self.width.to_string()]
+        }
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        if self.columnar {
+            format!("tabs {} true;\n", self.width).into()
+        } else {
+            format!("tabs {};\n", self.width).into()
+        }
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if !self.columnar {
+            return Ok(input.replace('\t', &" ".repeat(self.width)));
+        }
+
+        let mut result = String::with_capacity(input.len());
+        let mut column = 0;
+
+        for c in input.chars() {
+            match c {
+                '\t' => {
+                    let pad = self.width - (column % self.width);
+                    result.extend(std::iter::repeat_n(' ', pad));
+                    column += pad;
+                }
+                '\n' | '\r' => {
+                    result.push(c);
+                    column = 0;
+                }
+                _ => {
+                    result.push(c);
+                    column += 1;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "tabs"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, if params.len() == 2 { 2 } else { 1 }, "tabs", "")?;
+
+        let width = parse_args!(params, 0, Usize, "Width should be of usize type");
+        check_width(width)?;
+
+        self.width = width;
+        self.columnar = match params.len() {
+            2 => parse_args!(params, 1, Bool, "Columnar flag should be of bool type"),
+            _ => false,
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x71
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        if self.columnar {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.width),
+                AtpParamTypes::Bool(true),
+            ])
+        } else {
+            to_bytecode!(self.get_opcode(), [AtpParamTypes::Usize(self.width)])
+        }
+    }
+}
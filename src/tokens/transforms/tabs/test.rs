@@ -0,0 +1,114 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::tabs::Tabs };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_tabs() {
+        let t = Tabs::default();
+        assert_eq!(t.get_string_repr(), "tabs");
+    }
+
+    #[test]
+    fn transform_naive_replaces_each_tab_with_width_spaces() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tabs::new(4).unwrap();
+        assert_eq!(t.transform("ab\tc\t\td", &mut ctx), Ok("ab    c        d".to_string()));
+    }
+
+    #[test]
+    fn transform_columnar_pads_to_next_tab_stop() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tabs::new(4).unwrap().with_columnar(true);
+        assert_eq!(t.transform("ab\tc\t\td", &mut ctx), Ok("ab  c       d".to_string()));
+        assert_eq!(t.transform("abcd\te", &mut ctx), Ok("abcd    e".to_string()));
+    }
+
+    #[test]
+    fn transform_naive_and_columnar_differ_with_text_before_tab() {
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "id\tname";
+        let naive = Tabs::new(8).unwrap().transform(input, &mut ctx).unwrap();
+        let columnar = Tabs::new(8).unwrap().with_columnar(true).transform(input, &mut ctx).unwrap();
+
+        assert_eq!(naive, "id        name");
+        assert_eq!(columnar, "id      name");
+    }
+
+    #[test]
+    fn transform_columnar_resets_column_per_line() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tabs::new(4).unwrap().with_columnar(true);
+        assert_eq!(t.transform("abc\tx\n\ty", &mut ctx), Ok("abc x\n    y".to_string()));
+    }
+
+    #[test]
+    fn new_rejects_zero_width() {
+        let err = Tabs::new(0).err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_reads_optional_columnar_flag() {
+        let mut t = Tabs::default();
+        t.from_params(&vec![AtpParamTypes::Usize(2), AtpParamTypes::Bool(true)]).unwrap();
+        assert_eq!((t.width, t.columnar), (2, true));
+
+        t.from_params(&vec![AtpParamTypes::Usize(3)]).unwrap();
+        assert_eq!((t.width, t.columnar), (3, false));
+
+        let err = t.from_params(&vec![AtpParamTypes::Usize(0)]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_includes_flag_only_when_columnar() {
+        assert_eq!(Tabs::new(4).unwrap().to_atp_line().as_ref(), "tabs 4;\n");
+        assert_eq!(
+            Tabs::new(4).unwrap().with_columnar(true).to_atp_line().as_ref(),
+            "tabs 4 true;\n"
+        );
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Tabs::default();
+        let params = vec![
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1)
+        ];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x71() {
+            let t = Tabs::default();
+            assert_eq!(t.get_opcode(), 0x71);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Tabs::new(4).unwrap().with_columnar(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x71);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TBSA - Trim Both Sides (ASCII)
+///
+/// Removes ASCII whitespace (space, `\t`, `\n`, `\x0C` and `\r`) from both the beginning and the end of `input`
+///
+/// Unlike `tbs`, other Unicode whitespace such as the no-break space (U+00A0) is kept.
+///
+/// See Also:
+///
+/// - [`TBS` - Trim Both Sides](crate::tokens::transforms::tbs)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::tbsa::Tbsa};
+///
+/// let token = Tbsa::default();
+///
+/// assert_eq!(token.transform("  banana  "), Ok("banana".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Tbsa {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Tbsa {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "tbsa;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.trim_matches(|c: char| c.is_ascii_whitespace()).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "tbsa"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "tbsa", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x49
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::AtpError,
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::{ to_lowercase_with_locale, to_uppercase_with_locale };
+
+/// TGL - Toggle Case At
+///
+/// Flips the case of the character at `index` in `input`, every other character is kept as is.
+///
+/// If `index` is out of range an `AtpError` is returned.
+///
+/// See Also:
+///
+/// - [`TUCS` - To Uppercase Single](crate::tokens::transforms::tucs)
+/// - [`TLCS` - To Lowercase Single](crate::tokens::transforms::tlcs)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::tgl::Tgl};
+///
+/// let token = Tgl::new(0);
+///
+/// assert_eq!(token.transform("banana"), Ok("Banana".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Tgl {
+    pub index: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Tgl {
+    pub fn new(index: usize) -> Self {
+        Tgl {
+            index,
+            params: vec![index.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Tgl {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("tgl {};\n", self.index).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Char, context)? else {
+            return Ok(input.to_string());
+        };
+
+        Ok(
+            input
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if i != index {
+                        c.to_string()
+                    } else if c.is_uppercase() {
+                        to_lowercase_with_locale(&c.to_string(), context.get_locale())
+                    } else {
+                        to_uppercase_with_locale(&c.to_string(), context.get_locale())
+                    }
+                })
+                .collect()
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "tgl"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "tgl", "")?;
+
+        self.index = parse_args!(params, 0, Usize, "Index should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x43
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.index),
+        ])
+    }
+}
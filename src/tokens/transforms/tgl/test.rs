@@ -0,0 +1,91 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::tgl::Tgl };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_tgl() {
+        let t = Tgl::default();
+        assert_eq!(t.get_string_repr(), "tgl");
+    }
+
+    #[test]
+    fn transform_toggles_lowercase_char() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tgl::new(2);
+        assert_eq!(t.transform("banana", &mut ctx), Ok("baNana".to_string()));
+    }
+
+    #[test]
+    fn transform_toggles_uppercase_char() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tgl::new(0);
+        assert_eq!(t.transform("Banana", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_counts_chars_not_bytes() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tgl::new(3);
+        assert_eq!(t.transform("maçã", &mut ctx), Ok("maçÃ".to_string()));
+    }
+
+    #[test]
+    fn transform_out_of_range_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Tgl::new(6);
+        let err = t.transform("banana", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Tgl::new(4);
+        assert_eq!(t.to_atp_line().as_ref(), "tgl 4;\n");
+    }
+
+    #[test]
+    fn from_params_accepts_single_usize() {
+        let mut t = Tgl::default();
+        t.from_params(&vec![AtpParamTypes::Usize(7)]).unwrap();
+        assert_eq!(t.index, 7);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Tgl::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x43() {
+            let t = Tgl::default();
+            assert_eq!(t.get_opcode(), 0x43);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Tgl::new(4);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x43);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
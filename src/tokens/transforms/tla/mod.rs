@@ -10,6 +10,7 @@ use crate::{
 };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_lowercase_with_locale;
 /// TLA - To Lowercase All
 ///
 /// Lowercases every character from `input`
@@ -40,8 +41,12 @@ impl InstructionMethods for Tla {
     fn to_atp_line(&self) -> Cow<'static, str> {
         "tla;\n".into()
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        Ok(input.to_lowercase())
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        Ok(to_lowercase_with_locale(input, context.get_locale()))
     }
     fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
         check_vec_len(&params, 0, "tla", "")?;
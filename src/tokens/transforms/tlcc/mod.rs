@@ -10,6 +10,7 @@ use crate::{
 };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_lowercase_with_locale;
 
 /// TLCC - To Lowercase Chunk
 ///
@@ -47,6 +48,9 @@ impl InstructionMethods for Tlcc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "tlcc"
     }
@@ -54,7 +58,11 @@ impl InstructionMethods for Tlcc {
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("tlcc {} {};\n", self.start_index, self.end_index).into()
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
         check_chunk_bound_indexes(self.start_index, self.end_index, Some(input))?;
 
         let total_chars = input.chars().count();
@@ -67,7 +75,7 @@ impl InstructionMethods for Tlcc {
             .enumerate()
             .map(|(i, c)| {
                 if i >= self.start_index && i <= end {
-                    c.to_lowercase().to_string()
+                    to_lowercase_with_locale(&c.to_string(), context.get_locale())
                 } else {
                     c.to_string()
                 }
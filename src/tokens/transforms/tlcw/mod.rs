@@ -5,10 +5,11 @@ use std::borrow::Cow;
 
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_lowercase_with_locale;
 use crate::utils::validations::check_vec_len;
 use crate::{
     tokens::InstructionMethods,
-    utils::{ errors::{ AtpError }, validations::{ check_index_against_words } },
+    utils::{ errors::{ AtpError }, validations::{ IndexUnit, resolve_token_index } },
 };
 
 /// TLCW - To Lowercase Word
@@ -40,6 +41,9 @@ impl InstructionMethods for Tlcw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "tlcw"
     }
@@ -51,15 +55,21 @@ impl InstructionMethods for Tlcw {
     fn transform(
         &self,
         input: &str,
-        _: &mut GlobalExecutionContext
+        context: &mut GlobalExecutionContext
     ) -> Result<String, crate::utils::errors::AtpError> {
-        check_index_against_words(self.index, input)?;
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Word, context)? else {
+            return Ok(input.to_string());
+        };
         Ok(
             input
                 .split_whitespace()
                 .enumerate()
                 .map(|(i, w)| {
-                    if i == self.index { w.to_lowercase() } else { w.to_string() }
+                    if i == index {
+                        to_lowercase_with_locale(w, context.get_locale())
+                    } else {
+                        w.to_string()
+                    }
                 })
                 .collect::<Vec<_>>()
                 .join(" ")
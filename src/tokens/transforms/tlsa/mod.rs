@@ -0,0 +1,70 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TLSA - Trim Left Side (ASCII)
+///
+/// Removes ASCII whitespace (space, `\t`, `\n`, `\x0C` and `\r`) from the beginning of `input`
+///
+/// Unlike `tls`, other Unicode whitespace such as the no-break space (U+00A0) is kept.
+///
+/// See Also:
+///
+/// - [`TLS` - Trim Left Side](crate::tokens::transforms::tls)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::tlsa::Tlsa};
+///
+/// let token = Tlsa::default();
+///
+/// assert_eq!(token.transform("  banana  "), Ok("banana  ".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Tlsa {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Tlsa {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "tlsa;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.trim_start_matches(|c: char| c.is_ascii_whitespace()).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "tlsa"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "tlsa", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4a
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TRANSP - Transpose
+///
+/// Treats each line of `input` as a row of cells separated by `delimiter` and transposes it, so the
+/// columns become lines. Rows shorter than the longest one are padded with empty cells.
+///
+/// A trailing newline at the end of `input` is preserved, an empty `delimiter` returns an `AtpError`.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::transp::Transp};
+///
+/// let token = Transp::new(" ");
+///
+/// assert_eq!(token.transform("a b c\n1 2 3"), Ok("a 1\nb 2\nc 3".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Transp {
+    pub delimiter: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Transp {
+    pub fn new(delimiter: &str) -> Self {
+        Transp {
+            delimiter: delimiter.to_string(),
+            params: vec![delimiter.to_string().into()],
+        }
+    }
+}
+
+impl InstructionMethods for Transp {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.delimiter.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("transp {};\n", self.delimiter).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        if self.delimiter.is_empty() {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::InvalidParameters("Delimiter should not be empty".into()),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        if input.is_empty() {
+            return Ok(String::new());
+        }
+
+        let body = input.strip_suffix('\n');
+
+        let rows = body
+            .unwrap_or(input)
+            .split('\n')
+            .map(|line| line.split(self.delimiter.as_str()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let width = rows
+            .iter()
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut result = (0..width)
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row.get(column).copied().unwrap_or(""))
+                    .collect::<Vec<_>>()
+                    .join(&self.delimiter)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if body.is_some() {
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "transp"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "transp", "")?;
+
+        self.delimiter = parse_args!(params, 0, String, "Delimiter should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x5b
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.delimiter.clone()),
+        ])
+    }
+}
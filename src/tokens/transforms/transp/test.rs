@@ -0,0 +1,111 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::transp::Transp };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_transp() {
+        let t = Transp::default();
+        assert_eq!(t.get_string_repr(), "transp");
+    }
+
+    #[test]
+    fn transform_rectangular_matrix() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Transp::new(" ");
+        assert_eq!(
+            t.transform("a b c\n1 2 3", &mut ctx),
+            Ok("a 1\nb 2\nc 3".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_twice_returns_original() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Transp::new("\t");
+        let input = "nome\tidade\nbanana\t3\nlaranja\t5\n";
+        let once = t.transform(input, &mut ctx).unwrap();
+        assert_eq!(once, "nome\tbanana\tlaranja\nidade\t3\t5\n");
+        assert_eq!(t.transform(&once, &mut ctx), Ok(input.to_string()));
+    }
+
+    #[test]
+    fn transform_ragged_rows_are_padded() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Transp::new(",");
+        assert_eq!(
+            t.transform("a,b,c\n1\nx,y", &mut ctx),
+            Ok("a,1,x\nb,,y\nc,,".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_multichar_delimiter() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Transp::new(" | ");
+        assert_eq!(t.transform("a | b\nc | d", &mut ctx), Ok("a | c\nb | d".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        assert_eq!(Transp::new(" ").transform("", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn transform_rejects_empty_delimiter() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Transp::new("").transform("a b", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Transp::new(",").to_atp_line().as_ref(), "transp ,;\n");
+    }
+
+    #[test]
+    fn from_params_reads_delimiter() {
+        let mut t = Transp::default();
+        t.from_params(&vec![AtpParamTypes::String(";".to_string())]).unwrap();
+        assert_eq!(t.delimiter, ";");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Transp::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x5b() {
+            let t = Transp::default();
+            assert_eq!(t.get_opcode(), 0x5b);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Transp::new(",");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x5b);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
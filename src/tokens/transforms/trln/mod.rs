@@ -0,0 +1,79 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TRLN - Trim Lines
+///
+/// Removes leading and trailing whitespace from every line of `input`, lines made only of
+/// whitespace become empty. Lines ending in `\r\n` keep their line ending.
+///
+/// See Also:
+///
+/// - [`trs` - Trim Both Sides](crate::tokens::transforms::trs)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::trln::Trln};
+///
+/// let token = Trln::default();
+///
+/// assert_eq!(token.transform("  banana \n\tlaranja\t"), Ok("banana\nlaranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Trln {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Trln {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "trln;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let lines: Vec<String> = input
+            .split('\n')
+            .map(|line| {
+                match line.strip_suffix('\r') {
+                    Some(line) => format!("{}\r", line.trim()),
+                    None => line.trim().to_string(),
+                }
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "trln"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "trln", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7f
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::trln::Trln };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_trln() {
+        let t = Trln::default();
+        assert_eq!(t.get_string_repr(), "trln");
+    }
+
+    #[test]
+    fn transform_trims_spaces_and_tabs_of_each_line() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Trln::default();
+        assert_eq!(
+            t.transform("\t banana  \n  laranja\t\ncoxinha \t", &mut ctx),
+            Ok("banana\nlaranja\ncoxinha".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_blank_lines_become_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Trln::default();
+        assert_eq!(t.transform("a\n \t \n\nb\n   ", &mut ctx), Ok("a\n\n\nb\n".to_string()));
+    }
+
+    #[test]
+    fn transform_keeps_inner_whitespace_and_crlf() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Trln::default();
+        assert_eq!(t.transform(" a  b \r\n c\t", &mut ctx), Ok("a  b\r\nc".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Trln::default().to_atp_line().as_ref(), "trln;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Trln::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7f() {
+            let t = Trln::default();
+            assert_eq!(t.get_opcode(), 0x7f);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Trln::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7f);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TRSA - Trim Right Side (ASCII)
+///
+/// Removes ASCII whitespace (space, `\t`, `\n`, `\x0C` and `\r`) from the end of `input`
+///
+/// Unlike `trs`, other Unicode whitespace such as the no-break space (U+00A0) is kept.
+///
+/// See Also:
+///
+/// - [`TRS` - Trim Right Side](crate::tokens::transforms::trs)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::trsa::Trsa};
+///
+/// let token = Trsa::default();
+///
+/// assert_eq!(token.transform("  banana  "), Ok("  banana".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Trsa {
+    params: Vec<AtpParamTypes>,
+}
+
+impl InstructionMethods for Trsa {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        "trsa;\n".into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        Ok(input.trim_end_matches(|c: char| c.is_ascii_whitespace()).to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "trsa"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        check_vec_len(&params, 0, "trsa", "")?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4b
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [])
+    }
+}
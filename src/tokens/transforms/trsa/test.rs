@@ -0,0 +1,88 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::{ trs::Trs, trsa::Trsa } };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_trsa() {
+        let t = Trsa::default();
+        assert_eq!(t.get_string_repr(), "trsa");
+    }
+
+    #[test]
+    fn to_atp_line_is_correct() {
+        let t = Trsa::default();
+        assert_eq!(t.to_atp_line().as_ref(), "trsa;\n");
+    }
+
+    #[test]
+    fn transform_trims_ascii_whitespace() {
+        let t = Trsa::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("  banana  ", &mut ctx).unwrap(), "  banana");
+    }
+
+    #[test]
+    fn transform_keeps_no_break_space() {
+        let t = Trsa::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("banana \u{a0}", &mut ctx).unwrap(), "banana \u{a0}");
+    }
+
+    #[test]
+    fn unicode_trim_removes_no_break_space_but_ascii_trim_does_not() {
+        let mut ctx = GlobalExecutionContext::new();
+        let input = "\u{a0}\u{a0}banana\u{a0}\u{a0}";
+
+        assert_eq!(Trs::default().transform(input, &mut ctx).unwrap(), "\u{a0}\u{a0}banana");
+        assert_eq!(Trsa::default().transform(input, &mut ctx).unwrap(), input);
+    }
+
+    #[test]
+    fn transform_empty_is_empty() {
+        let t = Trsa::default();
+        let mut ctx = GlobalExecutionContext::new();
+
+        assert_eq!(t.transform("", &mut ctx).unwrap(), "");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Trsa::default();
+        let params = vec![AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x4b() {
+            let t = Trsa::default();
+            assert_eq!(t.get_opcode(), 0x4b);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Trsa::default();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x4b);
+            assert_eq!(bc[12] as usize, 0);
+        }
+    }
+}
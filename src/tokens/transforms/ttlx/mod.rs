@@ -0,0 +1,136 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{
+        errors::AtpError,
+        transforms::{ capitalize_with_locale, to_lowercase_with_locale },
+        validations::check_vec_len,
+    },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// TTLX - Title Case With Exceptions
+///
+/// Title-cases every word of `input` (first char uppercased, the rest lowercased), except the words
+/// listed in `exceptions`, which are lowercased instead unless they are the first or last word.
+///
+/// `exceptions` is a comma separated list compared case-insensitively, `Ttlx::default()`
+/// uses [`DEFAULT_TITLE_EXCEPTIONS`].
+///
+/// Words are split with `split_whitespace` and joined with a single space.
+///
+/// See Also:
+///
+/// - [`JPSC` - Join to PascalCase](crate::tokens::transforms::jpsc)
+/// - [`CTR` - Capitalize Range](crate::tokens::transforms::ctr)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::ttlx::Ttlx};
+///
+/// let token = Ttlx::default();
+///
+/// assert_eq!(token.transform("the lord of the rings"), Ok("The Lord of the Rings".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Ttlx {
+    pub exceptions: String,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Ttlx {
+    pub fn new(exceptions: &str) -> Self {
+        Ttlx {
+            exceptions: exceptions.to_string(),
+            params: vec![exceptions.to_string().into()],
+        }
+    }
+}
+
+/// Small words kept lowercase by `Ttlx::default()`.
+pub const DEFAULT_TITLE_EXCEPTIONS: &str =
+    "a,an,the,and,but,or,nor,for,so,yet,of,in,on,at,to,by,up,as";
+
+impl Default for Ttlx {
+    fn default() -> Self {
+        Ttlx::new(DEFAULT_TITLE_EXCEPTIONS)
+    }
+}
+
+impl InstructionMethods for Ttlx {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.exceptions.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("ttlx {};\n", self.exceptions).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let locale = context.get_locale();
+        let exceptions: Vec<String> = self.exceptions
+            .split(',')
+            .map(|e| to_lowercase_with_locale(e.trim(), locale))
+            .filter(|e| !e.is_empty())
+            .collect();
+
+        let words = input.split_whitespace().collect::<Vec<_>>();
+        let last = words.len().saturating_sub(1);
+
+        Ok(
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    let lower = to_lowercase_with_locale(w, locale);
+                    if i != 0 && i != last && exceptions.contains(&lower) {
+                        lower
+                    } else {
+                        capitalize_with_locale(&lower, locale)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "ttlx"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "ttlx", "")?;
+
+        self.exceptions = parse_args!(params, 0, String, "Exceptions should be of string type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x4f
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::String(self.exceptions.clone()),
+        ])
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::ttlx::Ttlx };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_ttlx() {
+        let t = Ttlx::default();
+        assert_eq!(t.get_string_repr(), "ttlx");
+    }
+
+    #[test]
+    fn transform_default_list_keeps_small_words_lowercase() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ttlx::default();
+        assert_eq!(
+            t.transform("the LORD of the rings and a tale", &mut ctx),
+            Ok("The Lord of the Rings and a Tale".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_first_and_last_words_are_always_capitalized() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ttlx::default();
+        assert_eq!(t.transform("of mice and men of", &mut ctx), Ok("Of Mice and Men Of".to_string()));
+    }
+
+    #[test]
+    fn transform_custom_exceptions() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ttlx::new(" vs , x");
+        assert_eq!(
+            t.transform("alien VS predator x the movie", &mut ctx),
+            Ok("Alien vs Predator x The Movie".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_empty_exceptions_title_cases_everything() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Ttlx::new("");
+        assert_eq!(t.transform("war and peace", &mut ctx), Ok("War And Peace".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        let t = Ttlx::new("vs,x");
+        assert_eq!(t.to_atp_line().as_ref(), "ttlx vs,x;\n");
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Ttlx::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[test]
+    fn transform_honors_turkic_locale() {
+        let t = Ttlx::new("");
+        let mut ctx = GlobalExecutionContext::new();
+        ctx.set_locale(Some("tr"));
+
+        assert_eq!(t.transform("İSTANBUL ILIK", &mut ctx), Ok("İstanbul Ilık".to_string()));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x4f() {
+            let t = Ttlx::default();
+            assert_eq!(t.get_opcode(), 0x4f);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Ttlx::new("of,the");
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x4f);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
 };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_uppercase_with_locale;
 
 #[derive(Clone, Default)]
 pub struct Tua {
@@ -27,8 +28,12 @@ impl InstructionMethods for Tua {
     fn to_atp_line(&self) -> Cow<'static, str> {
         "tua;\n".into()
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        Ok(input.to_uppercase())
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        Ok(to_uppercase_with_locale(input, context.get_locale()))
     }
     #[cfg(feature = "bytecode")]
     fn get_opcode(&self) -> u32 {
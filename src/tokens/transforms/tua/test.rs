@@ -71,6 +71,12 @@ mod tests {
     // ============================
     // Bytecode tests
     // ============================
+    #[test]
+    fn get_args_is_empty() {
+        let t = Tua::default();
+        assert!(t.get_args().is_empty());
+    }
+
     #[cfg(feature = "bytecode")]
     mod bytecode_tests {
         use super::*;
@@ -10,6 +10,7 @@ use crate::{
 };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_uppercase_with_locale;
 /// TUCC - To uppercase Chunk
 ///
 /// Lowercases every character from a chunk delimited by `start_index` and `end_index`(inclusive) in `input`
@@ -46,6 +47,9 @@ impl InstructionMethods for Tucc {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start_index.to_string(), self.end_index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "tucc"
     }
@@ -53,7 +57,11 @@ impl InstructionMethods for Tucc {
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("tucc {} {};\n", self.start_index, self.end_index).into()
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
         check_chunk_bound_indexes(self.start_index, self.end_index, Some(input))?;
 
         // Since the user will probably not know the length of the string in the middle of the processing
@@ -69,7 +77,7 @@ impl InstructionMethods for Tucc {
             .enumerate()
             .map(|(i, c)| {
                 if i >= self.start_index && i <= end {
-                    c.to_uppercase().to_string()
+                    to_uppercase_with_locale(&c.to_string(), context.get_locale())
                 } else {
                     c.to_string()
                 }
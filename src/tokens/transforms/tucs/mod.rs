@@ -6,10 +6,14 @@ use std::borrow::Cow;
 use crate::{
     context::execution_context::GlobalExecutionContext,
     tokens::InstructionMethods,
-    utils::{ errors::AtpError, validations::{ check_index_against_input, check_vec_len } },
+    utils::{
+        errors::AtpError,
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
 };
 
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_uppercase_with_locale;
 
 /// TUCS - To Uppercase Single
 ///
@@ -42,6 +46,9 @@ impl InstructionMethods for Tucs {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "tucs"
     }
@@ -49,12 +56,22 @@ impl InstructionMethods for Tucs {
     fn to_atp_line(&self) -> Cow<'static, str> {
         format!("tucs {};\n", self.index).into()
     }
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        check_index_against_input(self.index, input)?;
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Char, context)? else {
+            return Ok(input.to_string());
+        };
         let result: String = input
             .char_indices()
             .map(|(i, c)| {
-                if i == self.index { c.to_uppercase().to_string() } else { c.to_string() }
+                if i == index {
+                    to_uppercase_with_locale(&c.to_string(), context.get_locale())
+                } else {
+                    c.to_string()
+                }
             })
             .collect();
         Ok(result)
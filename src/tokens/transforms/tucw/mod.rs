@@ -5,9 +5,13 @@ use std::borrow::Cow;
 
 use crate::context::execution_context::GlobalExecutionContext;
 use crate::utils::params::AtpParamTypes;
+use crate::utils::transforms::to_uppercase_with_locale;
 use crate::{
     tokens::InstructionMethods,
-    utils::{ errors::{ AtpError }, validations::{ check_index_against_words, check_vec_len } },
+    utils::{
+        errors::{ AtpError },
+        validations::{ IndexUnit, check_vec_len, resolve_token_index },
+    },
 };
 /// TUCW - To Uppercase Word
 ///
@@ -38,6 +42,9 @@ impl InstructionMethods for Tucw {
     fn get_params(&self) -> &Vec<AtpParamTypes> {
         &self.params
     }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
     fn get_string_repr(&self) -> &'static str {
         "tucw"
     }
@@ -46,14 +53,24 @@ impl InstructionMethods for Tucw {
         format!("tucw {};\n", self.index).into()
     }
 
-    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
-        check_index_against_words(self.index, input)?;
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Word, context)? else {
+            return Ok(input.to_string());
+        };
         Ok(
             input
                 .split_whitespace()
                 .enumerate()
                 .map(|(i, w)| {
-                    if i == self.index { w.to_uppercase() } else { w.to_string() }
+                    if i == index {
+                        to_uppercase_with_locale(w, context.get_locale())
+                    } else {
+                        w.to_string()
+                    }
                 })
                 .collect::<Vec<_>>()
                 .join(" ")
@@ -0,0 +1,102 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::{ borrow::Cow, collections::HashMap };
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// WFREQ - Word Frequency
+///
+/// Counts how many times each whitespace separated word appears in `input` and returns one `word:count`
+/// line per word, sorted by descending count. Words with the same count are sorted alphabetically.
+///
+/// When `case_insensitive` is true, words are lowercased before being counted.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::wfreq::Wfreq};
+///
+/// let token = Wfreq::new(true);
+///
+/// assert_eq!(token.transform("banana Laranja banana laranja canja banana"), Ok("banana:3\nlaranja:2\ncanja:1".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Wfreq {
+    pub case_insensitive: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Wfreq {
+    pub fn new(case_insensitive: bool) -> Self {
+        Wfreq {
+            case_insensitive,
+            params: vec![case_insensitive.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Wfreq {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.case_insensitive.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("wfreq {};\n", self.case_insensitive).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for word in input.split_whitespace() {
+            let key = if self.case_insensitive { word.to_lowercase() } else { word.to_string() };
+            *counts.entry(key).or_insert(0) += 1;
+        }
+
+        let mut entries: Vec<(String, usize)> = counts.into_iter().collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Ok(
+            entries
+                .iter()
+                .map(|(word, count)| format!("{}:{}", word, count))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "wfreq"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "wfreq", "")?;
+
+        self.case_insensitive = parse_args!(params, 0, Bool, "Case insensitive flag should be of bool type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x59
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Bool(self.case_insensitive),
+        ])
+    }
+}
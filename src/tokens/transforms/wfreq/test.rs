@@ -0,0 +1,116 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::wfreq::Wfreq };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_wfreq() {
+        let t = Wfreq::default();
+        assert_eq!(t.get_string_repr(), "wfreq");
+    }
+
+    #[test]
+    fn transform_sorts_by_descending_count() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(false);
+        assert_eq!(
+            t.transform("canja banana laranja banana canja banana", &mut ctx),
+            Ok("banana:3\ncanja:2\nlaranja:1".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_breaks_ties_alphabetically() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(false);
+        let expected = Ok("b:2\nc:2\na:1\nd:1".to_string());
+
+        assert_eq!(t.transform("d c b a c b", &mut ctx), expected);
+        assert_eq!(t.transform("a b c d b c", &mut ctx), expected);
+    }
+
+    #[test]
+    fn transform_case_sensitive_counts_variants_apart() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(false);
+        assert_eq!(
+            t.transform("Banana banana BANANA banana", &mut ctx),
+            Ok("banana:2\nBANANA:1\nBanana:1".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_case_insensitive_merges_variants() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(true);
+        assert_eq!(
+            t.transform("Banana banana BANANA Laranja", &mut ctx),
+            Ok("banana:3\nlaranja:1".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_ignores_repeated_whitespace_and_newlines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(false);
+        assert_eq!(t.transform("  a\n\ta   b  ", &mut ctx), Ok("a:2\nb:1".to_string()));
+    }
+
+    #[test]
+    fn transform_empty_input_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wfreq::new(true);
+        assert_eq!(t.transform("   ", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Wfreq::new(true).to_atp_line().as_ref(), "wfreq true;\n");
+        assert_eq!(Wfreq::new(false).to_atp_line().as_ref(), "wfreq false;\n");
+    }
+
+    #[test]
+    fn from_params_reads_flag() {
+        let mut t = Wfreq::default();
+        t.from_params(&vec![AtpParamTypes::Bool(true)]).unwrap();
+        assert!(t.case_insensitive);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Wfreq::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x59() {
+            let t = Wfreq::default();
+            assert_eq!(t.get_opcode(), 0x59);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Wfreq::new(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x59);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::check_vec_len },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// WRAP - Word Wrap
+///
+/// Wraps each line of `input` so it fits in `width` characters, breaking only between words.
+/// Runs of whitespace between words are collapsed into a single space, and words longer than
+/// `width` are kept whole on a line of their own.
+///
+/// When `hyphenate` is set, words longer than `width` are hard broken instead: each piece takes
+/// `width - 1` characters followed by a `-`, and the rest of the word continues on the next line.
+/// With a `width` of 1 there is no room for the `-`, so words are broken one character per line.
+/// A `width` of 0 returns an `AtpError`.
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::wrap::Wrap};
+///
+/// let token = Wrap::new(10).unwrap();
+/// let hyphenated = Wrap::new(4).unwrap().with_hyphenate(true);
+///
+/// assert_eq!(token.transform("the quick brown fox"), Ok("the quick\nbrown fox".to_string()));
+/// assert_eq!(hyphenated.transform("banana"), Ok("ban-\nana".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Wrap {
+    pub width: usize,
+    pub hyphenate: bool,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Wrap {
+    pub fn new(width: usize) -> Result<Self, AtpError> {
+        check_width(width)?;
+
+        Ok(Wrap { width, hyphenate: false, params: vec![width.into()] })
+    }
+
+    /// Opts into hard breaking words longer than `width` with a trailing `-`.
+    pub fn with_hyphenate(mut self, hyphenate: bool) -> Self {
+        self.params.truncate(1);
+        if hyphenate {
+            self.params.push(hyphenate.into());
+        }
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    fn wrap_line(&self, line: &str, out: &mut Vec<String>) {
+        let mut current = String::new();
+        let mut current_len = 0;
+
+        for word in line.split_whitespace() {
+            let word_len = word.chars().count();
+
+            if self.hyphenate && word_len > self.width {
+                if current_len > 0 {
+                    out.push(std::mem::take(&mut current));
+                }
+
+                let piece_len = if self.width > 1 { self.width - 1 } else { 1 };
+                let mut chars: Vec<char> = word.chars().collect();
+
+                while chars.len() > self.width {
+                    let rest = chars.split_off(piece_len);
+                    let mut piece: String = chars.into_iter().collect();
+                    if self.width > 1 {
+                        piece.push('-');
+                    }
+                    out.push(piece);
+                    chars = rest;
+                }
+
+                current = chars.iter().collect();
+                current_len = chars.len();
+                continue;
+            }
+
+            if current_len == 0 {
+                current.push_str(word);
+                current_len = word_len;
+            } else if current_len + 1 + word_len <= self.width {
+                current.push(' ');
+                current.push_str(word);
+                current_len += 1 + word_len;
+            } else {
+                out.push(std::mem::replace(&mut current, word.to_string()));
+                current_len = word_len;
+            }
+        }
+
+        // Blank lines are kept as they are
+        if current_len > 0 || line.trim().is_empty() {
+            out.push(current);
+        }
+    }
+}
+
+fn check_width(width: usize) -> Result<(), AtpError> {
+    if width == 0 {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Wrap width should be greater than 0".into()),
+                "wrap",
+                width.to_string()
+            )
+        );
+    }
+    Ok(())
+}
+
+impl Default for Wrap {
+    fn default() -> Self {
+        Wrap { width: 80, hyphenate: false, params: vec![80usize.into()] }
+    }
+}
+
+impl InstructionMethods for Wrap {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        if self.hyphenate {
+            vec![self.width.to_string(), self.hyphenate.to_string()]
+        } else {
+            vec![self.width.to_string()]
+        }
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        if self.hyphenate {
+            format!("wrap {} true;\n", self.width).into()
+        } else {
+            format!("wrap {};\n", self.width).into()
+        }
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let mut lines = Vec::new();
+
+        for line in input.split('\n') {
+            self.wrap_line(line, &mut lines);
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "wrap"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, if params.len() == 2 { 2 } else { 1 }, "wrap", "")?;
+
+        let width = parse_args!(params, 0, Usize, "Width should be of usize type");
+        check_width(width)?;
+
+        self.width = width;
+        self.hyphenate = match params.len() {
+            2 => parse_args!(params, 1, Bool, "Hyphenate flag should be of bool type"),
+            _ => false,
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x7d
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        if self.hyphenate {
+            to_bytecode!(self.get_opcode(), [
+                AtpParamTypes::Usize(self.width),
+                AtpParamTypes::Bool(true),
+            ])
+        } else {
+            to_bytecode!(self.get_opcode(), [AtpParamTypes::Usize(self.width)])
+        }
+    }
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::wrap::Wrap };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_wrap() {
+        let t = Wrap::default();
+        assert_eq!(t.get_string_repr(), "wrap");
+    }
+
+    #[test]
+    fn transform_breaks_between_words() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(10).unwrap();
+        assert_eq!(
+            t.transform("the quick  brown fox jumps", &mut ctx),
+            Ok("the quick\nbrown fox\njumps".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_keeps_existing_and_blank_lines() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(5).unwrap();
+        assert_eq!(t.transform("ab cd ef\n\ngh", &mut ctx), Ok("ab cd\nef\n\ngh".to_string()));
+    }
+
+    #[test]
+    fn transform_without_hyphenate_keeps_long_words_whole() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(4).unwrap();
+        assert_eq!(t.transform("xy abcdefghij xy", &mut ctx), Ok("xy\nabcdefghij\nxy".to_string()));
+    }
+
+    #[test]
+    fn transform_hyphenate_word_exactly_at_width() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(4).unwrap().with_hyphenate(true);
+        assert_eq!(t.transform("abcd ef", &mut ctx), Ok("abcd\nef".to_string()));
+    }
+
+    #[test]
+    fn transform_hyphenate_word_just_over_width() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(4).unwrap().with_hyphenate(true);
+        assert_eq!(t.transform("abcde", &mut ctx), Ok("abc-\nde".to_string()));
+    }
+
+    #[test]
+    fn transform_hyphenate_word_many_times_the_width() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(3).unwrap().with_hyphenate(true);
+        assert_eq!(
+            t.transform("x abcdefghij y", &mut ctx),
+            Ok("x\nab-\ncd-\nef-\ngh-\nij\ny".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_hyphenate_width_one_breaks_without_hyphen() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wrap::new(1).unwrap().with_hyphenate(true);
+        assert_eq!(t.transform("abc", &mut ctx), Ok("a\nb\nc".to_string()));
+    }
+
+    #[test]
+    fn new_rejects_zero_width() {
+        let err = Wrap::new(0).err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_reads_optional_hyphenate_flag() {
+        let mut t = Wrap::default();
+        t.from_params(&vec![AtpParamTypes::Usize(2), AtpParamTypes::Bool(true)]).unwrap();
+        assert_eq!((t.width, t.hyphenate), (2, true));
+
+        t.from_params(&vec![AtpParamTypes::Usize(3)]).unwrap();
+        assert_eq!((t.width, t.hyphenate), (3, false));
+
+        let err = t.from_params(&vec![AtpParamTypes::Usize(0)]).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn to_atp_line_includes_flag_only_when_hyphenating() {
+        assert_eq!(Wrap::new(20).unwrap().to_atp_line().as_ref(), "wrap 20;\n");
+        assert_eq!(
+            Wrap::new(20).unwrap().with_hyphenate(true).to_atp_line().as_ref(),
+            "wrap 20 true;\n"
+        );
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Wrap::default();
+        let params = vec![
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1),
+            AtpParamTypes::Usize(1)
+        ];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x7d() {
+            let t = Wrap::default();
+            assert_eq!(t.get_opcode(), 0x7d);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Wrap::new(4).unwrap().with_hyphenate(true);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x7d);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::AtpError, validations::{ IndexUnit, check_vec_len, resolve_token_index } },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// WSEL - Word Select
+///
+/// Returns only the word at `index` (0-based) of `input`, words are separated by whitespace.
+///
+/// It will throw an `AtpError` if `index` does not exist in `input`, unless the processor sets
+/// another [`IndexPolicy`](crate::utils::validations::IndexPolicy).
+///
+/// See Also:
+///
+/// - [`SSLT` - Split Select](crate::tokens::transforms::sslt)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::wsel::Wsel};
+///
+/// let token = Wsel::new(1);
+///
+/// assert_eq!(token.transform("banana laranja cheia de canja"), Ok("laranja".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Wsel {
+    pub index: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Wsel {
+    pub fn new(index: usize) -> Self {
+        Wsel {
+            index,
+            params: vec![index.into()],
+        }
+    }
+}
+
+impl InstructionMethods for Wsel {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.index.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("wsel {};\n", self.index).into()
+    }
+
+    fn transform(
+        &self,
+        input: &str,
+        context: &mut GlobalExecutionContext
+    ) -> Result<String, AtpError> {
+        let Some(index) = resolve_token_index(self.index, input, IndexUnit::Word, context)? else {
+            return Ok(input.to_string());
+        };
+
+        Ok(input.split_whitespace().nth(index).unwrap_or_default().to_string())
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "wsel"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 1, "wsel", "")?;
+
+        self.index = parse_args!(params, 0, Usize, "Index should be of usize type");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x66
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.index),
+        ])
+    }
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::wsel::Wsel };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+    use crate::utils::validations::IndexPolicy;
+
+    #[test]
+    fn get_string_repr_is_wsel() {
+        let t = Wsel::default();
+        assert_eq!(t.get_string_repr(), "wsel");
+    }
+
+    #[test]
+    fn transform_first_word() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wsel::new(0);
+        assert_eq!(t.transform("  banana laranja canja", &mut ctx), Ok("banana".to_string()));
+    }
+
+    #[test]
+    fn transform_middle_word() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wsel::new(2);
+        assert_eq!(
+            t.transform("banana\tlaranja\n cheia  de canja", &mut ctx),
+            Ok("cheia".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_last_word() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wsel::new(2);
+        assert_eq!(t.transform("a b c ", &mut ctx), Ok("c".to_string()));
+    }
+
+    #[test]
+    fn transform_out_of_range_index_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wsel::new(3);
+        let err = t.transform("a b c", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn transform_input_without_words_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let err = Wsel::new(0).transform("   ", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn transform_honors_index_policy() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wsel::new(7);
+
+        ctx.set_index_policy(Some(IndexPolicy::Clamp));
+        assert_eq!(t.transform("a b c", &mut ctx), Ok("c".to_string()));
+
+        ctx.set_index_policy(Some(IndexPolicy::Ignore));
+        assert_eq!(t.transform("a b c", &mut ctx), Ok("a b c".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Wsel::new(4).to_atp_line().as_ref(), "wsel 4;\n");
+    }
+
+    #[test]
+    fn from_params_reads_index() {
+        let mut t = Wsel::default();
+        t.from_params(&vec![AtpParamTypes::Usize(7)]).unwrap();
+        assert_eq!(t.index, 7);
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Wsel::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x66() {
+            let t = Wsel::default();
+            assert_eq!(t.get_opcode(), 0x66);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Wsel::new(4);
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x66);
+            assert_eq!(bc[12] as usize, 1);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(feature = "test_access")]
+pub mod test;
+
+use std::borrow::Cow;
+
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    tokens::InstructionMethods,
+    utils::{ errors::{ AtpError, AtpErrorCode }, validations::{ check_chunk_bound_indexes, check_vec_len } },
+};
+
+use crate::utils::params::AtpParamTypes;
+
+/// WSLICE - Word Slice
+///
+/// Keeps only the words of `input` between `start` and `end` (inclusive), joined by single spaces.
+///
+/// If `end` is bigger than the last word index, it is clamped to it, like in [`Ctr`](crate::tokens::transforms::ctr).
+/// It will throw an `AtpError` if `start` is not smaller than `end`, or if `start` does not exist in `input`.
+///
+/// See Also:
+///
+/// - [`WSEL` - Word Select](crate::tokens::transforms::wsel)
+/// - [`CTR` - Capitalize Range](crate::tokens::transforms::ctr)
+/// - [`LSLICE` - Line Slice](crate::tokens::transforms::lslice)
+///
+/// # Example:
+///
+/// ```rust
+/// use atp::tokens::{InstructionMethods, transforms::wslice::Wslice};
+///
+/// let token = Wslice::new(1, 3).unwrap();
+///
+/// assert_eq!(token.transform("banana laranja cheia de canja"), Ok("laranja cheia de".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Wslice {
+    pub start: usize,
+    pub end: usize,
+    params: Vec<AtpParamTypes>,
+}
+
+impl Wslice {
+    pub fn new(start: usize, end: usize) -> Result<Self, AtpError> {
+        check_chunk_bound_indexes(start, end, None)?;
+
+        Ok(Wslice {
+            start,
+            end,
+            params: vec![start.into(), end.into()],
+        })
+    }
+}
+
+// `start` must stay below `end`, so the default is the first word and not an empty range
+impl Default for Wslice {
+    fn default() -> Self {
+        Wslice {
+            start: 0,
+            end: 1,
+            params: vec![],
+        }
+    }
+}
+
+impl InstructionMethods for Wslice {
+    fn get_params(&self) -> &Vec<AtpParamTypes> {
+        &self.params
+    }
+    fn get_args(&self) -> Vec<String> {
+        vec![self.start.to_string(), self.end.to_string()]
+    }
+    fn to_atp_line(&self) -> Cow<'static, str> {
+        format!("wslice {} {};\n", self.start, self.end).into()
+    }
+
+    fn transform(&self, input: &str, _: &mut GlobalExecutionContext) -> Result<String, AtpError> {
+        let words = input.split_whitespace().collect::<Vec<_>>();
+
+        if words.is_empty() {
+            return Ok(String::new());
+        }
+
+        if self.start >= words.len() {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::IndexOutOfRange(
+                        format!(
+                            "Word index {} does not exist for input, only indexes between 0-{} are allowed!",
+                            self.start,
+                            words.len() - 1
+                        ).into()
+                    ),
+                    self.to_atp_line(),
+                    input.to_string()
+                )
+            );
+        }
+
+        // Same as Ctr, the word count is usually unknown in the middle of a pipeline
+        let end = self.end.min(words.len() - 1);
+
+        Ok(words[self.start..=end].join(" "))
+    }
+
+    fn get_string_repr(&self) -> &'static str {
+        "wslice"
+    }
+
+    fn from_params(&mut self, params: &Vec<AtpParamTypes>) -> Result<(), AtpError> {
+        use crate::parse_args;
+
+        check_vec_len(&params, 2, "wslice", "")?;
+
+        let start = parse_args!(params, 0, Usize, "Index should be of usize type");
+        let end = parse_args!(params, 1, Usize, "Index should be of usize type");
+
+        // Same rule as `new`, so a parsed line can't build a token `new` would reject
+        check_chunk_bound_indexes(start, end, None).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::InvalidParameters("Start index must be smaller than end index".into()),
+                "wslice",
+                e.input
+            )
+        })?;
+
+        self.start = start;
+        self.end = end;
+        self.params = params.clone();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn get_opcode(&self) -> u32 {
+        0x67
+    }
+
+    #[cfg(feature = "bytecode")]
+    fn to_bytecode(&self) -> Vec<u8> {
+        use crate::to_bytecode;
+        to_bytecode!(self.get_opcode(), [
+            AtpParamTypes::Usize(self.start),
+            AtpParamTypes::Usize(self.end),
+        ])
+    }
+}
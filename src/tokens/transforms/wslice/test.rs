@@ -0,0 +1,121 @@
+#![cfg(feature = "test_access")]
+
+#[cfg(test)]
+mod tests {
+    use crate::context::execution_context::GlobalExecutionContext;
+    use crate::tokens::{ InstructionMethods, transforms::wslice::Wslice };
+    use crate::utils::errors::AtpErrorCode;
+    use crate::utils::params::AtpParamTypes;
+
+    #[test]
+    fn get_string_repr_is_wslice() {
+        let t = Wslice::default();
+        assert_eq!(t.get_string_repr(), "wslice");
+    }
+
+    #[test]
+    fn new_rejects_start_not_smaller_than_end() {
+        let err = Wslice::new(3, 3).err().unwrap();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidIndex(_)));
+    }
+
+    #[test]
+    fn transform_mid_range() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wslice::new(1, 3).unwrap();
+        assert_eq!(
+            t.transform("banana  laranja\tcheia\nde canja", &mut ctx),
+            Ok("laranja cheia de".to_string())
+        );
+    }
+
+    #[test]
+    fn transform_clamps_end_beyond_word_count() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wslice::new(2, 100).unwrap();
+        assert_eq!(t.transform("banana laranja cheia de canja", &mut ctx), Ok("cheia de canja".to_string()));
+    }
+
+    #[test]
+    fn transform_start_out_of_range_errors() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wslice::new(5, 8).unwrap();
+        let err = t.transform("a b c", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+    }
+
+    #[test]
+    fn transform_input_without_words_is_empty() {
+        let mut ctx = GlobalExecutionContext::new();
+        let t = Wslice::new(0, 1).unwrap();
+        assert_eq!(t.transform("  ", &mut ctx), Ok("".to_string()));
+    }
+
+    #[test]
+    fn to_atp_line_exact_format() {
+        assert_eq!(Wslice::new(1, 4).unwrap().to_atp_line().as_ref(), "wslice 1 4;\n");
+    }
+
+    #[test]
+    fn from_params_reads_indexes() {
+        let mut t = Wslice::default();
+        t.from_params(&vec![AtpParamTypes::Usize(2), AtpParamTypes::Usize(5)]).unwrap();
+        assert_eq!((t.start, t.end), (2, 5));
+    }
+
+    #[test]
+    fn from_params_rejects_start_not_smaller_than_end() {
+        let mut t = Wslice::default();
+
+        for (start, end) in [(3, 1), (2, 2)] {
+            let params = vec![AtpParamTypes::Usize(start), AtpParamTypes::Usize(end)];
+            let err = t.from_params(&params).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+        }
+    }
+
+    #[test]
+    fn parsed_line_with_reversed_bounds_is_an_error() {
+        use crate::text::reader::read_from_text;
+
+        let mut ctx = GlobalExecutionContext::new();
+        let token = read_from_text("wslice 3 1;").unwrap();
+
+        let err = token.apply_token("a b c d e f", &mut ctx).unwrap_err();
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidParameters(_)));
+    }
+
+    #[test]
+    fn from_params_rejects_wrong_param_count() {
+        let mut t = Wslice::default();
+        let params = vec![AtpParamTypes::Usize(1), AtpParamTypes::Usize(1), AtpParamTypes::Usize(1)];
+
+        let err = t.from_params(&params).unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::InvalidArgumentNumber(_)));
+    }
+
+    #[cfg(feature = "bytecode")]
+    mod bytecode_tests {
+        use super::*;
+
+        #[test]
+        fn get_opcode_is_0x67() {
+            let t = Wslice::default();
+            assert_eq!(t.get_opcode(), 0x67);
+        }
+
+        #[test]
+        fn to_bytecode_has_expected_header() {
+            let t = Wslice::new(1, 4).unwrap();
+            let bc = t.to_bytecode();
+
+            let total_size = u64::from_be_bytes(bc[0..8].try_into().unwrap()) as usize;
+            let opcode = u32::from_be_bytes(bc[8..12].try_into().unwrap());
+
+            assert_eq!(total_size, bc.len() - 8);
+            assert_eq!(opcode, 0x67);
+            assert_eq!(bc[12] as usize, 2);
+        }
+    }
+}
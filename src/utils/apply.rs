@@ -7,12 +7,15 @@ use crate::{
 pub fn apply_transform(
     token: &TokenWrapper,
     input: &str,
+    position: usize,
+    pipeline_id: &str,
     error_manager: &mut ErrorManager,
     context: &mut GlobalExecutionContext
 ) -> Result<String, AtpError> {
     match token.apply_token(input, &mut *context) {
         Ok(x) => Ok(x),
         Err(e) => {
+            let e = e.with_position(position).with_pipeline_id(pipeline_id);
             error_manager.add_error(e.clone());
             Err(e)
         }
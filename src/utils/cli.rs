@@ -1,9 +1,8 @@
 use std::borrow::Cow;
 
-use crate::{
-    api::atp_processor::{ AtpProcessor, AtpProcessorMethods },
-    utils::{ errors::AtpError, transforms::get_safe_utf8_char_index },
-};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{ api::atp_processor::{ AtpProcessor, AtpProcessorMethods }, utils::errors::AtpError };
 
 fn process_run(
     processor: &mut AtpProcessor,
@@ -51,11 +50,74 @@ pub fn process_input_line_by_line(
     Ok(text_vec.join("\n"))
 }
 
+/// Unit used to measure the chunks of `process_input_by_chunks`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ChunkUnit {
+    Chars,
+    Bytes,
+    Graphemes,
+}
+
+/// Splits `input` in chunks of `chunk_size` units, the last chunk may be shorter.
+///
+/// With `ChunkUnit::Bytes` a chunk never ends inside a multibyte character: it is cut at the last
+/// character boundary that fits, so chunks may have less than `chunk_size` bytes, and a character
+/// longer than `chunk_size` bytes gets a chunk of its own.
+fn split_chunks(input: &str, chunk_size: usize, unit: ChunkUnit) -> Vec<&str> {
+    let mut chunks = Vec::new();
+
+    match unit {
+        ChunkUnit::Bytes => {
+            let mut start = 0;
+
+            while start < input.len() {
+                let mut end = (start + chunk_size).min(input.len());
+                while !input.is_char_boundary(end) {
+                    end -= 1;
+                }
+                if end == start {
+                    end = input[start..]
+                        .chars()
+                        .next()
+                        .map_or(input.len(), |c| start + c.len_utf8());
+                }
+
+                chunks.push(&input[start..end]);
+                start = end;
+            }
+        }
+        ChunkUnit::Chars | ChunkUnit::Graphemes => {
+            let starts: Vec<usize> = match unit {
+                ChunkUnit::Graphemes =>
+                    input
+                        .grapheme_indices(true)
+                        .map(|(i, _)| i)
+                        .collect(),
+                _ =>
+                    input
+                        .char_indices()
+                        .map(|(i, _)| i)
+                        .collect(),
+            };
+
+            let mut bounds: Vec<usize> = starts.into_iter().step_by(chunk_size).collect();
+            bounds.push(input.len());
+
+            for pair in bounds.windows(2) {
+                chunks.push(&input[pair[0]..pair[1]]);
+            }
+        }
+    }
+
+    chunks
+}
+
 pub fn process_input_by_chunks(
     processor: &mut AtpProcessor,
     identifier: &str,
     input: &str,
     chunk_size: usize,
+    unit: ChunkUnit,
     debug: bool
 ) -> Result<String, AtpError> {
     if input.is_empty() {
@@ -70,30 +132,13 @@ pub fn process_input_by_chunks(
             )
         );
     }
-    let character_count = input.chars().count();
-    let iterations = character_count.div_ceil(chunk_size);
 
     let mut processed = Vec::new();
 
-    for i in 0..iterations - 1 {
-        let left_chunk_bound = get_safe_utf8_char_index(i * chunk_size, input)?;
-        let right_chunk_bound = get_safe_utf8_char_index((i + 1) * chunk_size, input)?;
-
-        let result = process_run(
-            processor,
-            identifier,
-            &input[left_chunk_bound..right_chunk_bound],
-            debug
-        )?;
-
-        processed.push(result);
+    for chunk in split_chunks(input, chunk_size, unit) {
+        processed.push(process_run(processor, identifier, chunk, debug)?);
     }
 
-    // Último chunk
-    let left_chunk_bound = get_safe_utf8_char_index((iterations - 1) * chunk_size, input)?;
-    let result = process_run(processor, identifier, &input[left_chunk_bound..], debug)?;
-    processed.push(result);
-
     Ok(processed.join(""))
 }
 
@@ -103,7 +148,7 @@ mod cli_tests {
     mod process_input_by_chunks_tests {
         use crate::{
             api::{ AtpBuilderMethods, atp_processor::AtpProcessor },
-            utils::{ cli::process_input_by_chunks, errors::AtpError },
+            utils::{ cli::{ ChunkUnit, process_input_by_chunks }, errors::AtpError },
         };
 
         #[test]
@@ -114,13 +159,70 @@ mod cli_tests {
 
             let input = "coxinha";
             let expected_output = "bcolbxilbnhlbal".to_string();
-            let result = process_input_by_chunks(&mut processor, &id, input, 2, true)?;
+            let result = process_input_by_chunks(
+                &mut processor,
+                &id,
+                input,
+                2,
+                ChunkUnit::Chars,
+                true
+            )?;
 
             println!("Resultado: {}", result);
 
             assert_eq!(result, expected_output, "It works correctly");
             Ok(())
         }
+
+        #[test]
+        fn chunk_units_split_multibyte_input() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().add_to_end("|")?.build();
+
+            // "é" is 2 bytes, "👍🏽" is a single grapheme made of 2 chars (4 bytes each)
+            let input = "aé👍🏽b";
+
+            let by_chars = process_input_by_chunks(
+                &mut processor,
+                &id,
+                input,
+                2,
+                ChunkUnit::Chars,
+                false
+            )?;
+            let by_graphemes = process_input_by_chunks(
+                &mut processor,
+                &id,
+                input,
+                2,
+                ChunkUnit::Graphemes,
+                false
+            )?;
+
+            assert_eq!(by_chars, "aé|👍🏽|b|");
+            assert_eq!(by_graphemes, "aé|👍🏽b|");
+            Ok(())
+        }
+
+        #[test]
+        fn byte_chunks_never_split_multibyte_chars() -> Result<(), AtpError> {
+            let mut processor = AtpProcessor::new();
+            let id = processor.create_pipeline().add_to_end("|")?.build();
+
+            let result = process_input_by_chunks(
+                &mut processor,
+                &id,
+                "aéb👍c",
+                2,
+                ChunkUnit::Bytes,
+                false
+            )?;
+
+            // The chunk is cut before a char that does not fit, chars bigger than the chunk size
+            // get a chunk of their own
+            assert_eq!(result, "a|é|b|👍|c|");
+            Ok(())
+        }
     }
 
     mod process_input_line_by_line_tests {
@@ -17,6 +17,10 @@ pub struct AtpError {
     pub error_code: AtpErrorCode,
     pub instruction: Cow<'static, str>,
     pub input: Cow<'static, str>,
+    /// Index of the failing token inside its pipeline, filled by the processor.
+    pub position: Option<usize>,
+    /// Identifier of the pipeline that was running when the error happened.
+    pub pipeline_id: Option<String>,
 }
 
 impl Error for AtpError {}
@@ -37,7 +41,16 @@ impl Display for AtpError {
             self.instruction.as_ref().cyan(),
             input_label,
             self.input.as_ref().dimmed()
-        )
+        )?;
+
+        if let Some(position) = self.position {
+            write!(f, "\n{} {}", "Position:".bold().blue(), position)?;
+        }
+        if let Some(pipeline_id) = &self.pipeline_id {
+            write!(f, "\n{} {}", "Pipeline:".bold().blue(), pipeline_id)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -49,9 +62,23 @@ impl AtpError {
             error_code,
             instruction: instruction.into(),
             input: input.into(),
+            position: None,
+            pipeline_id: None,
         }
     }
 
+    /// Records the index of the token that produced this error.
+    pub fn with_position(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Records the identifier of the pipeline that produced this error.
+    pub fn with_pipeline_id<S: Into<String>>(mut self, pipeline_id: S) -> Self {
+        self.pipeline_id = Some(pipeline_id.into());
+        self
+    }
+
     // Cheap helpers (do not change public fields; just convenience)
     pub fn instruction_str(&self) -> &str {
         self.instruction.as_ref()
@@ -309,6 +336,28 @@ mod tests {
 
         assert_eq!(err.instruction_str(), "raw");
         assert_eq!(err.input_str(), "banana");
+        assert_eq!(err.position, None);
+        assert_eq!(err.pipeline_id, None);
+    }
+
+    #[test]
+    fn atp_error_with_position_and_pipeline_id_are_shown_in_display() {
+        disable_colors();
+
+        let err = AtpError::new(
+            AtpErrorCode::IndexOutOfRange(Cow::Borrowed("out of range")),
+            "dlc",
+            "banana"
+        )
+            .with_position(2)
+            .with_pipeline_id("pipeline-1");
+
+        assert_eq!(err.position, Some(2));
+        assert_eq!(err.pipeline_id.as_deref(), Some("pipeline-1"));
+
+        let s = format!("{err}");
+        assert!(s.contains("Position: 2"));
+        assert!(s.contains("Pipeline: pipeline-1"));
     }
 
     #[test]
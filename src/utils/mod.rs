@@ -1,6 +1,7 @@
 pub mod cli;
 pub mod errors;
 pub mod params;
+pub mod regex_cache;
 pub mod transforms;
 pub mod validations;
 pub mod apply;
@@ -1,7 +1,7 @@
 // params.rs
 // Reescrito para suportar:
 // - Texto: retorna Vec<ValType> (Literal / VarRef) via sintaxe {{nome}}
-// - Bytecode: 0x01 String, 0x02 Usize, 0x03 Token, 0x04 VarRef
+// - Bytecode: 0x01 String, 0x02 Usize, 0x03 Token, 0x04 VarRef, 0x05 Bool, 0x06 I64
 // - PARAM_TOKEN: constrói TokenWrapper(params: Vec<ValType>, token: Box<dyn InstructionMethods>)
 //   (não chama from_params aqui; isso fica pro runtime no TokenWrapper)
 
@@ -16,10 +16,17 @@ use crate::context::execution_context::GlobalExecutionContext;
 use crate::{
     globals::{
         table::{ QuerySource, QueryTarget, SyntaxDef, SyntaxToken, TOKEN_TABLE, TargetValue },
-        var::{ TokenWrapper, ValType },
+        var::{
+            TokenWrapper,
+            ValType,
+            get_effective_param_type,
+            get_max_param_count,
+            get_required_param_count,
+            iter_syntax,
+        },
     },
     tokens::InstructionMethods,
-    utils::{ errors::{ AtpError, AtpErrorCode }, transforms::string_to_usize },
+    utils::{ errors::{ AtpError, AtpErrorCode }, transforms::{ string_to_bool, string_to_i64, string_to_usize } },
 };
 
 /// Tipos resolvidos (sem variáveis pendentes)
@@ -29,6 +36,8 @@ pub enum AtpParamTypes {
     Usize(usize),
     Token(TokenWrapper),
     VarRef(String),
+    Bool(bool),
+    I64(i64),
 }
 
 // --------------------------
@@ -47,6 +56,12 @@ impl From<usize> for AtpParamTypes {
     }
 }
 
+impl From<bool> for AtpParamTypes {
+    fn from(value: bool) -> Self {
+        AtpParamTypes::Bool(value)
+    }
+}
+
 impl From<TokenWrapper> for AtpParamTypes {
     fn from(value: TokenWrapper) -> Self {
         AtpParamTypes::Token(value)
@@ -66,6 +81,8 @@ impl TryFrom<AtpParamTypes> for String {
             AtpParamTypes::Usize(v) => v.to_string(),
             AtpParamTypes::Token(v) => v.to_text_line_unresolved()?,
             AtpParamTypes::VarRef(v) => v,
+            AtpParamTypes::Bool(v) => v.to_string(),
+            AtpParamTypes::I64(v) => v.to_string(),
         })
     }
 }
@@ -97,6 +114,25 @@ impl TryFrom<AtpParamTypes> for usize {
     }
 }
 
+impl TryFrom<AtpParamTypes> for bool {
+    type Error = AtpError;
+    fn try_from(value: AtpParamTypes) -> Result<Self, AtpError> {
+        match value {
+            AtpParamTypes::Bool(v) => Ok(v),
+            _ =>
+                Err(
+                    AtpError::new(
+                        AtpErrorCode::TryIntoFailError(
+                            "Failed conversion from AtpParamTypes to bool".into()
+                        ),
+                        "TryFrom<AtpParamTypes> for bool",
+                        ""
+                    )
+                ),
+        }
+    }
+}
+
 impl TryFrom<AtpParamTypes> for TokenWrapper {
     type Error = AtpError;
     fn try_from(value: AtpParamTypes) -> Result<Self, AtpError> {
@@ -116,6 +152,25 @@ impl TryFrom<AtpParamTypes> for TokenWrapper {
     }
 }
 
+impl TryFrom<AtpParamTypes> for i64 {
+    type Error = AtpError;
+    fn try_from(value: AtpParamTypes) -> Result<Self, AtpError> {
+        match value {
+            AtpParamTypes::I64(v) => Ok(v),
+            _ =>
+                Err(
+                    AtpError::new(
+                        AtpErrorCode::TryIntoFailError(
+                            "Failed conversion from AtpParamTypes to i64".into()
+                        ),
+                        "TryFrom<AtpParamTypes> for i64",
+                        ""
+                    )
+                ),
+        }
+    }
+}
+
 /// Debug customizado para evitar exigir Debug em dyn InstructionMethods
 impl std::fmt::Debug for AtpParamTypes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -124,6 +179,8 @@ impl std::fmt::Debug for AtpParamTypes {
             AtpParamTypes::Usize(n) => f.debug_tuple("Usize").field(n).finish(),
             AtpParamTypes::Token(t) => f.debug_tuple("Token").field(&t.get_string_repr()).finish(),
             AtpParamTypes::VarRef(s) => f.debug_tuple("VarRef").field(s).finish(),
+            AtpParamTypes::Bool(b) => f.debug_tuple("Bool").field(b).finish(),
+            AtpParamTypes::I64(n) => f.debug_tuple("I64").field(n).finish(),
         }
     }
 }
@@ -163,6 +220,8 @@ const PARAM_STRING: u32 = 0x01;
 const PARAM_USIZE: u32 = 0x02;
 const PARAM_TOKEN: u32 = 0x03;
 const PARAM_VARREF: u32 = 0x04;
+const PARAM_BOOL: u32 = 0x05;
+const PARAM_I64: u32 = 0x06;
 
 impl AtpParamTypes {
     pub fn to_string(&self) -> String {
@@ -171,6 +230,8 @@ impl AtpParamTypes {
             AtpParamTypes::VarRef(payload) => payload.to_string(),
             AtpParamTypes::Usize(payload) => payload.to_string(),
             AtpParamTypes::Token(payload) => payload.to_atp_line().into(),
+            AtpParamTypes::Bool(payload) => payload.to_string(),
+            AtpParamTypes::I64(payload) => payload.to_string(),
         }
     }
 
@@ -220,7 +281,12 @@ impl AtpParamTypes {
         let mut out: Vec<ValType> = Vec::with_capacity(expected.len());
         let this_is_block_like = Self::is_block_like_signature(&expected);
 
-        for p in expected.iter() {
+        for p in iter_syntax(&expected) {
+            // Trailing optional params may simply be left out of the line.
+            if p.optional && chunks.get(i).is_none() {
+                break;
+            }
+
             match p.token {
                 SyntaxToken::Literal(expected_literal) => {
                     let literal = chunks
@@ -298,6 +364,34 @@ impl AtpParamTypes {
                     i += 1;
                 }
 
+                SyntaxToken::Bool => {
+                    let s = chunks
+                        .get(i)
+                        .ok_or_else(|| {
+                            AtpError::new(
+                                AtpErrorCode::TextParsingError("Missing Bool parameter".into()),
+                                "AtpParamTypes::parse_with_cursor",
+                                format!("index={}", i)
+                            )
+                        })?;
+                    out.push(ValType::Literal(AtpParamTypes::Bool(string_to_bool(s)?)));
+                    i += 1;
+                }
+
+                SyntaxToken::I64 => {
+                    let s = chunks
+                        .get(i)
+                        .ok_or_else(|| {
+                            AtpError::new(
+                                AtpErrorCode::TextParsingError("Missing I64 parameter".into()),
+                                "AtpParamTypes::parse_with_cursor",
+                                format!("index={}", i)
+                            )
+                        })?;
+                    out.push(ValType::Literal(AtpParamTypes::I64(string_to_i64(s)?)));
+                    i += 1;
+                }
+
                 SyntaxToken::Token => {
                     let child_assoc_mode = if assoc_mode == AssocMode::AssocPayload {
                         AssocMode::AssocPayload
@@ -412,17 +506,6 @@ impl AtpParamTypes {
             matches!(expected[2].token, SyntaxToken::Token)
     }
 
-    fn effective_syntax_tokens(expected: &Arc<[SyntaxDef]>) -> Vec<SyntaxToken> {
-        let mut out = Vec::with_capacity(expected.len());
-        for ip in expected.iter() {
-            if matches!(ip.token, SyntaxToken::Literal(_)) {
-                continue;
-            }
-            out.push(ip.token);
-        }
-        out
-    }
-
     // --------------------------
     // Parsing de Bytecode -> AtpParamTypes (raiz) / ValType (params internos)
     // --------------------------
@@ -574,6 +657,39 @@ impl AtpParamTypes {
                 Ok(AtpParamTypes::Usize(usize::from_be_bytes(b)))
             }
 
+            PARAM_I64 => {
+                let b: [u8; 8] = payload
+                    .as_slice()
+                    .try_into()
+                    .map_err(|e: TryFromSliceError| {
+                        AtpError::new(
+                            AtpErrorCode::BytecodeParamParsingError(
+                                "Failed parsing bytes to i64".into()
+                            ),
+                            "AtpParamTypes::from_bytecode(I64)",
+                            e.to_string()
+                        )
+                    })?;
+                Ok(AtpParamTypes::I64(i64::from_be_bytes(b)))
+            }
+
+            PARAM_BOOL => {
+                match payload.as_slice() {
+                    [0] => Ok(AtpParamTypes::Bool(false)),
+                    [1] => Ok(AtpParamTypes::Bool(true)),
+                    _ =>
+                        Err(
+                            AtpError::new(
+                                AtpErrorCode::BytecodeParamParsingError(
+                                    "Failed parsing bytes to bool".into()
+                                ),
+                                "AtpParamTypes::from_bytecode(Bool)",
+                                format!("{:?}", payload)
+                            )
+                        ),
+                }
+            }
+
             PARAM_VARREF => {
                 // VarRef só deveria existir dentro de Token params (ValType),
                 // mas se aparecer aqui como raiz, retorna erro claro.
@@ -608,16 +724,17 @@ impl AtpParamTypes {
                     _ => unreachable!(),
                 };
 
-                let expected_effective = Self::effective_syntax_tokens(&expected);
-                if param_count != expected_effective.len() {
+                let required = get_required_param_count(&expected);
+                let max = get_max_param_count(&expected);
+                if param_count < required || max.is_some_and(|max| param_count > max) {
                     return Err(
                         AtpError::new(
                             AtpErrorCode::BytecodeParsingError("Param count mismatch".into()),
                             "AtpParamTypes::from_bytecode(Token.param_count)",
                             format!(
-                                "opcode=0x{:X}, expected_effective={}, got={}",
+                                "opcode=0x{:X}, expected_effective={:?}, got={}",
                                 opcode,
-                                expected_effective.len(),
+                                max,
                                 param_count
                             )
                         )
@@ -673,7 +790,7 @@ impl AtpParamTypes {
                         AssocMode::AssocPayload
                     } else if
                         this_is_block_like &&
-                        matches!(expected_effective[idx], SyntaxToken::Token)
+                        matches!(get_effective_param_type(&expected, idx), Some(SyntaxToken::Token))
                     {
                         AssocMode::AssocPayload
                     } else {
@@ -949,6 +1066,8 @@ impl AtpParamTypes {
             AtpParamTypes::Usize(_) => PARAM_USIZE,
             AtpParamTypes::Token(_) => PARAM_TOKEN,
             AtpParamTypes::VarRef(_) => PARAM_VARREF,
+            AtpParamTypes::Bool(_) => PARAM_BOOL,
+            AtpParamTypes::I64(_) => PARAM_I64,
         }
     }
 
@@ -956,15 +1075,18 @@ impl AtpParamTypes {
     pub fn write_as_instruction_param(
         &self,
         out: &mut Vec<u8>,
-        context: &mut GlobalExecutionContext
+        _context: &mut GlobalExecutionContext
     ) -> Result<(), AtpError> {
         let param_type = self.get_param_type_code();
 
         let payload: Vec<u8> = match self {
             AtpParamTypes::String(s) => s.as_bytes().to_vec(),
             AtpParamTypes::Usize(n) => n.to_be_bytes().to_vec(),
-            AtpParamTypes::Token(t) => t.to_bytecode_resolved(context)?,
+            // Nested tokens are stored without their own u64 size, the payload size already covers it
+            AtpParamTypes::Token(t) => t.to_bytecode_unresolved()?[8..].to_vec(),
             AtpParamTypes::VarRef(s) => s.as_bytes().to_vec(),
+            AtpParamTypes::Bool(b) => vec![*b as u8],
+            AtpParamTypes::I64(n) => n.to_be_bytes().to_vec(),
         };
 
         let payload_size_u32: u32 = payload.len() as u32;
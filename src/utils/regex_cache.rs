@@ -0,0 +1,93 @@
+use std::{ collections::HashMap, sync::{ Arc, LazyLock, Mutex } };
+
+use regex::Regex;
+
+use crate::utils::errors::{ AtpError, AtpErrorCode };
+
+/// Compiled regexes shared by every token instance, keyed by their source pattern.
+static REGEX_CACHE: LazyLock<Mutex<HashMap<String, Arc<Regex>>>> = LazyLock::new(||
+    Mutex::new(HashMap::new())
+);
+
+/// Returns the compiled `Regex` for `pattern`, compiling and caching it on first use.
+///
+/// Tokens built with the same pattern share the same `Arc`, so big pipelines and short-lived
+/// processors do not compile identical patterns again. Invalid patterns are not cached.
+///
+/// # Errors
+/// Returns `TextParsingError` if `pattern` is not a valid regex.
+pub fn get_or_compile(pattern: &str) -> Result<Arc<Regex>, AtpError> {
+    // A poisoned lock only means another thread panicked while holding it, the map is still valid
+    let mut cache = REGEX_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(Arc::clone(regex));
+    }
+
+    let regex = Arc::new(
+        Regex::new(pattern).map_err(|e| {
+            AtpError::new(
+                AtpErrorCode::TextParsingError(format!("Failed to create regex: {}", e).into()),
+                "regex_cache",
+                pattern.to_string()
+            )
+        })?
+    );
+
+    cache.insert(pattern.to_string(), Arc::clone(&regex));
+
+    Ok(regex)
+}
+
+#[cfg(feature = "test_access")]
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        tokens::{ InstructionMethods, transforms::{ raw::Raw, rfw::Rfw } },
+        utils::{ errors::AtpErrorCode, params::AtpParamTypes, regex_cache::get_or_compile },
+    };
+
+    #[test]
+    fn same_pattern_returns_the_same_arc() {
+        let a = get_or_compile(r"regex_cache_test_\d+").unwrap();
+        let b = get_or_compile(r"regex_cache_test_\d+").unwrap();
+
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn different_patterns_are_different_regexes() {
+        let a = get_or_compile("regex_cache_a").unwrap();
+        let b = get_or_compile("regex_cache_b").unwrap();
+
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn invalid_pattern_is_a_text_parsing_error() {
+        let err = get_or_compile("regex_cache_(").unwrap_err();
+
+        assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+    }
+
+    #[test]
+    fn tokens_with_the_same_pattern_share_the_regex() {
+        let first = Rfw::new("regex_cache_token", "x").unwrap();
+        let second = Raw::new("regex_cache_token", "y").unwrap();
+
+        let mut parsed = Raw::default();
+        parsed
+            .from_params(
+                &vec![
+                    AtpParamTypes::String("regex_cache_token".to_string()),
+                    AtpParamTypes::String("z".to_string())
+                ]
+            )
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&first.pattern, &second.pattern));
+        assert!(Arc::ptr_eq(&first.pattern, &parsed.pattern));
+    }
+}
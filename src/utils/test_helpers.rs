@@ -1,6 +1,7 @@
 use crate::api::{ AtpBlockMethods, AtpConditionalMethods };
 use crate::utils::errors::AtpError;
 use crate::api::{ AtpBuilderMethods, atp_processor::AtpProcessor };
+use crate::tokens::InstructionMethods;
 
 pub fn build_all_tokens_pipeline_safe(processor: &mut AtpProcessor) -> Result<String, AtpError> {
     let id = processor
@@ -112,3 +113,66 @@ pub fn build_all_tokens_pipeline_safe(processor: &mut AtpProcessor) -> Result<St
 
     Ok(id)
 }
+
+/// Encodes `token` with `to_bytecode`, reads it back through the `.atpbc` reader (which rebuilds
+/// the token with `from_params`) and checks that the decoded token has the same `.atp` line.
+#[cfg(feature = "bytecode")]
+pub fn bytecode_roundtrip(token: &dyn InstructionMethods) -> Result<(), AtpError> {
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+
+    use crate::bytecode::reader::read_bytecode_from_file;
+    use crate::utils::errors::AtpErrorCode;
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let mut bytes: Vec<u8> = vec![38, 235, 245, 8, 244, 137, 1, 179];
+    bytes.extend_from_slice(&(1u64).to_be_bytes());
+    bytes.extend_from_slice(&(1u32).to_be_bytes());
+    bytes.extend_from_slice(&token.to_bytecode());
+
+    let mut path = std::env::temp_dir();
+    path.push(
+        format!(
+            "atp_roundtrip_{}_{}_{}.atpbc",
+            token.get_string_repr(),
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    );
+    std::fs::write(&path, &bytes).map_err(|e| {
+        AtpError::new(
+            AtpErrorCode::FileWritingError("Failed writing roundtrip file".into()),
+            "bytecode_roundtrip",
+            e.to_string()
+        )
+    })?;
+
+    let decoded = read_bytecode_from_file(&path);
+    let _ = std::fs::remove_file(&path);
+    let decoded = decoded?;
+
+    let line = match decoded.as_slice() {
+        [wrapper] => wrapper.to_text_line_unresolved()?,
+        _ => {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::BytecodeParsingError("Expected exactly one token".into()),
+                    "bytecode_roundtrip",
+                    token.to_atp_line()
+                )
+            );
+        }
+    };
+
+    if line != token.to_atp_line() {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::ValidationError("Bytecode roundtrip changed the token".into()),
+                token.to_atp_line(),
+                line
+            )
+        );
+    }
+
+    Ok(())
+}
@@ -24,12 +24,48 @@ pub fn string_to_usize(chunk: &str) -> Result<usize, AtpError> {
     }
 }
 
+pub fn string_to_i64(chunk: &str) -> Result<i64, AtpError> {
+    chunk
+        .strip_suffix(";")
+        .unwrap_or(chunk)
+        .parse()
+        .map_err(|_| {
+            AtpError::new(
+                super::errors::AtpErrorCode::TextParsingError("String to i64 Parsing failed".into()),
+                Cow::Owned(chunk.to_string()),
+                chunk.to_string()
+            )
+        })
+}
+
+pub fn string_to_bool(chunk: &str) -> Result<bool, AtpError> {
+    match chunk.strip_suffix(";").unwrap_or(chunk) {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ =>
+            Err(
+                AtpError::new(
+                    super::errors::AtpErrorCode::TextParsingError(
+                        "String to bool Parsing failed".into()
+                    ),
+                    Cow::Owned(chunk.to_string()),
+                    chunk.to_string()
+                )
+            ),
+    }
+}
+
 pub fn capitalize(input: &str) -> String {
+    capitalize_with_locale(input, None)
+}
+
+/// Same as `capitalize`, but uppercasing the first char with `to_uppercase_with_locale`.
+pub fn capitalize_with_locale(input: &str, locale: Option<&str>) -> String {
     let mut chars = input.chars();
 
     match chars.next() {
         Some(x) => {
-            let f = x.to_uppercase().to_string();
+            let f = to_uppercase_with_locale(&x.to_string(), locale);
             let r: String = chars.collect();
             format!("{}{}", f, r)
         }
@@ -37,15 +73,94 @@ pub fn capitalize(input: &str) -> String {
     }
 }
 
-pub fn extend_string(input: &str, max_len: usize) -> String {
-    if input.is_empty() || max_len == 0 {
-        return String::new();
+/// Boundaries used by the capitalization tokens when `smart` is enabled, so
+/// `o'brien` becomes `O'Brien` and `jean-paul` becomes `Jean-Paul`.
+pub const SMART_CAPITALIZE_BOUNDARIES: &[char] = &['\'', '-'];
+
+/// Same as `capitalize`, but also uppercases every char that follows one of `boundaries`.
+///
+/// The rest of `word` is kept as is. Note that contractions are not special cased,
+/// so with `'` as a boundary `don't` becomes `Don'T`.
+pub fn capitalize_smart(word: &str, boundaries: &[char]) -> String {
+    capitalize_smart_with_locale(word, boundaries, None)
+}
+
+/// Same as `capitalize_smart`, but uppercasing with `to_uppercase_with_locale`.
+pub fn capitalize_smart_with_locale(
+    word: &str,
+    boundaries: &[char],
+    locale: Option<&str>
+) -> String {
+    let mut result = String::with_capacity(word.len());
+    let mut upper_next = true;
+
+    for c in word.chars() {
+        if upper_next {
+            result.push_str(&to_uppercase_with_locale(&c.to_string(), locale));
+        } else {
+            result.push(c);
+        }
+        upper_next = boundaries.contains(&c);
+    }
+
+    result
+}
+
+/// Locales whose case mapping differs from the default Unicode one for `i`/`I`
+/// (dotted `İ` and dotless `ı`).
+fn is_turkic_locale(locale: Option<&str>) -> bool {
+    match locale {
+        Some(l) => {
+            let language = l.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+            language == "tr" || language == "az"
+        }
+        None => false,
+    }
+}
+
+/// Same as `str::to_uppercase`, but honoring the `locale` set in the execution context.
+///
+/// Without a locale (or with one that has no special rules) the default Unicode mapping is used.
+pub fn to_uppercase_with_locale(input: &str, locale: Option<&str>) -> String {
+    if !is_turkic_locale(locale) {
+        return input.to_uppercase();
     }
-    let to_repeat = max_len.div_ceil(input.chars().count());
 
-    let repeated_string = input.repeat(to_repeat).chars().take(max_len).collect();
+    input
+        .chars()
+        .map(|c| {
+            match c {
+                'i' => "İ".to_string(),
+                _ => c.to_uppercase().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Same as `str::to_lowercase`, but honoring the `locale` set in the execution context.
+///
+/// Without a locale (or with one that has no special rules) the default Unicode mapping is used.
+pub fn to_lowercase_with_locale(input: &str, locale: Option<&str>) -> String {
+    if !is_turkic_locale(locale) {
+        return input.to_lowercase();
+    }
 
-    repeated_string
+    input
+        .chars()
+        .map(|c| {
+            match c {
+                'I' => "ı".to_string(),
+                'İ' => "i".to_string(),
+                _ => c.to_lowercase().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Repeats `input` until the result has exactly `max_len` characters, cutting the last
+/// repetition short when needed. Returns an empty string if `input` is empty.
+pub fn extend_string(input: &str, max_len: usize) -> String {
+    input.chars().cycle().take(max_len).collect()
 }
 
 pub fn get_safe_utf8_char_index(index: usize, input: &str) -> Result<usize, AtpError> {
@@ -64,6 +179,77 @@ pub fn get_safe_utf8_char_index(index: usize, input: &str) -> Result<usize, AtpE
     )
 }
 
+/// Maps accented latin characters to their closest ASCII spelling ("é" -> "e", "ß" -> "ss").
+///
+/// Characters without a known mapping are kept as they are.
+pub fn ascii_fold(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match fold_char(c) {
+            Some(folded) => result.push_str(folded),
+            None => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ď' | 'đ' | 'ð' => "d",
+        'Ď' | 'Đ' | 'Ð' => "D",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĥ' | 'ħ' => "h",
+        'Ĥ' | 'Ħ' => "H",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ĵ' => "j",
+        'Ĵ' => "J",
+        'ķ' => "k",
+        'Ķ' => "K",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'þ' => "th",
+        'Þ' => "TH",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ŵ' => "w",
+        'Ŵ' => "W",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ý' | 'Ÿ' | 'Ŷ' => "Y",
+        'ź' | 'ż' | 'ž' => "z",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        _ => {
+            return None;
+        }
+    };
+
+    Some(folded)
+}
+
 // tests for utils/string utils (or wherever these fns live)
 //
 // Observação: estes testes assumem que:
@@ -152,6 +338,35 @@ mod test_access {
         }
     }
     #[cfg(test)]
+    mod capitalize_smart_tests {
+        use crate::utils::transforms::{ SMART_CAPITALIZE_BOUNDARIES, capitalize_smart };
+
+        #[test]
+        fn capitalizes_after_apostrophe() {
+            assert_eq!(capitalize_smart("o'brien", SMART_CAPITALIZE_BOUNDARIES), "O'Brien");
+        }
+
+        #[test]
+        fn capitalizes_after_hyphen() {
+            assert_eq!(capitalize_smart("jean-paul", SMART_CAPITALIZE_BOUNDARIES), "Jean-Paul");
+        }
+
+        #[test]
+        fn without_boundaries_behaves_like_capitalize() {
+            assert_eq!(capitalize_smart("jean-paul", &[]), "Jean-paul");
+            assert_eq!(capitalize_smart("", SMART_CAPITALIZE_BOUNDARIES), "");
+        }
+
+        #[test]
+        fn honors_turkic_locale() {
+            use crate::utils::transforms::capitalize_smart_with_locale;
+
+            let out = capitalize_smart_with_locale("ilk-ipek", &['-'], Some("tr"));
+
+            assert_eq!(out, "İlk-İpek");
+        }
+    }
+    #[cfg(test)]
     mod capitalize_tests {
         use crate::utils::transforms::capitalize;
 
@@ -188,6 +403,15 @@ mod test_access {
             let out = capitalize("ßeta");
             assert!(out.starts_with("SS") || out.starts_with("ẞ"), "got: {out}");
         }
+
+        #[test]
+        fn honors_turkic_locale() {
+            use crate::utils::transforms::capitalize_with_locale;
+
+            assert_eq!(capitalize_with_locale("istanbul", Some("tr")), "İstanbul");
+            assert_eq!(capitalize_with_locale("ılık", Some("tr")), "Ilık");
+            assert_eq!(capitalize_with_locale("istanbul", None), "Istanbul");
+        }
     }
     #[cfg(test)]
     mod extend_string_tests {
@@ -298,4 +522,141 @@ mod test_access {
             );
         }
     }
+
+    #[cfg(test)]
+    mod ascii_fold_tests {
+        use crate::utils::transforms::ascii_fold;
+
+        #[test]
+        fn folds_accented_latin_characters() {
+            assert_eq!(ascii_fold("Héllo Wörld"), "Hello World");
+            assert_eq!(ascii_fold("ação"), "acao");
+        }
+
+        #[test]
+        fn expands_ligatures_and_sharp_s() {
+            assert_eq!(ascii_fold("straße"), "strasse");
+            assert_eq!(ascii_fold("Æsir œuvre"), "AEsir oeuvre");
+        }
+
+        #[test]
+        fn keeps_unmapped_characters() {
+            assert_eq!(ascii_fold("abc 123 🔥 日本"), "abc 123 🔥 日本");
+        }
+    }
+
+    #[cfg(test)]
+    mod locale_case_tests {
+        use crate::utils::transforms::{ to_lowercase_with_locale, to_uppercase_with_locale };
+
+        #[test]
+        fn default_locale_uses_unicode_mapping() {
+            assert_eq!(to_uppercase_with_locale("i", None), "I");
+            assert_eq!(to_lowercase_with_locale("I", None), "i");
+            assert_eq!(to_uppercase_with_locale("i", Some("pt-BR")), "I");
+        }
+
+        #[test]
+        fn turkish_locale_handles_dotted_and_dotless_i() {
+            assert_eq!(to_uppercase_with_locale("istanbul ılık", Some("tr")), "İSTANBUL ILIK");
+            assert_eq!(to_lowercase_with_locale("İSTANBUL ILIK", Some("tr_TR")), "istanbul ılık");
+        }
+    }
+
+    #[cfg(test)]
+    mod string_to_bool_tests {
+        use crate::utils::errors::{ AtpError, AtpErrorCode };
+        use crate::utils::transforms::string_to_bool;
+
+        #[test]
+        fn parses_true_and_false() -> Result<(), AtpError> {
+            assert!(string_to_bool("true")?);
+            assert!(!string_to_bool("false;")?);
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_anything_else() {
+            let err = string_to_bool("yes").unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+    }
+
+    #[cfg(test)]
+    mod string_to_i64_tests {
+        use crate::utils::errors::{ AtpError, AtpErrorCode };
+        use crate::utils::transforms::string_to_i64;
+
+        #[test]
+        fn parses_signed_values() -> Result<(), AtpError> {
+            assert_eq!(string_to_i64("-3")?, -3);
+            assert_eq!(string_to_i64("42;")?, 42);
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_non_numeric() {
+            let err = string_to_i64("abc").unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::TextParsingError(_)));
+        }
+    }
 }
+
+/// International Morse code for letters, digits and common punctuation, used by `morsee` and
+/// `morsed`.
+pub const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
@@ -1,6 +1,19 @@
 use std::{borrow::Cow, path::Path};
 
-use crate::utils::errors::{AtpError, AtpErrorCode};
+use crate::{
+    context::execution_context::GlobalExecutionContext,
+    globals::{
+        table::{ QuerySource, QueryTarget, SyntaxToken, TOKEN_TABLE, TargetValue },
+        var::{
+            TokenWrapper,
+            ValType,
+            get_max_param_count,
+            get_required_param_count,
+            iter_syntax,
+        },
+    },
+    utils::{ errors::{ AtpError, AtpErrorCode }, params::AtpParamTypes },
+};
 
 pub fn check_file_path(path: &Path, ext: Option<&str>) -> Result<(), AtpError> {
     let parsed_ext = ext.unwrap_or("atp");
@@ -110,6 +123,84 @@ pub fn check_chunk_bound_indexes(
     Ok(())
 }
 
+/// How indexed tokens (`cts`, `dla`, `dlb`, `dls`, `swpw`, `tgl`, `tlcs`, `tlcw`, `tucs`, `tucw`
+/// and `wsel`) treat an index that does not exist in their input. Without a policy they are
+/// `Strict`. Set it for a whole processor with `AtpProcessor::set_index_policy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexPolicy {
+    /// Out of range indexes are an `IndexOutOfRange` error.
+    Strict,
+    /// Out of range indexes are moved to the last valid one.
+    Clamp,
+    /// Out of range indexes leave the input unchanged.
+    Ignore,
+}
+
+fn resolve_index(
+    index: usize,
+    count: usize,
+    policy: IndexPolicy,
+    strict_check: impl FnOnce() -> Result<(), AtpError>
+) -> Result<Option<usize>, AtpError> {
+    if index < count {
+        return Ok(Some(index));
+    }
+
+    match policy {
+        IndexPolicy::Strict => {
+            strict_check()?;
+            Ok(Some(index))
+        }
+        IndexPolicy::Clamp => Ok(count.checked_sub(1)),
+        IndexPolicy::Ignore => Ok(None),
+    }
+}
+
+/// Applies `policy` to a char `index` of `input`.
+///
+/// Returns the index the token should use, or `None` when it should leave `input` unchanged.
+pub fn resolve_index_against_input(
+    index: usize,
+    input: &str,
+    policy: IndexPolicy
+) -> Result<Option<usize>, AtpError> {
+    resolve_index(index, input.chars().count(), policy, || check_index_against_input(index, input))
+}
+
+/// Same as `resolve_index_against_input`, but `index` is a word index.
+pub fn resolve_index_against_words(
+    index: usize,
+    input: &str,
+    policy: IndexPolicy
+) -> Result<Option<usize>, AtpError> {
+    resolve_index(index, input.split_whitespace().count(), policy, || {
+        check_index_against_words(index, input)
+    })
+}
+
+/// What the index of an indexed token counts in its input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexUnit {
+    Char,
+    Word,
+}
+
+/// Resolves a token `index` with the `IndexPolicy` of `context`, `Strict` when none is set.
+///
+/// Returns the index the token should use, or `None` when it should return `input` unchanged.
+pub fn resolve_token_index(
+    index: usize,
+    input: &str,
+    unit: IndexUnit,
+    context: &GlobalExecutionContext
+) -> Result<Option<usize>, AtpError> {
+    let policy = context.get_index_policy().unwrap_or(IndexPolicy::Strict);
+    match unit {
+        IndexUnit::Char => resolve_index_against_input(index, input, policy),
+        IndexUnit::Word => resolve_index_against_words(index, input, policy),
+    }
+}
+
 pub fn check_index_against_input(index: usize, input: &str) -> Result<(), AtpError> {
     let character_count = input.chars().count();
     if !(0..character_count).contains(&index) {
@@ -184,6 +275,108 @@ pub fn check_vec_len<T>(
         ))
     }
 }
+/// Checks that `token` is structurally sound without running it:
+/// its params must match the syntax registered in `TOKEN_TABLE` (nested tokens are checked too)
+/// and, when no param is a variable reference, the token must accept them through `from_params`
+/// and produce an `.atp` line for its own identifier.
+pub fn check_token_consistency(token: &TokenWrapper) -> Result<(), AtpError> {
+    let syntax = match
+        TOKEN_TABLE.find((
+            QuerySource::Identifier(token.get_string_repr().into()),
+            QueryTarget::Syntax,
+        ))?
+    {
+        TargetValue::Syntax(x) => x,
+        _ => unreachable!("Invalid Query result (Syntax)"),
+    };
+    let required = get_required_param_count(&syntax);
+    let max = get_max_param_count(&syntax);
+    let params = token.get_val_params();
+
+    if params.len() < required || max.is_some_and(|max| params.len() > max) {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::ValidationError("Param count does not match token syntax".into()),
+                "check_token_consistency",
+                format!(
+                    "token={}, expected={}, got={}",
+                    token.get_string_repr(),
+                    max.map_or_else(|| format!("{}..", required), |max| max.to_string()),
+                    params.len()
+                )
+            )
+        );
+    }
+
+    let expected = iter_syntax(&syntax)
+        .filter(|ip| !matches!(ip.token, SyntaxToken::Literal(_)))
+        .map(|ip| ip.token);
+
+    let mut literals = Vec::with_capacity(params.len());
+
+    for (i, (param, expected_type)) in params.iter().zip(expected).enumerate() {
+        let literal = match param {
+            ValType::VarRef(_) => {
+                continue;
+            }
+            ValType::Literal(x) => x,
+        };
+
+        let matches_syntax = match (literal, expected_type) {
+            (AtpParamTypes::String(_), SyntaxToken::String) => true,
+            (AtpParamTypes::Usize(_), SyntaxToken::Usize) => true,
+            (AtpParamTypes::Bool(_), SyntaxToken::Bool) => true,
+            (AtpParamTypes::I64(_), SyntaxToken::I64) => true,
+            (AtpParamTypes::Token(inner), SyntaxToken::Token) => {
+                check_token_consistency(inner)?;
+                true
+            }
+            _ => false,
+        };
+
+        if !matches_syntax {
+            return Err(
+                AtpError::new(
+                    AtpErrorCode::ValidationError("Param type does not match token syntax".into()),
+                    "check_token_consistency",
+                    format!(
+                        "token={}, param={}, expected={:?}, got={:?}",
+                        token.get_string_repr(),
+                        i,
+                        expected_type,
+                        literal
+                    )
+                )
+            );
+        }
+
+        literals.push(literal.clone());
+    }
+
+    // Variables are only known at runtime, so the rebuild check is skipped for them.
+    if literals.len() != params.len() {
+        return Ok(());
+    }
+
+    let mut rebuilt = token.get_default_token();
+    rebuilt.from_params(&literals)?;
+
+    let line = rebuilt.to_atp_line();
+    let identifier = line.split_whitespace().next().unwrap_or("").trim_end_matches(';');
+
+    if identifier != token.get_string_repr() || !line.trim_end().ends_with(';') {
+        return Err(
+            AtpError::new(
+                AtpErrorCode::ValidationError("Token produced an inconsistent atp line".into()),
+                "check_token_consistency",
+                line.to_string()
+            )
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,6 +628,55 @@ mod tests {
         }
     }
 
+    mod resolve_index_tests {
+        use crate::context::execution_context::GlobalExecutionContext;
+        use crate::utils::{
+            errors::AtpErrorCode,
+            validations::{
+                IndexPolicy,
+                IndexUnit,
+                resolve_index_against_input,
+                resolve_index_against_words,
+                resolve_token_index,
+            },
+        };
+
+        #[test]
+        fn valid_index_is_kept_by_every_policy() {
+            for policy in [IndexPolicy::Strict, IndexPolicy::Clamp, IndexPolicy::Ignore] {
+                assert_eq!(resolve_index_against_input(2, "banana", policy), Ok(Some(2)));
+            }
+        }
+
+        #[test]
+        fn strict_rejects_out_of_range() {
+            let err = resolve_index_against_input(9, "banana", IndexPolicy::Strict).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+        }
+
+        #[test]
+        fn clamp_moves_to_last_index() {
+            assert_eq!(resolve_index_against_input(9, "banana", IndexPolicy::Clamp), Ok(Some(5)));
+            assert_eq!(resolve_index_against_words(9, "a b c", IndexPolicy::Clamp), Ok(Some(2)));
+            assert_eq!(resolve_index_against_input(0, "", IndexPolicy::Clamp), Ok(None));
+        }
+
+        #[test]
+        fn ignore_returns_none() {
+            assert_eq!(resolve_index_against_words(3, "a b c", IndexPolicy::Ignore), Ok(None));
+        }
+
+        #[test]
+        fn token_index_uses_context_policy_and_defaults_to_strict() {
+            let mut ctx = GlobalExecutionContext::new();
+            let err = resolve_token_index(9, "banana", IndexUnit::Char, &ctx).unwrap_err();
+            assert!(matches!(err.error_code, AtpErrorCode::IndexOutOfRange(_)));
+
+            ctx.set_index_policy(Some(IndexPolicy::Clamp));
+            assert_eq!(resolve_token_index(9, "a b c", IndexUnit::Word, &ctx), Ok(Some(2)));
+        }
+    }
+
     mod check_index_against_words_tests {
         use super::*;
 
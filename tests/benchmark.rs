@@ -3,8 +3,10 @@
 pub mod benchmark {
     use atp::{
         api::{ atp_processor::{ AtpProcessor, AtpProcessorMethods } },
+        tokens::transforms::raw::Raw,
         utils::test_helpers::build_all_tokens_pipeline_safe,
     };
+    use regex::Regex;
     use std::time::Instant;
 
     use atp::utils::errors::AtpError;
@@ -137,4 +139,27 @@ pub mod benchmark {
         assert!(avg < 0.003, "Executou muito devagar");
         Ok(())
     }
+
+    #[test]
+    fn regex_cache_reduces_compile_time() {
+        let runs = 100;
+        let pattern = r"\b(banana|laranja|canja)\w*\b";
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            Regex::new(pattern).unwrap();
+        }
+        let uncached = start.elapsed().as_secs_f64();
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            Raw::new(pattern, "x").unwrap();
+        }
+        let cached = start.elapsed().as_secs_f64();
+
+        println!("Compilando sempre: {:.6} Segundos", uncached);
+        println!("Com cache: {:.6} Segundos", cached);
+
+        assert!(cached < uncached, "O cache de regex não reduziu o tempo de compilação");
+    }
 }